@@ -58,28 +58,43 @@
 //! ```
 
 mod client;
+mod conditional;
+mod count;
 mod endpoint;
 mod error;
+mod idempotent;
 mod ignore;
+mod json_body;
+mod optional;
 mod paged;
 mod params;
 pub(crate) mod query;
 mod raw;
+mod raw_endpoint;
 mod sudo;
 
 pub mod endpoint_prelude;
 
+pub mod application_settings;
+pub mod audit_events;
 pub mod common;
 pub mod deploy_keys;
+pub mod events;
 pub mod groups;
+pub mod import;
 pub mod issues;
 pub mod job;
+pub mod metadata;
+pub mod namespaces;
+pub mod notification_settings;
 pub mod packages;
 pub mod personal_access_tokens;
 pub mod projects;
 pub mod retry;
 pub mod runners;
+pub mod todos;
 pub mod users;
+pub mod version;
 
 pub(crate) mod helpers;
 
@@ -87,15 +102,31 @@ pub use self::client::AsyncClient;
 pub use self::client::Client;
 pub use self::client::RestClient;
 
+pub use self::conditional::conditional;
+pub use self::conditional::Conditional;
+pub use self::conditional::Modified;
+
+pub use self::count::count;
+pub use self::count::Count;
+
 pub use self::endpoint::Endpoint;
 pub use self::endpoint::UrlBase;
 
 pub use self::error::ApiError;
 pub use self::error::BodyError;
 
+pub use self::idempotent::idempotent;
+pub use self::idempotent::Idempotent;
+
 pub use self::ignore::ignore;
 pub use self::ignore::Ignore;
 
+pub use self::json_body::json_body;
+pub use self::json_body::JsonBody;
+
+pub use self::optional::optional;
+pub use self::optional::Optional;
+
 pub use self::paged::paged;
 pub use self::paged::LazilyPagedIter;
 pub use self::paged::LinkHeaderParseError;
@@ -106,6 +137,7 @@ pub use self::paged::PaginationError;
 
 pub use self::params::FormParams;
 pub use self::params::JsonParams;
+pub use self::params::MultipartParams;
 pub use self::params::ParamValue;
 pub use self::params::QueryParams;
 
@@ -115,6 +147,10 @@ pub use self::query::Query;
 pub use self::raw::raw;
 pub use self::raw::Raw;
 
+pub use self::raw_endpoint::RawEndpoint;
+pub use self::raw_endpoint::RawEndpointBuilder;
+pub use self::raw_endpoint::RawEndpointBuilderError;
+
 pub use self::sudo::sudo;
 pub use self::sudo::Sudo;
 pub use self::sudo::SudoContext;
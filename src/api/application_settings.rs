@@ -0,0 +1,21 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Application settings API endpoints.
+//!
+//! These endpoints are used for querying and modifying the settings of the instance. They require
+//! administrator privileges.
+
+mod edit;
+mod settings;
+
+pub use self::edit::EditApplicationSettings;
+pub use self::edit::EditApplicationSettingsBuilder;
+pub use self::edit::EditApplicationSettingsBuilderError;
+
+pub use self::settings::ApplicationSettings;
+pub use self::settings::ApplicationSettingsBuilder;
+pub use self::settings::ApplicationSettingsBuilderError;
@@ -0,0 +1,278 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+
+use derive_builder::Builder;
+
+use crate::api::common::VisibilityLevel;
+use crate::api::endpoint_prelude::*;
+
+/// Edit the settings of the instance.
+///
+/// Only commonly used settings have dedicated setters. Any other setting may be changed using
+/// the `param` and `params` setters; see the GitLab documentation for the available settings.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditApplicationSettings<'a> {
+    /// Whether new users may sign up.
+    #[builder(default)]
+    signup_enabled: Option<bool>,
+    /// The default visibility of new projects.
+    #[builder(default)]
+    default_project_visibility: Option<VisibilityLevel>,
+    /// The maximum size of attachments (in megabytes).
+    #[builder(default)]
+    max_attachment_size: Option<u64>,
+    /// The name of the initial branch of new repositories.
+    #[builder(setter(into), default)]
+    default_branch_name: Option<Cow<'a, str>>,
+    /// Whether shared runners are enabled for new projects.
+    #[builder(default)]
+    shared_runners_enabled: Option<bool>,
+    /// How long container registry tokens are valid for (in minutes).
+    #[builder(default)]
+    container_registry_token_expire_delay: Option<u64>,
+
+    /// Other settings to change.
+    #[builder(setter(name = "_params"), default, private)]
+    params: BTreeMap<Cow<'a, str>, Cow<'a, str>>,
+}
+
+impl<'a> EditApplicationSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditApplicationSettingsBuilder<'a> {
+        EditApplicationSettingsBuilder::default()
+    }
+}
+
+impl<'a> EditApplicationSettingsBuilder<'a> {
+    /// Change a setting without a dedicated setter.
+    pub fn param<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.params
+            .get_or_insert_with(BTreeMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Change multiple settings without dedicated setters.
+    pub fn params<I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = (K, V)>,
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.params
+            .get_or_insert_with(BTreeMap::new)
+            .extend(iter.map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+}
+
+impl<'a> Endpoint for EditApplicationSettings<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "application/settings".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("signup_enabled", self.signup_enabled)
+            .push_opt(
+                "default_project_visibility",
+                self.default_project_visibility,
+            )
+            .push_opt("max_attachment_size", self.max_attachment_size)
+            .push_opt("default_branch_name", self.default_branch_name.as_ref())
+            .push_opt("shared_runners_enabled", self.shared_runners_enabled)
+            .push_opt(
+                "container_registry_token_expire_delay",
+                self.container_registry_token_expire_delay,
+            )
+            .extend(self.params.iter().map(|(key, value)| (key.as_ref(), value)));
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::application_settings::EditApplicationSettings;
+    use crate::api::common::VisibilityLevel;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        EditApplicationSettings::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_signup_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("signup_enabled=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .signup_enabled(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_default_project_visibility() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("default_project_visibility=internal")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .default_project_visibility(VisibilityLevel::Internal)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_max_attachment_size() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("max_attachment_size=100")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .max_attachment_size(100)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_default_branch_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("default_branch_name=main")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .default_branch_name("main")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_shared_runners_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("shared_runners_enabled=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .shared_runners_enabled(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_container_registry_token_expire_delay() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("container_registry_token_expire_delay=15")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .container_registry_token_expire_delay(15)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_params() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "signup_enabled=true",
+                "&gravatar_enabled=false",
+                "&home_page_url=https%3A%2F%2Fexample.com",
+                "&require_two_factor_authentication=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .signup_enabled(true)
+            .param("require_two_factor_authentication", "true")
+            .params(
+                [
+                    ("home_page_url", "https://example.com"),
+                    ("gravatar_enabled", "false"),
+                ]
+                .iter()
+                .cloned(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,54 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the settings of the instance.
+#[derive(Debug, Builder, Clone)]
+pub struct ApplicationSettings {}
+
+impl ApplicationSettings {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ApplicationSettingsBuilder {
+        ApplicationSettingsBuilder::default()
+    }
+}
+
+impl Endpoint for ApplicationSettings {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "application/settings".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::application_settings::ApplicationSettings;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn default_is_sufficient() {
+        ApplicationSettings::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("application/settings")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ApplicationSettings::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,205 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Audit event API endpoints and types.
+//!
+//! These endpoints are used for querying audit events of the whole instance. Group- and
+//! project-scoped variants are available in the `groups` and `projects` modules.
+//!
+//! Audit events are only available on GitLab Premium and Ultimate instances. Other instances
+//! will return a `404 Not Found` error.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Entity types which may be used to filter instance audit events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AuditEventEntityType {
+    /// Events on users.
+    User,
+    /// Events on groups.
+    Group,
+    /// Events on projects.
+    Project,
+    /// Events on the instance itself.
+    Instance,
+}
+
+impl AuditEventEntityType {
+    fn as_str(self) -> &'static str {
+        match self {
+            AuditEventEntityType::User => "User",
+            AuditEventEntityType::Group => "Group",
+            AuditEventEntityType::Project => "Project",
+            AuditEventEntityType::Instance => "Gitlab::Audit::InstanceScope",
+        }
+    }
+}
+
+impl ParamValue<'static> for AuditEventEntityType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for audit events of the instance.
+///
+/// This endpoint requires administrator privileges.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct AuditEvents {
+    /// Filter events created after a time.
+    #[builder(default)]
+    created_after: Option<DateTime<Utc>>,
+    /// Filter events created before a time.
+    #[builder(default)]
+    created_before: Option<DateTime<Utc>>,
+    /// Filter events by the type of entity.
+    #[builder(default)]
+    entity_type: Option<AuditEventEntityType>,
+    /// Filter events by the ID of the entity.
+    #[builder(default)]
+    entity_id: Option<u64>,
+}
+
+impl AuditEvents {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AuditEventsBuilder {
+        AuditEventsBuilder::default()
+    }
+}
+
+impl Endpoint for AuditEvents {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "audit_events".into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("created_after", self.created_after)
+            .push_opt("created_before", self.created_before)
+            .push_opt("entity_type", self.entity_type)
+            .push_opt("entity_id", self.entity_id);
+
+        params
+    }
+}
+
+impl Pageable for AuditEvents {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::api::audit_events::{AuditEventEntityType, AuditEvents};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn audit_event_entity_type_as_str() {
+        let items = &[
+            (AuditEventEntityType::User, "User"),
+            (AuditEventEntityType::Group, "Group"),
+            (AuditEventEntityType::Project, "Project"),
+            (
+                AuditEventEntityType::Instance,
+                "Gitlab::Audit::InstanceScope",
+            ),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn defaults_are_sufficient() {
+        AuditEvents::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("audit_events")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AuditEvents::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("audit_events")
+            .add_query_params(&[("created_after", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AuditEvents::builder()
+            .created_after(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("audit_events")
+            .add_query_params(&[("created_before", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AuditEvents::builder()
+            .created_before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_entity_type() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("audit_events")
+            .add_query_params(&[("entity_type", "Project")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AuditEvents::builder()
+            .entity_type(AuditEventEntityType::Project)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_entity_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("audit_events")
+            .add_query_params(&[("entity_id", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AuditEvents::builder().entity_id(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
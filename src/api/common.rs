@@ -545,6 +545,9 @@ mod tests {
                 "special/name?string".to_string().into(),
                 "special%2Fname%3Fstring",
             ),
+            ("group/subgroup/project".into(), "group%2Fsubgroup%2Fproject"),
+            ("group/my project".into(), "group%2Fmy%20project"),
+            ("group/project.name".into(), "group%2Fproject.name"),
             (1.into(), "1"),
         ];
 
@@ -0,0 +1,280 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{header, Request, Response, StatusCode};
+use serde::de::DeserializeOwned;
+
+use crate::api::{query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, RestClient};
+
+/// A query modifier that performs a conditional request using an entity tag.
+///
+/// If the resource has not changed since the given entity tag was returned, the query results in
+/// `None`. Otherwise, the new data is returned along with its entity tag (if any).
+#[derive(Debug, Clone)]
+pub struct Conditional<'a, E> {
+    endpoint: E,
+    etag: Option<Cow<'a, str>>,
+}
+
+/// Perform a conditional query on an endpoint.
+///
+/// The `etag` should be the value of `Modified::etag` from a previous query of the same endpoint.
+/// When `None`, the request is unconditional.
+pub fn conditional<'a, E, S>(endpoint: E, etag: Option<S>) -> Conditional<'a, E>
+where
+    S: Into<Cow<'a, str>>,
+{
+    Conditional {
+        endpoint,
+        etag: etag.map(Into::into),
+    }
+}
+
+/// Data returned from a conditional query when the resource has been modified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Modified<T> {
+    /// The data from the endpoint.
+    pub data: T,
+    /// The entity tag of the resource, if provided.
+    ///
+    /// This may be used to perform the next conditional query.
+    pub etag: Option<String>,
+}
+
+impl<'a, E> Conditional<'a, E>
+where
+    E: Endpoint,
+{
+    fn request<C>(
+        &self,
+        client: &C,
+    ) -> Result<(http::request::Builder, Vec<u8>), ApiError<C::Error>>
+    where
+        C: RestClient,
+    {
+        let mut url = self
+            .endpoint
+            .url_base()
+            .endpoint_for(client, &self.endpoint.endpoint())?;
+        self.endpoint.parameters().add_to_url(&mut url);
+
+        let req = Request::builder()
+            .method(self.endpoint.method())
+            .uri(query::url_to_http_uri(url));
+        let req = if let Some(etag) = self.etag.as_ref() {
+            req.header(header::IF_NONE_MATCH, etag.as_ref())
+        } else {
+            req
+        };
+        Ok(if let Some((mime, data)) = self.endpoint.body()? {
+            let req = req.header(header::CONTENT_TYPE, mime);
+            (req, data)
+        } else {
+            (req, Vec::new())
+        })
+    }
+}
+
+fn handle_response<T, E>(rsp: Response<Bytes>) -> Result<Option<Modified<T>>, ApiError<E>>
+where
+    T: DeserializeOwned,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let status = rsp.status();
+    // A `304 Not Modified` response has no body; avoid trying to parse it.
+    if status == StatusCode::NOT_MODIFIED {
+        return Ok(None);
+    }
+
+    let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
+        v
+    } else {
+        return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
+    };
+    if !status.is_success() {
+        return Err(ApiError::from_gitlab(v, rsp.headers()));
+    } else if status == StatusCode::MOVED_PERMANENTLY {
+        return Err(ApiError::moved_permanently(
+            rsp.headers().get(header::LOCATION),
+        ));
+    }
+
+    let etag = rsp
+        .headers()
+        .get(header::ETAG)
+        .map(|v| String::from_utf8_lossy(v.as_bytes()).into());
+    let data = serde_json::from_value::<T>(v).map_err(ApiError::data_type::<T>)?;
+
+    Ok(Some(Modified { data, etag }))
+}
+
+impl<'a, E, T, C> Query<Option<Modified<T>>, C> for Conditional<'a, E>
+where
+    E: Endpoint,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Option<Modified<T>>, ApiError<C::Error>> {
+        let (req, data) = self.request(client)?;
+        let rsp = client.rest(req, data)?;
+        handle_response(rsp)
+    }
+}
+
+#[async_trait]
+impl<'a, E, T, C> AsyncQuery<Option<Modified<T>>, C> for Conditional<'a, E>
+where
+    E: Endpoint + Sync,
+    T: DeserializeOwned + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Option<Modified<T>>, ApiError<C::Error>> {
+        let (req, data) = self.request(client)?;
+        let rsp = client.rest_async(req, data).await?;
+        handle_response(rsp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, AsyncQuery, Modified, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct DummyResult {
+        value: u8,
+    }
+
+    #[test]
+    fn test_unconditional() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_response_header("etag", "W/\"abc\"")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "value": 0,
+            }),
+        );
+
+        let res: Option<Modified<DummyResult>> = api::conditional(Dummy, None::<&str>)
+            .query(&client)
+            .unwrap();
+        assert_eq!(
+            res,
+            Some(Modified {
+                data: DummyResult { value: 0 },
+                etag: Some("W/\"abc\"".into()),
+            }),
+        );
+    }
+
+    #[test]
+    fn test_modified_no_etag() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_header("if-none-match", "W/\"abc\"")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "value": 1,
+            }),
+        );
+
+        let res: Option<Modified<DummyResult>> = api::conditional(Dummy, Some("W/\"abc\""))
+            .query(&client)
+            .unwrap();
+        let res = res.unwrap();
+        assert_eq!(res.data.value, 1);
+        assert_eq!(res.etag, None);
+    }
+
+    #[test]
+    fn test_not_modified() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_header("if-none-match", "W/\"abc\"")
+            .status(StatusCode::NOT_MODIFIED)
+            .build()
+            .unwrap();
+        // An empty body would fail to parse as JSON if it were attempted.
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let res: Option<Modified<DummyResult>> = api::conditional(Dummy, Some("W/\"abc\""))
+            .query(&client)
+            .unwrap();
+        assert_eq!(res, None);
+    }
+
+    #[tokio::test]
+    async fn test_not_modified_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_header("if-none-match", "W/\"abc\"")
+            .status(StatusCode::NOT_MODIFIED)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let res: Option<Modified<DummyResult>> = api::conditional(Dummy, Some("W/\"abc\""))
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert_eq!(res, None);
+    }
+
+    #[test]
+    fn test_gitlab_error_detection() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let res: Result<Option<Modified<DummyResult>>, _> =
+            api::conditional(Dummy, None::<&str>).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::Gitlab {
+            msg, ..
+        } = err
+        {
+            assert_eq!(msg, "dummy error message");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
@@ -0,0 +1,266 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{header, Request, Response};
+
+use crate::api::{
+    query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Pageable, PaginationError, Query,
+    RestClient,
+};
+
+const TOTAL_HEADER: &str = "x-total";
+
+/// A query modifier that returns the total number of results of a pageable endpoint.
+///
+/// Only a single result is requested; the response body is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Count<E> {
+    endpoint: E,
+}
+
+/// Count the total number of results of a pageable endpoint.
+///
+/// GitLab may omit the total for large result sets. In this case, the query fails with
+/// `PaginationError::MissingTotal`.
+pub fn count<E>(endpoint: E) -> Count<E> {
+    Count {
+        endpoint,
+    }
+}
+
+impl<E> Count<E>
+where
+    E: Endpoint,
+    E: Pageable,
+{
+    fn request<C>(
+        &self,
+        client: &C,
+    ) -> Result<(http::request::Builder, Vec<u8>), ApiError<C::Error>>
+    where
+        C: RestClient,
+    {
+        let mut url = self
+            .endpoint
+            .url_base()
+            .endpoint_for(client, &self.endpoint.endpoint())?;
+        self.endpoint.parameters().add_to_url(&mut url);
+        url.query_pairs_mut().append_pair("per_page", "1");
+
+        let req = Request::builder()
+            .method(self.endpoint.method())
+            .uri(query::url_to_http_uri(url));
+        Ok(if let Some((mime, data)) = self.endpoint.body()? {
+            let req = req.header(header::CONTENT_TYPE, mime);
+            (req, data)
+        } else {
+            (req, Vec::new())
+        })
+    }
+}
+
+fn handle_response<E>(rsp: Response<Bytes>) -> Result<u64, ApiError<E>>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let status = rsp.status();
+    if !status.is_success() {
+        let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
+            v
+        } else {
+            return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
+        };
+        return Err(ApiError::from_gitlab(v, rsp.headers()));
+    } else if status == http::StatusCode::MOVED_PERMANENTLY {
+        return Err(ApiError::moved_permanently(
+            rsp.headers().get(http::header::LOCATION),
+        ));
+    }
+
+    let total = rsp
+        .headers()
+        .get(TOTAL_HEADER)
+        .ok_or(PaginationError::MissingTotal)?;
+    let total = String::from_utf8_lossy(total.as_bytes());
+    let total = total.trim().parse().map_err(|_| {
+        PaginationError::InvalidTotal {
+            value: total.clone().into_owned(),
+        }
+    })?;
+
+    Ok(total)
+}
+
+impl<E, C> Query<u64, C> for Count<E>
+where
+    E: Endpoint,
+    E: Pageable,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<u64, ApiError<C::Error>> {
+        let (req, data) = self.request(client)?;
+        let rsp = client.rest(req, data)?;
+        handle_response(rsp)
+    }
+}
+
+#[async_trait]
+impl<E, C> AsyncQuery<u64, C> for Count<E>
+where
+    E: Endpoint + Sync,
+    E: Pageable,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<u64, ApiError<C::Error>> {
+        let (req, data) = self.request(client)?;
+        let rsp = client.rest_async(req, data).await?;
+        handle_response(rsp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, AsyncQuery, PaginationError, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+
+        fn parameters(&self) -> QueryParams {
+            let mut params = QueryParams::default();
+            params.push("state", "opened");
+            params
+        }
+    }
+
+    impl Pageable for Dummy {}
+
+    #[test]
+    fn test_count() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("state", "opened"), ("per_page", "1")])
+            .add_response_header("x-total", "42")
+            .add_response_header("x-total-pages", "42")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(endpoint, &json!([{}]));
+
+        let count = api::count(Dummy).query(&client).unwrap();
+        assert_eq!(count, 42);
+    }
+
+    #[tokio::test]
+    async fn test_count_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("state", "opened"), ("per_page", "1")])
+            .add_response_header("x-total", "0")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(endpoint, &json!([]));
+
+        let count = api::count(Dummy).query_async(&client).await.unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_count_ignores_body() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("state", "opened"), ("per_page", "1")])
+            .add_response_header("x-total", "5")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "not json");
+
+        let count = api::count(Dummy).query(&client).unwrap();
+        assert_eq!(count, 5);
+    }
+
+    #[test]
+    fn test_count_missing_total() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("state", "opened"), ("per_page", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(endpoint, &json!([{}]));
+
+        let err = api::count(Dummy).query(&client).unwrap_err();
+        if let ApiError::Pagination {
+            source: PaginationError::MissingTotal,
+        } = err
+        {
+            // expected
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_count_invalid_total() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("state", "opened"), ("per_page", "1")])
+            .add_response_header("x-total", "many")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(endpoint, &json!([{}]));
+
+        let err = api::count(Dummy).query(&client).unwrap_err();
+        if let ApiError::Pagination {
+            source: PaginationError::InvalidTotal {
+                value,
+            },
+        } = err
+        {
+            assert_eq!(value, "many");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_gitlab_error_detection() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("state", "opened"), ("per_page", "1")])
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let err = api::count(Dummy).query(&client).unwrap_err();
+        if let ApiError::Gitlab {
+            msg, ..
+        } = err
+        {
+            assert_eq!(msg, "dummy error message");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
@@ -112,10 +112,10 @@ where
         let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
             v
         } else {
-            return Err(ApiError::server_error(status, rsp.body()));
+            return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
         };
         if !status.is_success() {
-            return Err(ApiError::from_gitlab(v));
+            return Err(ApiError::from_gitlab(v, rsp.headers()));
         } else if status == http::StatusCode::MOVED_PERMANENTLY {
             return Err(ApiError::moved_permanently(
                 rsp.headers().get(http::header::LOCATION),
@@ -151,10 +151,10 @@ where
         let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
             v
         } else {
-            return Err(ApiError::server_error(status, rsp.body()));
+            return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
         };
         if !status.is_success() {
-            return Err(ApiError::from_gitlab(v));
+            return Err(ApiError::from_gitlab(v, rsp.headers()));
         } else if status == http::StatusCode::MOVED_PERMANENTLY {
             return Err(ApiError::moved_permanently(
                 rsp.headers().get(http::header::LOCATION),
@@ -263,11 +263,69 @@ mod tests {
 
         let res: Result<DummyResult, _> = Dummy.query(&client);
         let err = res.unwrap_err();
+        if let ApiError::Gitlab {
+            msg, ..
+        } = err
+        {
+            assert_eq!(msg, "dummy error message");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_gitlab_error_request_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NOT_FOUND)
+            .add_response_header("x-request-id", "01ABCDEF")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let res: Result<DummyResult, _> = Dummy.query(&client);
+        let err = res.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "gitlab server error: dummy error message (request id: 01ABCDEF)",
+        );
         if let ApiError::Gitlab {
             msg,
+            request_id,
         } = err
         {
             assert_eq!(msg, "dummy error message");
+            assert_eq!(request_id.as_deref(), Some("01ABCDEF"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_gitlab_service_error_request_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::BAD_GATEWAY)
+            .add_response_header("x-request-id", "01ABCDEF")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "not json");
+
+        let res: Result<DummyResult, _> = Dummy.query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::GitlabService {
+            status,
+            request_id,
+            ..
+        } = err
+        {
+            assert_eq!(status, StatusCode::BAD_GATEWAY);
+            assert_eq!(request_id.as_deref(), Some("01ABCDEF"));
         } else {
             panic!("unexpected error: {}", err);
         }
@@ -290,7 +348,7 @@ mod tests {
         let res: Result<DummyResult, _> = Dummy.query(&client);
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
-            msg,
+            msg, ..
         } = err
         {
             assert_eq!(msg, "dummy error message");
@@ -314,7 +372,7 @@ mod tests {
         let res: Result<DummyResult, _> = Dummy.query(&client);
         let err = res.unwrap_err();
         if let ApiError::GitlabUnrecognized {
-            obj,
+            obj, ..
         } = err
         {
             assert_eq!(obj, err_obj);
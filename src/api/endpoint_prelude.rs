@@ -18,6 +18,7 @@ pub use crate::api::Client;
 pub use crate::api::Endpoint;
 pub use crate::api::FormParams;
 pub use crate::api::JsonParams;
+pub use crate::api::MultipartParams;
 pub use crate::api::Pageable;
 pub use crate::api::QueryParams;
 pub use crate::api::UrlBase;
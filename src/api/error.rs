@@ -6,6 +6,7 @@
 
 use std::any;
 use std::error::Error;
+use std::fmt;
 
 use thiserror::Error;
 
@@ -22,6 +23,13 @@ pub enum BodyError {
         #[from]
         source: serde_urlencoded::ser::Error,
     },
+    /// Form body data could not be decoded to be re-encoded as JSON.
+    #[error("failed to URL decode form parameters: {}", source)]
+    UrlDecoded {
+        /// The source of the error.
+        #[from]
+        source: serde_urlencoded::de::Error,
+    },
     /// Body data could not be serialized to JSON from form parameters.
     #[error("failed to JSON encode form parameters: {}", source)]
     JsonEncoded {
@@ -29,6 +37,34 @@ pub enum BodyError {
         #[from]
         source: serde_json::Error,
     },
+    /// Multipart body data contains the boundary used to separate its parts.
+    #[error("multipart form data for `{}` contains the part boundary", name)]
+    MultipartBoundary {
+        /// The name of the parameter containing the boundary.
+        name: String,
+    },
+}
+
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Extract the request ID GitLab assigned to a response.
+fn request_id(headers: &http::HeaderMap) -> Option<String> {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .map(|v| String::from_utf8_lossy(v.as_bytes()).into())
+}
+
+/// Display an optional request ID as a suffix for error messages.
+struct RequestIdDisplay<'a>(&'a Option<String>);
+
+impl fmt::Display for RequestIdDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(request_id) = self.0 {
+            write!(f, " (request id: {})", request_id)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 /// Errors which may occur when using API endpoints.
@@ -79,30 +115,42 @@ where
         location: Option<String>,
     },
     /// GitLab returned an error message.
-    #[error("gitlab server error: {}", msg)]
+    #[error("gitlab server error: {}{}", msg, RequestIdDisplay(request_id))]
     Gitlab {
         /// The error message from GitLab.
         msg: String,
+        /// The `X-Request-Id` of the response, if provided.
+        request_id: Option<String>,
     },
     /// GitLab returned an error without JSON information.
-    #[error("gitlab internal server error {}", status)]
+    #[error(
+        "gitlab internal server error {}{}",
+        status,
+        RequestIdDisplay(request_id)
+    )]
     GitlabService {
         /// The status code for the return.
         status: http::StatusCode,
         /// The error data from GitLab.
         data: Vec<u8>,
+        /// The `X-Request-Id` of the response, if provided.
+        request_id: Option<String>,
     },
     /// GitLab returned an error object.
-    #[error("gitlab server error: {:?}", obj)]
+    #[error("gitlab server error: {:?}{}", obj, RequestIdDisplay(request_id))]
     GitlabObject {
         /// The error object from GitLab.
         obj: serde_json::Value,
+        /// The `X-Request-Id` of the response, if provided.
+        request_id: Option<String>,
     },
     /// GitLab returned an HTTP error with JSON we did not recognize.
-    #[error("gitlab server error: {:?}", obj)]
+    #[error("gitlab server error: {:?}{}", obj, RequestIdDisplay(request_id))]
     GitlabUnrecognized {
         /// The full object from GitLab.
         obj: serde_json::Value,
+        /// The `X-Request-Id` of the response, if provided.
+        request_id: Option<String>,
     },
     /// Failed to parse an expected data type from JSON.
     #[error("could not parse {} data from JSON: {}", typename, source)]
@@ -185,32 +233,40 @@ where
             },
             Self::Gitlab {
                 msg,
+                request_id,
             } => {
                 ApiError::Gitlab {
                     msg,
+                    request_id,
                 }
             },
             Self::GitlabService {
                 status,
                 data,
+                request_id,
             } => {
                 ApiError::GitlabService {
                     status,
                     data,
+                    request_id,
                 }
             },
             Self::GitlabObject {
                 obj,
+                request_id,
             } => {
                 ApiError::GitlabObject {
                     obj,
+                    request_id,
                 }
             },
             Self::GitlabUnrecognized {
                 obj,
+                request_id,
             } => {
                 ApiError::GitlabUnrecognized {
                     obj,
+                    request_id,
                 }
             },
             Self::DataType {
@@ -246,31 +302,40 @@ where
         }
     }
 
-    pub(crate) fn server_error(status: http::StatusCode, body: &bytes::Bytes) -> Self {
+    pub(crate) fn server_error(
+        status: http::StatusCode,
+        headers: &http::HeaderMap,
+        body: &bytes::Bytes,
+    ) -> Self {
         Self::GitlabService {
             status,
             data: body.into_iter().copied().collect(),
+            request_id: request_id(headers),
         }
     }
 
-    pub(crate) fn from_gitlab(value: serde_json::Value) -> Self {
+    pub(crate) fn from_gitlab(value: serde_json::Value, headers: &http::HeaderMap) -> Self {
         let error_value = value
             .pointer("/message")
             .or_else(|| value.pointer("/error"));
+        let request_id = request_id(headers);
 
         if let Some(error_value) = error_value {
             if let Some(msg) = error_value.as_str() {
                 ApiError::Gitlab {
                     msg: msg.into(),
+                    request_id,
                 }
             } else {
                 ApiError::GitlabObject {
                     obj: error_value.clone(),
+                    request_id,
                 }
             }
         } else {
             ApiError::GitlabUnrecognized {
                 obj: value,
+                request_id,
             }
         }
     }
@@ -306,9 +371,9 @@ mod tests {
             "error": "error contents",
         });
 
-        let err: ApiError<MyError> = ApiError::from_gitlab(obj);
+        let err: ApiError<MyError> = ApiError::from_gitlab(obj, &http::HeaderMap::new());
         if let ApiError::Gitlab {
-            msg,
+            msg, ..
         } = err
         {
             assert_eq!(msg, "error contents");
@@ -317,15 +382,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn gitlab_error_display_without_request_id() {
+        let obj = json!({
+            "message": "error contents",
+        });
+
+        let err: ApiError<MyError> = ApiError::from_gitlab(obj, &http::HeaderMap::new());
+        assert_eq!(err.to_string(), "gitlab server error: error contents");
+    }
+
     #[test]
     fn gitlab_error_message_string() {
         let obj = json!({
             "message": "error contents",
         });
 
-        let err: ApiError<MyError> = ApiError::from_gitlab(obj);
+        let err: ApiError<MyError> = ApiError::from_gitlab(obj, &http::HeaderMap::new());
         if let ApiError::Gitlab {
-            msg,
+            msg, ..
         } = err
         {
             assert_eq!(msg, "error contents");
@@ -343,9 +418,9 @@ mod tests {
             "message": err_obj,
         });
 
-        let err: ApiError<MyError> = ApiError::from_gitlab(obj);
+        let err: ApiError<MyError> = ApiError::from_gitlab(obj, &http::HeaderMap::new());
         if let ApiError::GitlabObject {
-            obj,
+            obj, ..
         } = err
         {
             assert_eq!(obj, err_obj);
@@ -360,9 +435,10 @@ mod tests {
             "some_weird_key": "an even weirder value",
         });
 
-        let err: ApiError<MyError> = ApiError::from_gitlab(err_obj.clone());
+        let err: ApiError<MyError> =
+            ApiError::from_gitlab(err_obj.clone(), &http::HeaderMap::new());
         if let ApiError::GitlabUnrecognized {
-            obj,
+            obj, ..
         } = err
         {
             assert_eq!(obj, err_obj);
@@ -11,22 +11,36 @@
 //! These endpoints are used for querying and modifying groups and their resources.
 
 pub mod access_requests;
+pub mod access_tokens;
+mod audit_events;
+pub mod badges;
 mod create;
+pub mod custom_attributes;
+mod delete;
 mod edit;
+pub mod epics;
 mod group;
 mod groups;
 pub mod hooks;
 pub mod issues;
+pub mod ldap_group_links;
 pub mod members;
 pub mod milestones;
+pub mod notification_settings;
 pub mod packages;
 pub mod projects;
 pub mod push_rule;
 pub mod runners;
+pub mod saml_group_links;
 mod share;
 pub mod subgroups;
+mod transfer;
 mod unshare;
 
+pub use audit_events::GroupAuditEvents;
+pub use audit_events::GroupAuditEventsBuilder;
+pub use audit_events::GroupAuditEventsBuilderError;
+
 pub use create::BranchProtection;
 pub use create::BranchProtectionAccessLevel;
 pub use create::BranchProtectionDefaults;
@@ -39,6 +53,10 @@ pub use create::GroupProjectCreationAccessLevel;
 pub use create::SharedRunnersMinutesLimit;
 pub use create::SubgroupCreationAccessLevel;
 
+pub use delete::DeleteGroup;
+pub use delete::DeleteGroupBuilder;
+pub use delete::DeleteGroupBuilderError;
+
 pub use edit::EditGroup;
 pub use edit::EditGroupBuilder;
 pub use edit::EditGroupBuilderError;
@@ -57,6 +75,10 @@ pub use share::ShareGroup;
 pub use share::ShareGroupBuilder;
 pub use share::ShareGroupBuilderError;
 
+pub use transfer::TransferGroup;
+pub use transfer::TransferGroupBuilder;
+pub use transfer::TransferGroupBuilderError;
+
 pub use unshare::UnshareGroup;
 pub use unshare::UnshareGroupBuilder;
 pub use unshare::UnshareGroupBuilderError;
@@ -0,0 +1,36 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group access token API endpoints
+//!
+//! These endpoints are used for querying and modifying group access tokens. Scopes and access
+//! levels are shared with project access tokens.
+
+mod access_token;
+mod access_tokens;
+mod create;
+mod revoke;
+mod rotate;
+
+pub use self::access_token::GroupAccessToken;
+pub use self::access_token::GroupAccessTokenBuilder;
+pub use self::access_token::GroupAccessTokenBuilderError;
+
+pub use self::access_tokens::GroupAccessTokens;
+pub use self::access_tokens::GroupAccessTokensBuilder;
+pub use self::access_tokens::GroupAccessTokensBuilderError;
+
+pub use self::create::CreateGroupAccessToken;
+pub use self::create::CreateGroupAccessTokenBuilder;
+pub use self::create::CreateGroupAccessTokenBuilderError;
+
+pub use self::revoke::RevokeGroupAccessToken;
+pub use self::revoke::RevokeGroupAccessTokenBuilder;
+pub use self::revoke::RevokeGroupAccessTokenBuilderError;
+
+pub use self::rotate::RotateGroupAccessToken;
+pub use self::rotate::RotateGroupAccessTokenBuilder;
+pub use self::rotate::RotateGroupAccessTokenBuilderError;
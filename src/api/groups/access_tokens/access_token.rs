@@ -0,0 +1,86 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Get a single group access token.
+#[derive(Debug, Builder, Clone)]
+pub struct GroupAccessToken<'a> {
+    /// The ID of the group.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the group access token.
+    id: u64,
+}
+
+impl<'a> GroupAccessToken<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupAccessTokenBuilder<'a> {
+        GroupAccessTokenBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for GroupAccessToken<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/access_tokens/{}", self.group, self.id).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::access_tokens::{GroupAccessToken, GroupAccessTokenBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_id_is_necessary() {
+        let err = GroupAccessToken::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupAccessTokenBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = GroupAccessToken::builder().id(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupAccessTokenBuilderError, "group");
+    }
+
+    #[test]
+    fn id_is_necessary() {
+        let err = GroupAccessToken::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupAccessTokenBuilderError, "id");
+    }
+
+    #[test]
+    fn group_and_id_are_sufficient() {
+        GroupAccessToken::builder().group(1).id(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::GET)
+            .endpoint("groups/foo/access_tokens/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupAccessToken::builder()
+            .group("foo")
+            .id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
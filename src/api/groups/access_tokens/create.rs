@@ -0,0 +1,251 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{AccessLevel, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::access_tokens::{ProjectAccessTokenAccessLevel, ProjectAccessTokenScope};
+
+/// Create a new access token for a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreateGroupAccessToken<'a> {
+    /// The group to create the access token for.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The name of the access token.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    /// The scopes to allow the token to access.
+    #[builder(setter(name = "_scopes"), private)]
+    scopes: BTreeSet<ProjectAccessTokenScope>,
+
+    /// The access level of the token within the group.
+    #[builder(default)]
+    access_level: Option<ProjectAccessTokenAccessLevel>,
+    /// When the token expires.
+    #[builder(default)]
+    expires_at: Option<NaiveDate>,
+}
+
+impl<'a> CreateGroupAccessToken<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateGroupAccessTokenBuilder<'a> {
+        CreateGroupAccessTokenBuilder::default()
+    }
+}
+
+impl<'a> CreateGroupAccessTokenBuilder<'a> {
+    /// Add a scope for the token.
+    pub fn scope(&mut self, scope: ProjectAccessTokenScope) -> &mut Self {
+        self.scopes.get_or_insert_with(BTreeSet::new).insert(scope);
+        self
+    }
+
+    /// Add scopes for the token.
+    pub fn scopes<I>(&mut self, scopes: I) -> &mut Self
+    where
+        I: Iterator<Item = ProjectAccessTokenScope>,
+    {
+        self.scopes.get_or_insert_with(BTreeSet::new).extend(scopes);
+        self
+    }
+}
+
+impl<'a> Endpoint for CreateGroupAccessToken<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/access_tokens", self.group).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("name", &self.name)
+            .push_opt(
+                "access_level",
+                self.access_level.map(|a| AccessLevel::from(a).as_u64()),
+            )
+            .push_opt("expires_at", self.expires_at);
+
+        params.extend(self.scopes.iter().map(|&value| ("scopes[]", value)));
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+
+    use crate::api::groups::access_tokens::{
+        CreateGroupAccessToken, CreateGroupAccessTokenBuilderError,
+    };
+    use crate::api::projects::access_tokens::{
+        ProjectAccessTokenAccessLevel, ProjectAccessTokenScope,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_name_and_scopes_are_necessary() {
+        let err = CreateGroupAccessToken::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupAccessTokenBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = CreateGroupAccessToken::builder()
+            .name("name")
+            .scope(ProjectAccessTokenScope::K8sProxy)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupAccessTokenBuilderError, "group");
+    }
+
+    #[test]
+    fn name_is_necessary() {
+        let err = CreateGroupAccessToken::builder()
+            .group(1)
+            .scope(ProjectAccessTokenScope::K8sProxy)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupAccessTokenBuilderError, "name");
+    }
+
+    #[test]
+    fn scopes_is_necessary() {
+        let err = CreateGroupAccessToken::builder()
+            .group(1)
+            .name("name")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupAccessTokenBuilderError, "scopes");
+    }
+
+    #[test]
+    fn group_name_and_scopes_are_sufficient() {
+        CreateGroupAccessToken::builder()
+            .group(1)
+            .name("name")
+            .scope(ProjectAccessTokenScope::K8sProxy)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/1/access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=name", "&scopes%5B%5D=k8s_proxy"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupAccessToken::builder()
+            .group(1)
+            .name("name")
+            .scopes([ProjectAccessTokenScope::K8sProxy].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_access_level() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/1/access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=name",
+                "&access_level=30",
+                "&scopes%5B%5D=k8s_proxy",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupAccessToken::builder()
+            .group(1)
+            .name("name")
+            .scope(ProjectAccessTokenScope::K8sProxy)
+            .access_level(ProjectAccessTokenAccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_expires_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/1/access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=name",
+                "&expires_at=2022-01-01",
+                "&scopes%5B%5D=k8s_proxy",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupAccessToken::builder()
+            .group(1)
+            .name("name")
+            .scope(ProjectAccessTokenScope::K8sProxy)
+            .expires_at(NaiveDate::from_ymd_opt(2022, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_scopes() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=name",
+                "&scopes%5B%5D=api",
+                "&scopes%5B%5D=read_repository",
+                "&scopes%5B%5D=write_repository",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupAccessToken::builder()
+            .group("simple/group")
+            .name("name")
+            .scope(ProjectAccessTokenScope::WriteRepository)
+            .scopes(
+                [
+                    ProjectAccessTokenScope::Api,
+                    ProjectAccessTokenScope::ReadRepository,
+                    ProjectAccessTokenScope::Api,
+                ]
+                .iter()
+                .cloned(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
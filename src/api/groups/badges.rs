@@ -0,0 +1,42 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group badge API endpoints.
+//!
+//! These endpoints are used for querying and modifying badges of a group. Group badges are
+//! shown on every project within the group and their URLs may contain the same placeholders as
+//! project badges (e.g., `%{project_path}`), which are expanded for each project.
+
+mod add;
+mod badge;
+mod badges;
+mod delete;
+mod edit;
+mod render;
+
+pub use self::add::AddGroupBadge;
+pub use self::add::AddGroupBadgeBuilder;
+pub use self::add::AddGroupBadgeBuilderError;
+
+pub use self::badge::GroupBadge;
+pub use self::badge::GroupBadgeBuilder;
+pub use self::badge::GroupBadgeBuilderError;
+
+pub use self::badges::GroupBadges;
+pub use self::badges::GroupBadgesBuilder;
+pub use self::badges::GroupBadgesBuilderError;
+
+pub use self::delete::DeleteGroupBadge;
+pub use self::delete::DeleteGroupBadgeBuilder;
+pub use self::delete::DeleteGroupBadgeBuilderError;
+
+pub use self::edit::EditGroupBadge;
+pub use self::edit::EditGroupBadgeBuilder;
+pub use self::edit::EditGroupBadgeBuilderError;
+
+pub use self::render::RenderGroupBadge;
+pub use self::render::RenderGroupBadgeBuilder;
+pub use self::render::RenderGroupBadgeBuilderError;
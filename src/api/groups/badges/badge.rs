@@ -0,0 +1,83 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for a badge of a group.
+#[derive(Debug, Builder, Clone)]
+pub struct GroupBadge<'a> {
+    /// The group of the badge.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the badge.
+    badge: u64,
+}
+
+impl<'a> GroupBadge<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupBadgeBuilder<'a> {
+        GroupBadgeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for GroupBadge<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/badges/{}", self.group, self.badge).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::badges::{GroupBadge, GroupBadgeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_badge_are_needed() {
+        let err = GroupBadge::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupBadgeBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupBadge::builder().badge(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupBadgeBuilderError, "group");
+    }
+
+    #[test]
+    fn badge_is_needed() {
+        let err = GroupBadge::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupBadgeBuilderError, "badge");
+    }
+
+    #[test]
+    fn group_and_badge_are_sufficient() {
+        GroupBadge::builder().group(1).badge(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/badges/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupBadge::builder()
+            .group("simple/group")
+            .badge(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,100 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for badges of a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct GroupBadges<'a> {
+    /// The group to query for badges.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// Filter badges by name.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+}
+
+impl<'a> GroupBadges<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupBadgesBuilder<'a> {
+        GroupBadgesBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for GroupBadges<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/badges", self.group).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("name", self.name.as_ref());
+
+        params
+    }
+}
+
+impl<'a> Pageable for GroupBadges<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::badges::{GroupBadges, GroupBadgesBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupBadges::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupBadgesBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupBadges::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/badges")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupBadges::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/badges")
+            .add_query_params(&[("name", "coverage")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupBadges::builder()
+            .group("simple/group")
+            .name("coverage")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,90 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete a badge from a group.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteGroupBadge<'a> {
+    /// The group of the badge.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the badge.
+    badge: u64,
+}
+
+impl<'a> DeleteGroupBadge<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteGroupBadgeBuilder<'a> {
+        DeleteGroupBadgeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteGroupBadge<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/badges/{}", self.group, self.badge).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::badges::{DeleteGroupBadge, DeleteGroupBadgeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_badge_are_needed() {
+        let err = DeleteGroupBadge::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupBadgeBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = DeleteGroupBadge::builder().badge(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupBadgeBuilderError, "group");
+    }
+
+    #[test]
+    fn badge_is_needed() {
+        let err = DeleteGroupBadge::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupBadgeBuilderError, "badge");
+    }
+
+    #[test]
+    fn group_and_badge_are_sufficient() {
+        DeleteGroupBadge::builder()
+            .group(1)
+            .badge(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/badges/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroupBadge::builder()
+            .group("simple/group")
+            .badge(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
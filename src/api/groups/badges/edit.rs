@@ -0,0 +1,170 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Edit a badge of a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditGroupBadge<'a> {
+    /// The group of the badge.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the badge.
+    badge: u64,
+
+    /// The URL the badge links to.
+    #[builder(setter(into), default)]
+    link_url: Option<Cow<'a, str>>,
+    /// The URL of the badge image.
+    #[builder(setter(into), default)]
+    image_url: Option<Cow<'a, str>>,
+    /// The name of the badge.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+}
+
+impl<'a> EditGroupBadge<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditGroupBadgeBuilder<'a> {
+        EditGroupBadgeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for EditGroupBadge<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/badges/{}", self.group, self.badge).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("link_url", self.link_url.as_ref())
+            .push_opt("image_url", self.image_url.as_ref())
+            .push_opt("name", self.name.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::badges::{EditGroupBadge, EditGroupBadgeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_badge_are_needed() {
+        let err = EditGroupBadge::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupBadgeBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = EditGroupBadge::builder().badge(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupBadgeBuilderError, "group");
+    }
+
+    #[test]
+    fn badge_is_needed() {
+        let err = EditGroupBadge::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupBadgeBuilderError, "badge");
+    }
+
+    #[test]
+    fn group_and_badge_are_sufficient() {
+        EditGroupBadge::builder().group(1).badge(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/badges/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupBadge::builder()
+            .group("simple/group")
+            .badge(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_link_url() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/badges/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("link_url=https%3A%2F%2Fexample.com")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupBadge::builder()
+            .group("simple/group")
+            .badge(1)
+            .link_url("https://example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_image_url() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/badges/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("image_url=https%3A%2F%2Fexample.com%2Fbadge.svg")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupBadge::builder()
+            .group("simple/group")
+            .badge(1)
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/badges/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=coverage")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupBadge::builder()
+            .group("simple/group")
+            .badge(1)
+            .name("coverage")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
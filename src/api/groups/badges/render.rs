@@ -0,0 +1,127 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Preview how badge URLs are rendered for a group.
+///
+/// This shows how GitLab would render the URLs of a badge added to the group.
+#[derive(Debug, Builder, Clone)]
+pub struct RenderGroupBadge<'a> {
+    /// The group to render the badge for.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The URL the badge links to.
+    #[builder(setter(into))]
+    link_url: Cow<'a, str>,
+    /// The URL of the badge image.
+    #[builder(setter(into))]
+    image_url: Cow<'a, str>,
+}
+
+impl<'a> RenderGroupBadge<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RenderGroupBadgeBuilder<'a> {
+        RenderGroupBadgeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for RenderGroupBadge<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/badges/render", self.group).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push("link_url", &self.link_url)
+            .push("image_url", &self.image_url);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::badges::{RenderGroupBadge, RenderGroupBadgeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_link_url_and_image_url_are_needed() {
+        let err = RenderGroupBadge::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RenderGroupBadgeBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = RenderGroupBadge::builder()
+            .link_url("https://example.com")
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RenderGroupBadgeBuilderError, "group");
+    }
+
+    #[test]
+    fn link_url_is_needed() {
+        let err = RenderGroupBadge::builder()
+            .group(1)
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RenderGroupBadgeBuilderError, "link_url");
+    }
+
+    #[test]
+    fn image_url_is_needed() {
+        let err = RenderGroupBadge::builder()
+            .group(1)
+            .link_url("https://example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RenderGroupBadgeBuilderError, "image_url");
+    }
+
+    #[test]
+    fn group_link_url_and_image_url_are_sufficient() {
+        RenderGroupBadge::builder()
+            .group(1)
+            .link_url("https://example.com")
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/badges/render")
+            .add_query_params(&[
+                ("link_url", "https://example.com/%{project_path}"),
+                ("image_url", "https://example.com/%{default_branch}.svg"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RenderGroupBadge::builder()
+            .group("simple/group")
+            .link_url("https://example.com/%{project_path}")
+            .image_url("https://example.com/%{default_branch}.svg")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,31 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group custom attribute API endpoints.
+//!
+//! These endpoints are used for querying and modifying custom attributes on groups. Custom
+//! attributes are key/value pairs which may only be managed by administrators.
+
+mod custom_attribute;
+mod custom_attributes;
+mod delete;
+mod set;
+
+pub use self::custom_attribute::GroupCustomAttribute;
+pub use self::custom_attribute::GroupCustomAttributeBuilder;
+pub use self::custom_attribute::GroupCustomAttributeBuilderError;
+
+pub use self::custom_attributes::GroupCustomAttributes;
+pub use self::custom_attributes::GroupCustomAttributesBuilder;
+pub use self::custom_attributes::GroupCustomAttributesBuilderError;
+
+pub use self::delete::DeleteGroupCustomAttribute;
+pub use self::delete::DeleteGroupCustomAttributeBuilder;
+pub use self::delete::DeleteGroupCustomAttributeBuilderError;
+
+pub use self::set::SetGroupCustomAttribute;
+pub use self::set::SetGroupCustomAttributeBuilder;
+pub use self::set::SetGroupCustomAttributeBuilderError;
@@ -0,0 +1,117 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a custom attribute on a group.
+#[derive(Debug, Builder, Clone)]
+pub struct GroupCustomAttribute<'a> {
+    /// The group to query for the custom attribute.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The key of the custom attribute.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+}
+
+impl<'a> GroupCustomAttribute<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupCustomAttributeBuilder<'a> {
+        GroupCustomAttributeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for GroupCustomAttribute<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/custom_attributes/{}",
+            self.group,
+            common::path_escaped(&self.key),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::custom_attributes::{
+        GroupCustomAttribute, GroupCustomAttributeBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_key_are_necessary() {
+        let err = GroupCustomAttribute::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupCustomAttributeBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = GroupCustomAttribute::builder()
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupCustomAttributeBuilderError, "group");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = GroupCustomAttribute::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupCustomAttributeBuilderError, "key");
+    }
+
+    #[test]
+    fn group_and_key_are_sufficient() {
+        GroupCustomAttribute::builder()
+            .group(1)
+            .key("key")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/custom_attributes/key")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupCustomAttribute::builder()
+            .group("simple/group")
+            .key("key")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_key_escaped() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/custom_attributes/external%2Fid")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupCustomAttribute::builder()
+            .group("simple/group")
+            .key("external/id")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
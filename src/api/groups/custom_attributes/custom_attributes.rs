@@ -0,0 +1,70 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for custom attributes on a group.
+#[derive(Debug, Builder, Clone)]
+pub struct GroupCustomAttributes<'a> {
+    /// The group to query for custom attributes.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> GroupCustomAttributes<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupCustomAttributesBuilder<'a> {
+        GroupCustomAttributesBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for GroupCustomAttributes<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/custom_attributes", self.group).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::custom_attributes::{
+        GroupCustomAttributes, GroupCustomAttributesBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupCustomAttributes::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupCustomAttributesBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupCustomAttributes::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/custom_attributes")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupCustomAttributes::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
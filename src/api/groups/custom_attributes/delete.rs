@@ -0,0 +1,121 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a custom attribute from a group.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteGroupCustomAttribute<'a> {
+    /// The group to delete the custom attribute from.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The key of the custom attribute.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+}
+
+impl<'a> DeleteGroupCustomAttribute<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteGroupCustomAttributeBuilder<'a> {
+        DeleteGroupCustomAttributeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteGroupCustomAttribute<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/custom_attributes/{}",
+            self.group,
+            common::path_escaped(&self.key),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::custom_attributes::{
+        DeleteGroupCustomAttribute, DeleteGroupCustomAttributeBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_key_are_necessary() {
+        let err = DeleteGroupCustomAttribute::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupCustomAttributeBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = DeleteGroupCustomAttribute::builder()
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupCustomAttributeBuilderError, "group");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = DeleteGroupCustomAttribute::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupCustomAttributeBuilderError, "key");
+    }
+
+    #[test]
+    fn group_and_key_are_sufficient() {
+        DeleteGroupCustomAttribute::builder()
+            .group(1)
+            .key("key")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/custom_attributes/key")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroupCustomAttribute::builder()
+            .group("simple/group")
+            .key("key")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_key_escaped() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/custom_attributes/external%2Fid")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroupCustomAttribute::builder()
+            .group("simple/group")
+            .key("external/id")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,151 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Set a custom attribute on a group.
+#[derive(Debug, Builder, Clone)]
+pub struct SetGroupCustomAttribute<'a> {
+    /// The group to set the custom attribute on.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The key of the custom attribute.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+    /// The value of the custom attribute.
+    #[builder(setter(into))]
+    value: Cow<'a, str>,
+}
+
+impl<'a> SetGroupCustomAttribute<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SetGroupCustomAttributeBuilder<'a> {
+        SetGroupCustomAttributeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for SetGroupCustomAttribute<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/custom_attributes/{}",
+            self.group,
+            common::path_escaped(&self.key),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("value", &self.value);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::custom_attributes::{
+        SetGroupCustomAttribute, SetGroupCustomAttributeBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_key_and_value_are_necessary() {
+        let err = SetGroupCustomAttribute::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SetGroupCustomAttributeBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = SetGroupCustomAttribute::builder()
+            .key("key")
+            .value("value")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetGroupCustomAttributeBuilderError, "group");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = SetGroupCustomAttribute::builder()
+            .group(1)
+            .value("value")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetGroupCustomAttributeBuilderError, "key");
+    }
+
+    #[test]
+    fn value_is_necessary() {
+        let err = SetGroupCustomAttribute::builder()
+            .group(1)
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetGroupCustomAttributeBuilderError, "value");
+    }
+
+    #[test]
+    fn group_key_and_value_are_sufficient() {
+        SetGroupCustomAttribute::builder()
+            .group(1)
+            .key("key")
+            .value("value")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/custom_attributes/key")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("value=value")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetGroupCustomAttribute::builder()
+            .group("simple/group")
+            .key("key")
+            .value("value")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_key_escaped() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/custom_attributes/external%2Fid")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("value=value")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetGroupCustomAttribute::builder()
+            .group("simple/group")
+            .key("external/id")
+            .value("value")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,74 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete a group.
+///
+/// Depending on the instance configuration, the group may only be marked for deletion
+/// rather than being removed immediately.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteGroup<'a> {
+    /// The group to delete.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> DeleteGroup<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteGroupBuilder<'a> {
+        DeleteGroupBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteGroup<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}", self.group).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::{DeleteGroup, DeleteGroupBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = DeleteGroup::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        DeleteGroup::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroup::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
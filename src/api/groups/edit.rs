@@ -1120,4 +1120,29 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_creation_and_protection_levels() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "project_creation_level=developer",
+                "&subgroup_creation_level=maintainer",
+                "&default_branch_protection=1",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroup::builder()
+            .group("simple/group")
+            .default_branch_protection(BranchProtection::Partial)
+            .project_creation_level(GroupProjectCreationAccessLevel::Developer)
+            .subgroup_creation_level(SubgroupCreationAccessLevel::Maintainer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -0,0 +1,41 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group epic API endpoints.
+//!
+//! These endpoints are used for querying and modifying group epics.
+//!
+//! Epics are only available on GitLab Premium and Ultimate. Other instances respond with a
+//! `403 Forbidden` or `404 Not Found` error which is reported as an `ApiError::GitlabService`.
+
+mod create;
+mod delete;
+mod edit;
+mod epic;
+mod epics;
+
+pub use self::create::CreateEpic;
+pub use self::create::CreateEpicBuilder;
+pub use self::create::CreateEpicBuilderError;
+
+pub use self::delete::DeleteEpic;
+pub use self::delete::DeleteEpicBuilder;
+pub use self::delete::DeleteEpicBuilderError;
+
+pub use self::edit::EditEpic;
+pub use self::edit::EditEpicBuilder;
+pub use self::edit::EditEpicBuilderError;
+pub use self::edit::EpicStateEvent;
+
+pub use self::epic::Epic;
+pub use self::epic::EpicBuilder;
+pub use self::epic::EpicBuilderError;
+
+pub use self::epics::EpicOrderBy;
+pub use self::epics::EpicState;
+pub use self::epics::Epics;
+pub use self::epics::EpicsBuilder;
+pub use self::epics::EpicsBuilderError;
@@ -0,0 +1,328 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{CommaSeparatedList, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Create a new epic within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreateEpic<'a> {
+    /// The group to create the epic within.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The title of the epic.
+    #[builder(setter(into))]
+    title: Cow<'a, str>,
+
+    /// The description of the epic.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// Labels to add to the epic.
+    #[builder(setter(name = "_labels"), default, private)]
+    labels: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Whether the epic is confidential or not.
+    #[builder(default)]
+    confidential: Option<bool>,
+    /// The ID of the parent epic.
+    #[builder(default)]
+    parent_id: Option<u64>,
+    /// The color of the epic (e.g., `#1068bf`).
+    #[builder(setter(into), default)]
+    color: Option<Cow<'a, str>>,
+
+    /// Whether the start date is fixed or derived from its milestones.
+    #[builder(default)]
+    start_date_is_fixed: Option<bool>,
+    /// The fixed start date of the epic.
+    #[builder(default)]
+    start_date_fixed: Option<NaiveDate>,
+    /// Whether the due date is fixed or derived from its milestones.
+    #[builder(default)]
+    due_date_is_fixed: Option<bool>,
+    /// The fixed due date of the epic.
+    #[builder(default)]
+    due_date_fixed: Option<NaiveDate>,
+}
+
+impl<'a> CreateEpic<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateEpicBuilder<'a> {
+        CreateEpicBuilder::default()
+    }
+}
+
+impl<'a> CreateEpicBuilder<'a> {
+    /// Add a label to the epic.
+    pub fn label<L>(&mut self, label: L) -> &mut Self
+    where
+        L: Into<Cow<'a, str>>,
+    {
+        self.labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(label.into());
+        self
+    }
+
+    /// Add a set of labels to the epic.
+    pub fn labels<I, L>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<Cow<'a, str>>,
+    {
+        self.labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set a fixed start date for the epic.
+    pub fn start_date(&mut self, date: NaiveDate) -> &mut Self {
+        self.start_date_is_fixed(true).start_date_fixed(date)
+    }
+
+    /// Set a fixed due date for the epic.
+    pub fn due_date(&mut self, date: NaiveDate) -> &mut Self {
+        self.due_date_is_fixed(true).due_date_fixed(date)
+    }
+}
+
+impl<'a> Endpoint for CreateEpic<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/epics", self.group).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("title", &self.title)
+            .push_opt("description", self.description.as_ref())
+            .push_opt("labels", self.labels.as_ref())
+            .push_opt("confidential", self.confidential)
+            .push_opt("parent_id", self.parent_id)
+            .push_opt("color", self.color.as_ref())
+            .push_opt("start_date_is_fixed", self.start_date_is_fixed)
+            .push_opt("start_date_fixed", self.start_date_fixed)
+            .push_opt("due_date_is_fixed", self.due_date_is_fixed)
+            .push_opt("due_date_fixed", self.due_date_fixed);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+
+    use crate::api::groups::epics::{CreateEpic, CreateEpicBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_title_are_necessary() {
+        let err = CreateEpic::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateEpicBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = CreateEpic::builder().title("title").build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateEpicBuilderError, "group");
+    }
+
+    #[test]
+    fn title_is_necessary() {
+        let err = CreateEpic::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateEpicBuilderError, "title");
+    }
+
+    #[test]
+    fn group_and_title_are_sufficient() {
+        CreateEpic::builder()
+            .group(1)
+            .title("title")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/epics")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("title=title")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEpic::builder()
+            .group("simple/group")
+            .title("title")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/epics")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("title=title", "&description=description"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEpic::builder()
+            .group("simple/group")
+            .title("title")
+            .description("description")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_labels() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/epics")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("title=title", "&labels=label%2Clabel1%2Clabel2"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEpic::builder()
+            .group("simple/group")
+            .title("title")
+            .label("label")
+            .labels(["label1", "label2"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_confidential() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/epics")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("title=title", "&confidential=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEpic::builder()
+            .group("simple/group")
+            .title("title")
+            .confidential(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_parent_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/epics")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("title=title", "&parent_id=1"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEpic::builder()
+            .group("simple/group")
+            .title("title")
+            .parent_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_color() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/epics")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("title=title", "&color=%231068bf"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEpic::builder()
+            .group("simple/group")
+            .title("title")
+            .color("#1068bf")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_dates() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/epics")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "title=title",
+                "&start_date_is_fixed=true",
+                "&start_date_fixed=2020-01-01",
+                "&due_date_is_fixed=true",
+                "&due_date_fixed=2020-12-31",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEpic::builder()
+            .group("simple/group")
+            .title("title")
+            .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .due_date(NaiveDate::from_ymd_opt(2020, 12, 31).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_start_date_not_fixed() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/epics")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("title=title", "&start_date_is_fixed=false"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEpic::builder()
+            .group("simple/group")
+            .title("title")
+            .start_date_is_fixed(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
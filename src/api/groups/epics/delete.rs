@@ -0,0 +1,86 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete an epic within a group.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteEpic<'a> {
+    /// The group of the epic.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The internal ID of the epic.
+    epic: u64,
+}
+
+impl<'a> DeleteEpic<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteEpicBuilder<'a> {
+        DeleteEpicBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteEpic<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/epics/{}", self.group, self.epic).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::epics::{DeleteEpic, DeleteEpicBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_epic_are_needed() {
+        let err = DeleteEpic::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteEpicBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = DeleteEpic::builder().epic(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteEpicBuilderError, "group");
+    }
+
+    #[test]
+    fn epic_is_needed() {
+        let err = DeleteEpic::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteEpicBuilderError, "epic");
+    }
+
+    #[test]
+    fn group_and_epic_are_sufficient() {
+        DeleteEpic::builder().group(1).epic(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/epics/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
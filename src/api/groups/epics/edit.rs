@@ -0,0 +1,449 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{CommaSeparatedList, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// States an epic may be set to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EpicStateEvent {
+    /// Close the epic.
+    Close,
+    /// Reopen a closed epic.
+    Reopen,
+}
+
+impl EpicStateEvent {
+    fn as_str(self) -> &'static str {
+        match self {
+            EpicStateEvent::Close => "close",
+            EpicStateEvent::Reopen => "reopen",
+        }
+    }
+}
+
+impl ParamValue<'static> for EpicStateEvent {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Edit an epic within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditEpic<'a> {
+    /// The group of the epic.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The internal ID of the epic.
+    epic: u64,
+
+    /// The new title of the epic.
+    #[builder(setter(into), default)]
+    title: Option<Cow<'a, str>>,
+    /// The new description of the epic.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// The labels to set on the epic.
+    #[builder(setter(name = "_labels"), default, private)]
+    labels: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Labels to add to the epic.
+    #[builder(setter(name = "_add_labels"), default, private)]
+    add_labels: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Labels to remove from the epic.
+    #[builder(setter(name = "_remove_labels"), default, private)]
+    remove_labels: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Whether the epic is confidential or not.
+    #[builder(default)]
+    confidential: Option<bool>,
+    /// The ID of the parent epic.
+    #[builder(default)]
+    parent_id: Option<u64>,
+    /// The color of the epic (e.g., `#1068bf`).
+    #[builder(setter(into), default)]
+    color: Option<Cow<'a, str>>,
+    /// Change the state of the epic.
+    #[builder(default)]
+    state_event: Option<EpicStateEvent>,
+
+    /// Whether the start date is fixed or derived from its milestones.
+    #[builder(default)]
+    start_date_is_fixed: Option<bool>,
+    /// The fixed start date of the epic.
+    #[builder(default)]
+    start_date_fixed: Option<NaiveDate>,
+    /// Whether the due date is fixed or derived from its milestones.
+    #[builder(default)]
+    due_date_is_fixed: Option<bool>,
+    /// The fixed due date of the epic.
+    #[builder(default)]
+    due_date_fixed: Option<NaiveDate>,
+}
+
+impl<'a> EditEpic<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditEpicBuilder<'a> {
+        EditEpicBuilder::default()
+    }
+}
+
+impl<'a> EditEpicBuilder<'a> {
+    /// Add a label to the epic.
+    ///
+    /// Note that the list of labels sent will replace the set on the instance. This only adds it
+    /// to the list of labels to add to the set before sending it to the instance.
+    pub fn label<L>(&mut self, label: L) -> &mut Self
+    where
+        L: Into<Cow<'a, str>>,
+    {
+        self.labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(label.into());
+        self
+    }
+
+    /// Add a set of labels to the epic.
+    ///
+    /// Note that the list of labels sent will replace the set on the instance. This only adds it
+    /// to the list of labels to add to the set before sending it to the instance.
+    pub fn labels<I, L>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<Cow<'a, str>>,
+    {
+        self.labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add a label to the epic.
+    ///
+    /// This is an incremental addition to the existing set of labels on the epic.
+    pub fn add_label<L>(&mut self, label: L) -> &mut Self
+    where
+        L: Into<Cow<'a, str>>,
+    {
+        self.add_labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(label.into());
+        self
+    }
+
+    /// Remove a label from the epic.
+    ///
+    /// This is an incremental removal from the existing set of labels on the epic.
+    pub fn remove_label<L>(&mut self, label: L) -> &mut Self
+    where
+        L: Into<Cow<'a, str>>,
+    {
+        self.remove_labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(label.into());
+        self
+    }
+
+    /// Set a fixed start date for the epic.
+    pub fn start_date(&mut self, date: NaiveDate) -> &mut Self {
+        self.start_date_is_fixed(true).start_date_fixed(date)
+    }
+
+    /// Set a fixed due date for the epic.
+    pub fn due_date(&mut self, date: NaiveDate) -> &mut Self {
+        self.due_date_is_fixed(true).due_date_fixed(date)
+    }
+}
+
+impl<'a> Endpoint for EditEpic<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/epics/{}", self.group, self.epic).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("title", self.title.as_ref())
+            .push_opt("description", self.description.as_ref())
+            .push_opt("labels", self.labels.as_ref())
+            .push_opt("add_labels", self.add_labels.as_ref())
+            .push_opt("remove_labels", self.remove_labels.as_ref())
+            .push_opt("confidential", self.confidential)
+            .push_opt("parent_id", self.parent_id)
+            .push_opt("color", self.color.as_ref())
+            .push_opt("state_event", self.state_event)
+            .push_opt("start_date_is_fixed", self.start_date_is_fixed)
+            .push_opt("start_date_fixed", self.start_date_fixed)
+            .push_opt("due_date_is_fixed", self.due_date_is_fixed)
+            .push_opt("due_date_fixed", self.due_date_fixed);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+
+    use crate::api::groups::epics::{EditEpic, EditEpicBuilderError, EpicStateEvent};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn epic_state_event_as_str() {
+        let items = &[
+            (EpicStateEvent::Close, "close"),
+            (EpicStateEvent::Reopen, "reopen"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn group_and_epic_are_necessary() {
+        let err = EditEpic::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditEpicBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = EditEpic::builder().epic(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditEpicBuilderError, "group");
+    }
+
+    #[test]
+    fn epic_is_necessary() {
+        let err = EditEpic::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditEpicBuilderError, "epic");
+    }
+
+    #[test]
+    fn group_and_epic_are_sufficient() {
+        EditEpic::builder().group(1).epic(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_title() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("title=title")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .title("title")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("description=description")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .description("description")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_labels() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("labels=label%2Clabel1%2Clabel2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .label("label")
+            .labels(["label1", "label2"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_add_remove_labels() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("add_labels=added", "&remove_labels=removed"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .add_label("added")
+            .remove_label("removed")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_confidential() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("confidential=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .confidential(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_parent_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("parent_id=2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .parent_id(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_color() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("color=%231068bf")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .color("#1068bf")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_state_event() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("state_event=close")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .state_event(EpicStateEvent::Close)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_dates() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "start_date_is_fixed=true",
+                "&start_date_fixed=2020-01-01",
+                "&due_date_is_fixed=false",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .due_date_is_fixed(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
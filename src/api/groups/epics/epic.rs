@@ -0,0 +1,83 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for an epic within a group.
+#[derive(Debug, Builder, Clone)]
+pub struct Epic<'a> {
+    /// The group of the epic.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The internal ID of the epic.
+    epic: u64,
+}
+
+impl<'a> Epic<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EpicBuilder<'a> {
+        EpicBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for Epic<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/epics/{}", self.group, self.epic).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::epics::{Epic, EpicBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_epic_are_needed() {
+        let err = Epic::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EpicBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = Epic::builder().epic(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EpicBuilderError, "group");
+    }
+
+    #[test]
+    fn epic_is_needed() {
+        let err = Epic::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EpicBuilderError, "epic");
+    }
+
+    #[test]
+    fn group_and_epic_are_sufficient() {
+        Epic::builder().group(1).epic(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Epic::builder()
+            .group("simple/group")
+            .epic(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,460 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::{CommaSeparatedList, NameOrId, SortOrder};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Filters for epic states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EpicState {
+    /// Filter epics that are open.
+    Opened,
+    /// Filter epics that are closed.
+    Closed,
+    /// Return all epics.
+    All,
+}
+
+impl EpicState {
+    fn as_str(self) -> &'static str {
+        match self {
+            EpicState::Opened => "opened",
+            EpicState::Closed => "closed",
+            EpicState::All => "all",
+        }
+    }
+}
+
+impl ParamValue<'static> for EpicState {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Keys epic results may be ordered by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EpicOrderBy {
+    /// Sort by creation date.
+    CreatedAt,
+    /// Sort by last updated date.
+    UpdatedAt,
+    /// Sort by title.
+    Title,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for EpicOrderBy {
+    fn default() -> Self {
+        // XXX(rust-1.62): use `#[default]`
+        EpicOrderBy::CreatedAt
+    }
+}
+
+impl EpicOrderBy {
+    fn as_str(self) -> &'static str {
+        match self {
+            EpicOrderBy::CreatedAt => "created_at",
+            EpicOrderBy::UpdatedAt => "updated_at",
+            EpicOrderBy::Title => "title",
+        }
+    }
+}
+
+impl ParamValue<'static> for EpicOrderBy {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for epics within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct Epics<'a> {
+    /// The group to query for epics.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// Filter epics by their state.
+    #[builder(default)]
+    state: Option<EpicState>,
+    /// Filter epics by their labels.
+    #[builder(setter(name = "_labels"), default, private)]
+    labels: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Filter epics by the ID of their author.
+    #[builder(default)]
+    author_id: Option<u64>,
+    /// Search for epics by their title or description.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+
+    /// Filter epics created after a point in time.
+    #[builder(default)]
+    created_after: Option<DateTime<Utc>>,
+    /// Filter epics created before a point in time.
+    #[builder(default)]
+    created_before: Option<DateTime<Utc>>,
+    /// Filter epics last updated after a point in time.
+    #[builder(default)]
+    updated_after: Option<DateTime<Utc>>,
+    /// Filter epics last updated before a point in time.
+    #[builder(default)]
+    updated_before: Option<DateTime<Utc>>,
+
+    /// Include epics from ancestor groups.
+    #[builder(default)]
+    include_ancestor_groups: Option<bool>,
+    /// Include epics from descendant groups.
+    #[builder(default)]
+    include_descendant_groups: Option<bool>,
+
+    /// Order results by a given key.
+    #[builder(default)]
+    order_by: Option<EpicOrderBy>,
+    /// The sort order for returned results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl<'a> Epics<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EpicsBuilder<'a> {
+        EpicsBuilder::default()
+    }
+}
+
+impl<'a> EpicsBuilder<'a> {
+    /// Filter epics by a label.
+    pub fn label<L>(&mut self, label: L) -> &mut Self
+    where
+        L: Into<Cow<'a, str>>,
+    {
+        self.labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(label.into());
+        self
+    }
+
+    /// Filter epics by a set of labels.
+    pub fn labels<I, L>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<Cow<'a, str>>,
+    {
+        self.labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+impl<'a> Endpoint for Epics<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/epics", self.group).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("state", self.state)
+            .push_opt("labels", self.labels.as_ref())
+            .push_opt("author_id", self.author_id)
+            .push_opt("search", self.search.as_ref())
+            .push_opt("created_after", self.created_after)
+            .push_opt("created_before", self.created_before)
+            .push_opt("updated_after", self.updated_after)
+            .push_opt("updated_before", self.updated_before)
+            .push_opt("include_ancestor_groups", self.include_ancestor_groups)
+            .push_opt("include_descendant_groups", self.include_descendant_groups)
+            .push_opt("order_by", self.order_by)
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl<'a> Pageable for Epics<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::api::common::SortOrder;
+    use crate::api::groups::epics::{EpicOrderBy, EpicState, Epics, EpicsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn epic_state_as_str() {
+        let items = &[
+            (EpicState::Opened, "opened"),
+            (EpicState::Closed, "closed"),
+            (EpicState::All, "all"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn order_by_default() {
+        assert_eq!(EpicOrderBy::default(), EpicOrderBy::CreatedAt);
+    }
+
+    #[test]
+    fn order_by_as_str() {
+        let items = &[
+            (EpicOrderBy::CreatedAt, "created_at"),
+            (EpicOrderBy::UpdatedAt, "updated_at"),
+            (EpicOrderBy::Title, "title"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = Epics::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EpicsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        Epics::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Epics::builder().group("simple/group").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_state() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics")
+            .add_query_params(&[("state", "closed")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Epics::builder()
+            .group("simple/group")
+            .state(EpicState::Closed)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_labels() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics")
+            .add_query_params(&[("labels", "label,label1,label2")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Epics::builder()
+            .group("simple/group")
+            .label("label")
+            .labels(["label1", "label2"].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_author_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics")
+            .add_query_params(&[("author_id", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Epics::builder()
+            .group("simple/group")
+            .author_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics")
+            .add_query_params(&[("search", "query")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Epics::builder()
+            .group("simple/group")
+            .search("query")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics")
+            .add_query_params(&[("created_after", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Epics::builder()
+            .group("simple/group")
+            .created_after(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics")
+            .add_query_params(&[("created_before", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Epics::builder()
+            .group("simple/group")
+            .created_before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_updated_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics")
+            .add_query_params(&[("updated_after", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Epics::builder()
+            .group("simple/group")
+            .updated_after(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_updated_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics")
+            .add_query_params(&[("updated_before", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Epics::builder()
+            .group("simple/group")
+            .updated_before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_ancestor_groups() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics")
+            .add_query_params(&[("include_ancestor_groups", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Epics::builder()
+            .group("simple/group")
+            .include_ancestor_groups(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_descendant_groups() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics")
+            .add_query_params(&[("include_descendant_groups", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Epics::builder()
+            .group("simple/group")
+            .include_descendant_groups(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_order_by() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics")
+            .add_query_params(&[("order_by", "title")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Epics::builder()
+            .group("simple/group")
+            .order_by(EpicOrderBy::Title)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics")
+            .add_query_params(&[("sort", "asc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Epics::builder()
+            .group("simple/group")
+            .sort(SortOrder::Ascending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -35,3 +35,10 @@ pub type IssueOrderBy = crate::api::issues::IssueOrderBy;
 pub type Issues<'a> = crate::api::issues::GroupIssues<'a>;
 /// Builder for [`Issues`].
 pub type IssuesBuilder<'a> = crate::api::issues::GroupIssuesBuilder<'a>;
+
+/// Query for statistics of issues within a group.
+///
+/// TODO: Negation (not) filters are not yet supported.
+pub type IssuesStatistics<'a> = crate::api::issues::GroupIssuesStatistics<'a>;
+/// Builder for [`IssuesStatistics`].
+pub type IssuesStatisticsBuilder<'a> = crate::api::issues::GroupIssuesStatisticsBuilder<'a>;
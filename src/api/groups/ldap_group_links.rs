@@ -0,0 +1,27 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group LDAP link API endpoints.
+//!
+//! These endpoints are used for querying and managing the LDAP groups synchronized with a group.
+//! A link identifies LDAP users either by the common name (`cn`) of an LDAP group or by an LDAP
+//! user filter.
+
+mod add;
+mod delete;
+mod ldap_group_links;
+
+pub use self::add::AddGroupLdapLink;
+pub use self::add::AddGroupLdapLinkBuilder;
+pub use self::add::AddGroupLdapLinkBuilderError;
+
+pub use self::delete::DeleteGroupLdapLink;
+pub use self::delete::DeleteGroupLdapLinkBuilder;
+pub use self::delete::DeleteGroupLdapLinkBuilderError;
+
+pub use self::ldap_group_links::GroupLdapLinks;
+pub use self::ldap_group_links::GroupLdapLinksBuilder;
+pub use self::ldap_group_links::GroupLdapLinksBuilderError;
@@ -0,0 +1,242 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{AccessLevel, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Add an LDAP link to a group.
+///
+/// Exactly one of `cn` or `filter` must be given.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct AddGroupLdapLink<'a> {
+    /// The group to add the LDAP link to.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The access level to grant to members of the LDAP group.
+    group_access: AccessLevel,
+    /// The LDAP provider for the link.
+    #[builder(setter(into))]
+    provider: Cow<'a, str>,
+
+    /// The common name of the LDAP group.
+    #[builder(setter(into), default)]
+    cn: Option<Cow<'a, str>>,
+    /// An LDAP filter selecting the users to add to the group.
+    #[builder(setter(into), default)]
+    filter: Option<Cow<'a, str>>,
+    /// The ID of a custom member role to grant.
+    #[builder(default)]
+    member_role_id: Option<u64>,
+}
+
+impl<'a> AddGroupLdapLink<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AddGroupLdapLinkBuilder<'a> {
+        AddGroupLdapLinkBuilder::default()
+    }
+}
+
+impl<'a> AddGroupLdapLinkBuilder<'a> {
+    fn validate(&self) -> Result<(), AddGroupLdapLinkBuilderError> {
+        let has_cn = matches!(self.cn, Some(Some(_)));
+        let has_filter = matches!(self.filter, Some(Some(_)));
+
+        if has_cn == has_filter {
+            return Err(AddGroupLdapLinkBuilderError::ValidationError(
+                "exactly one of `cn` or `filter` is required".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for AddGroupLdapLink<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/ldap_group_links", self.group).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("cn", self.cn.as_ref())
+            .push_opt("filter", self.filter.as_ref())
+            .push("group_access", self.group_access.as_u64())
+            .push("provider", &self.provider)
+            .push_opt("member_role_id", self.member_role_id);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::common::AccessLevel;
+    use crate::api::groups::ldap_group_links::{AddGroupLdapLink, AddGroupLdapLinkBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = AddGroupLdapLink::builder()
+            .group_access(AccessLevel::Developer)
+            .provider("ldapmain")
+            .cn("developers")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddGroupLdapLinkBuilderError, "group");
+    }
+
+    #[test]
+    fn group_access_is_needed() {
+        let err = AddGroupLdapLink::builder()
+            .group(1)
+            .provider("ldapmain")
+            .cn("developers")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddGroupLdapLinkBuilderError, "group_access");
+    }
+
+    #[test]
+    fn provider_is_needed() {
+        let err = AddGroupLdapLink::builder()
+            .group(1)
+            .group_access(AccessLevel::Developer)
+            .cn("developers")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddGroupLdapLinkBuilderError, "provider");
+    }
+
+    #[test]
+    fn cn_or_filter_is_needed() {
+        let err = AddGroupLdapLink::builder()
+            .group(1)
+            .group_access(AccessLevel::Developer)
+            .provider("ldapmain")
+            .build()
+            .unwrap_err();
+        if let AddGroupLdapLinkBuilderError::ValidationError(message) = err {
+            assert_eq!(message, "exactly one of `cn` or `filter` is required");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn cn_and_filter_are_exclusive() {
+        let err = AddGroupLdapLink::builder()
+            .group(1)
+            .group_access(AccessLevel::Developer)
+            .provider("ldapmain")
+            .cn("developers")
+            .filter("(department=dev)")
+            .build()
+            .unwrap_err();
+        if let AddGroupLdapLinkBuilderError::ValidationError(message) = err {
+            assert_eq!(message, "exactly one of `cn` or `filter` is required");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn group_group_access_provider_and_cn_are_sufficient() {
+        AddGroupLdapLink::builder()
+            .group(1)
+            .group_access(AccessLevel::Developer)
+            .provider("ldapmain")
+            .cn("developers")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_group_access_provider_and_filter_are_sufficient() {
+        AddGroupLdapLink::builder()
+            .group(1)
+            .group_access(AccessLevel::Developer)
+            .provider("ldapmain")
+            .filter("(department=dev)")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint_cn() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/ldap_group_links")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("cn=developers&group_access=30&provider=ldapmain")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddGroupLdapLink::builder()
+            .group("simple/group")
+            .group_access(AccessLevel::Developer)
+            .provider("ldapmain")
+            .cn("developers")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_filter() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/ldap_group_links")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("filter=%28department%3Ddev%29&group_access=40&provider=ldapmain")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddGroupLdapLink::builder()
+            .group("simple/group")
+            .group_access(AccessLevel::Maintainer)
+            .provider("ldapmain")
+            .filter("(department=dev)")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_member_role_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/ldap_group_links")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("cn=developers&group_access=30&provider=ldapmain&member_role_id=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddGroupLdapLink::builder()
+            .group("simple/group")
+            .group_access(AccessLevel::Developer)
+            .provider("ldapmain")
+            .cn("developers")
+            .member_role_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,176 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete an LDAP link from a group.
+///
+/// Exactly one of `cn` or `filter` must be given.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct DeleteGroupLdapLink<'a> {
+    /// The group to delete the LDAP link from.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// The common name of the LDAP group.
+    #[builder(setter(into), default)]
+    cn: Option<Cow<'a, str>>,
+    /// The LDAP filter of the link.
+    #[builder(setter(into), default)]
+    filter: Option<Cow<'a, str>>,
+    /// The LDAP provider of the link.
+    #[builder(setter(into), default)]
+    provider: Option<Cow<'a, str>>,
+}
+
+impl<'a> DeleteGroupLdapLink<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteGroupLdapLinkBuilder<'a> {
+        DeleteGroupLdapLinkBuilder::default()
+    }
+}
+
+impl<'a> DeleteGroupLdapLinkBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteGroupLdapLinkBuilderError> {
+        let has_cn = matches!(self.cn, Some(Some(_)));
+        let has_filter = matches!(self.filter, Some(Some(_)));
+
+        if has_cn == has_filter {
+            return Err(DeleteGroupLdapLinkBuilderError::ValidationError(
+                "exactly one of `cn` or `filter` is required".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteGroupLdapLink<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/ldap_group_links", self.group).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("cn", self.cn.as_ref())
+            .push_opt("filter", self.filter.as_ref())
+            .push_opt("provider", self.provider.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::ldap_group_links::{
+        DeleteGroupLdapLink, DeleteGroupLdapLinkBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = DeleteGroupLdapLink::builder()
+            .cn("developers")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupLdapLinkBuilderError, "group");
+    }
+
+    #[test]
+    fn cn_or_filter_is_needed() {
+        let err = DeleteGroupLdapLink::builder().group(1).build().unwrap_err();
+        if let DeleteGroupLdapLinkBuilderError::ValidationError(message) = err {
+            assert_eq!(message, "exactly one of `cn` or `filter` is required");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn cn_and_filter_are_exclusive() {
+        let err = DeleteGroupLdapLink::builder()
+            .group(1)
+            .cn("developers")
+            .filter("(department=dev)")
+            .build()
+            .unwrap_err();
+        if let DeleteGroupLdapLinkBuilderError::ValidationError(message) = err {
+            assert_eq!(message, "exactly one of `cn` or `filter` is required");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn group_and_cn_are_sufficient() {
+        DeleteGroupLdapLink::builder()
+            .group(1)
+            .cn("developers")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_and_filter_are_sufficient() {
+        DeleteGroupLdapLink::builder()
+            .group(1)
+            .filter("(department=dev)")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint_cn() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/ldap_group_links")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("cn=developers")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroupLdapLink::builder()
+            .group("simple/group")
+            .cn("developers")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_filter_provider() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/ldap_group_links")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("filter=%28department%3Ddev%29&provider=ldapmain")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroupLdapLink::builder()
+            .group("simple/group")
+            .filter("(department=dev)")
+            .provider("ldapmain")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,68 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the LDAP links of a group.
+#[derive(Debug, Builder, Clone)]
+pub struct GroupLdapLinks<'a> {
+    /// The group to query for LDAP links.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> GroupLdapLinks<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupLdapLinksBuilder<'a> {
+        GroupLdapLinksBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for GroupLdapLinks<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/ldap_group_links", self.group).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::ldap_group_links::{GroupLdapLinks, GroupLdapLinksBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupLdapLinks::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupLdapLinksBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupLdapLinks::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/ldap_group_links")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupLdapLinks::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,21 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group notification settings API endpoints.
+//!
+//! These endpoints are used for querying and modifying the notification settings of the current
+//! user for a group.
+
+mod edit;
+mod settings;
+
+pub use self::edit::EditGroupNotificationSettings;
+pub use self::edit::EditGroupNotificationSettingsBuilder;
+pub use self::edit::EditGroupNotificationSettingsBuilderError;
+
+pub use self::settings::GroupNotificationSettings;
+pub use self::settings::GroupNotificationSettingsBuilder;
+pub use self::settings::GroupNotificationSettingsBuilderError;
@@ -0,0 +1,161 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::api::notification_settings::{NotificationEvent, NotificationLevel};
+
+/// Edit the notification settings of the current user for a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditGroupNotificationSettings<'a> {
+    /// The group to edit notification settings for.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// The notification level.
+    #[builder(default)]
+    level: Option<NotificationLevel>,
+
+    /// Events to enable or disable for the `custom` notification level.
+    #[builder(setter(name = "_events"), default, private)]
+    events: BTreeMap<NotificationEvent, bool>,
+}
+
+impl<'a> EditGroupNotificationSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditGroupNotificationSettingsBuilder<'a> {
+        EditGroupNotificationSettingsBuilder::default()
+    }
+}
+
+impl<'a> EditGroupNotificationSettingsBuilder<'a> {
+    /// Enable or disable notifications for an event.
+    pub fn event(&mut self, event: NotificationEvent, enabled: bool) -> &mut Self {
+        self.events
+            .get_or_insert_with(BTreeMap::new)
+            .insert(event, enabled);
+        self
+    }
+
+    /// Enable or disable notifications for multiple events.
+    pub fn events<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = (NotificationEvent, bool)>,
+    {
+        self.events.get_or_insert_with(BTreeMap::new).extend(iter);
+        self
+    }
+}
+
+impl<'a> Endpoint for EditGroupNotificationSettings<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/notification_settings", self.group).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push_opt("level", self.level);
+        NotificationEvent::add_params(&self.events, &mut params);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::notification_settings::{
+        EditGroupNotificationSettings, EditGroupNotificationSettingsBuilderError,
+    };
+    use crate::api::notification_settings::{NotificationEvent, NotificationLevel};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = EditGroupNotificationSettings::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupNotificationSettingsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        EditGroupNotificationSettings::builder()
+            .group(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupNotificationSettings::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_level() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("level=watch")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupNotificationSettings::builder()
+            .group("simple/group")
+            .level(NotificationLevel::Watch)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_events() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("level=custom&new_issue=true&success_pipeline=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupNotificationSettings::builder()
+            .group("simple/group")
+            .level(NotificationLevel::Custom)
+            .event(NotificationEvent::SuccessPipeline, false)
+            .event(NotificationEvent::NewIssue, true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,73 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the notification settings of the current user for a group.
+#[derive(Debug, Builder, Clone)]
+pub struct GroupNotificationSettings<'a> {
+    /// The group to query for notification settings.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> GroupNotificationSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupNotificationSettingsBuilder<'a> {
+        GroupNotificationSettingsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for GroupNotificationSettings<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/notification_settings", self.group).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::notification_settings::{
+        GroupNotificationSettings, GroupNotificationSettingsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupNotificationSettings::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupNotificationSettingsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupNotificationSettings::builder()
+            .group(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/notification_settings")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupNotificationSettings::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
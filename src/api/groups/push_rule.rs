@@ -4,13 +4,30 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#![allow(clippy::module_inception)]
+
 //! Group push-rule related API endpoints
 //!
 //! These endpoints are to manage [push rules](https://docs.gitlab.com/ee/api/groups.html#get-group-push-rules)
 //! for groups.
 
+mod create;
+mod delete;
 mod edit;
+mod push_rule;
+
+pub use create::CreateGroupPushRule;
+pub use create::CreateGroupPushRuleBuilder;
+pub use create::CreateGroupPushRuleBuilderError;
+
+pub use delete::DeleteGroupPushRule;
+pub use delete::DeleteGroupPushRuleBuilder;
+pub use delete::DeleteGroupPushRuleBuilderError;
 
 pub use edit::EditGroupPushRule;
 pub use edit::EditGroupPushRuleBuilder;
 pub use edit::EditGroupPushRuleBuilderError;
+
+pub use push_rule::GroupPushRule;
+pub use push_rule::GroupPushRuleBuilder;
+pub use push_rule::GroupPushRuleBuilderError;
@@ -0,0 +1,73 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete the push rules of a group.
+///
+/// See https://docs.gitlab.com/ee/api/groups.html#delete-group-push-rule
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteGroupPushRule<'a> {
+    /// The group to delete push rules from.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> DeleteGroupPushRule<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteGroupPushRuleBuilder<'a> {
+        DeleteGroupPushRuleBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteGroupPushRule<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/push_rule", self.group).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::push_rule::{DeleteGroupPushRule, DeleteGroupPushRuleBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = DeleteGroupPushRule::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupPushRuleBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        DeleteGroupPushRule::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/push_rule")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroupPushRule::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,70 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the push rules of a group.
+///
+/// See https://docs.gitlab.com/ee/api/groups.html#get-group-push-rules
+#[derive(Debug, Builder, Clone)]
+pub struct GroupPushRule<'a> {
+    /// The group to query for push rules.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> GroupPushRule<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupPushRuleBuilder<'a> {
+        GroupPushRuleBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for GroupPushRule<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/push_rule", self.group).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::push_rule::{GroupPushRule, GroupPushRuleBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupPushRule::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupPushRuleBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupPushRule::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/push_rule")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupPushRule::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
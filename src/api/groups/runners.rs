@@ -10,8 +10,13 @@
 //!
 //! These endpoints are used for querying and modifying CI runners for a group and their resources.
 
+mod reset_registration_token;
 mod runners;
 
+pub use self::reset_registration_token::ResetGroupRunnerRegistrationToken;
+pub use self::reset_registration_token::ResetGroupRunnerRegistrationTokenBuilder;
+pub use self::reset_registration_token::ResetGroupRunnerRegistrationTokenBuilderError;
+
 pub use self::runners::GroupRunners;
 pub use self::runners::GroupRunnersBuilder;
 pub use self::runners::GroupRunnersBuilderError;
@@ -0,0 +1,84 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Reset the runner registration token of a group.
+///
+/// The response contains the new token.
+#[derive(Debug, Builder, Clone)]
+pub struct ResetGroupRunnerRegistrationToken<'a> {
+    /// The group to reset the registration token of.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> ResetGroupRunnerRegistrationToken<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ResetGroupRunnerRegistrationTokenBuilder<'a> {
+        ResetGroupRunnerRegistrationTokenBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ResetGroupRunnerRegistrationToken<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/runners/reset_registration_token", self.group).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::runners::{
+        ResetGroupRunnerRegistrationToken, ResetGroupRunnerRegistrationTokenBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = ResetGroupRunnerRegistrationToken::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ResetGroupRunnerRegistrationTokenBuilderError,
+            "group"
+        );
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        ResetGroupRunnerRegistrationToken::builder()
+            .group(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/runners/reset_registration_token")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ResetGroupRunnerRegistrationToken::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,31 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group SAML link API endpoints.
+//!
+//! These endpoints are used for querying and managing the SAML groups whose members are added to
+//! a group.
+
+mod add;
+mod delete;
+mod saml_group_link;
+mod saml_group_links;
+
+pub use self::add::AddGroupSamlLink;
+pub use self::add::AddGroupSamlLinkBuilder;
+pub use self::add::AddGroupSamlLinkBuilderError;
+
+pub use self::delete::DeleteGroupSamlLink;
+pub use self::delete::DeleteGroupSamlLinkBuilder;
+pub use self::delete::DeleteGroupSamlLinkBuilderError;
+
+pub use self::saml_group_link::GroupSamlLink;
+pub use self::saml_group_link::GroupSamlLinkBuilder;
+pub use self::saml_group_link::GroupSamlLinkBuilderError;
+
+pub use self::saml_group_links::GroupSamlLinks;
+pub use self::saml_group_links::GroupSamlLinksBuilder;
+pub use self::saml_group_links::GroupSamlLinksBuilderError;
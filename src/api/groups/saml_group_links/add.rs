@@ -0,0 +1,153 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{AccessLevel, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Add a SAML link to a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct AddGroupSamlLink<'a> {
+    /// The group to add the SAML link to.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The name of the SAML group.
+    #[builder(setter(into))]
+    saml_group_name: Cow<'a, str>,
+    /// The access level to grant to members of the SAML group.
+    access_level: AccessLevel,
+
+    /// The ID of a custom member role to grant.
+    #[builder(default)]
+    member_role_id: Option<u64>,
+}
+
+impl<'a> AddGroupSamlLink<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AddGroupSamlLinkBuilder<'a> {
+        AddGroupSamlLinkBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for AddGroupSamlLink<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/saml_group_links", self.group).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("saml_group_name", &self.saml_group_name)
+            .push("access_level", self.access_level.as_u64())
+            .push_opt("member_role_id", self.member_role_id);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::common::AccessLevel;
+    use crate::api::groups::saml_group_links::{AddGroupSamlLink, AddGroupSamlLinkBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = AddGroupSamlLink::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, AddGroupSamlLinkBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = AddGroupSamlLink::builder()
+            .saml_group_name("saml-group")
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddGroupSamlLinkBuilderError, "group");
+    }
+
+    #[test]
+    fn saml_group_name_is_needed() {
+        let err = AddGroupSamlLink::builder()
+            .group(1)
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddGroupSamlLinkBuilderError, "saml_group_name");
+    }
+
+    #[test]
+    fn access_level_is_needed() {
+        let err = AddGroupSamlLink::builder()
+            .group(1)
+            .saml_group_name("saml-group")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddGroupSamlLinkBuilderError, "access_level");
+    }
+
+    #[test]
+    fn group_saml_group_name_and_access_level_are_sufficient() {
+        AddGroupSamlLink::builder()
+            .group(1)
+            .saml_group_name("saml-group")
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/saml_group_links")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("saml_group_name=saml-group&access_level=30")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddGroupSamlLink::builder()
+            .group("simple/group")
+            .saml_group_name("saml-group")
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_member_role_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/saml_group_links")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("saml_group_name=saml-group&access_level=10&member_role_id=12")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddGroupSamlLink::builder()
+            .group("simple/group")
+            .saml_group_name("saml-group")
+            .access_level(AccessLevel::Guest)
+            .member_role_id(12)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
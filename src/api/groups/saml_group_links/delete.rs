@@ -0,0 +1,101 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a SAML link from a group.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteGroupSamlLink<'a> {
+    /// The group to delete the SAML link from.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The name of the SAML group.
+    #[builder(setter(into))]
+    saml_group_name: Cow<'a, str>,
+}
+
+impl<'a> DeleteGroupSamlLink<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteGroupSamlLinkBuilder<'a> {
+        DeleteGroupSamlLinkBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteGroupSamlLink<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/saml_group_links/{}",
+            self.group,
+            common::path_escaped(&self.saml_group_name),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::saml_group_links::{
+        DeleteGroupSamlLink, DeleteGroupSamlLinkBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_saml_group_name_are_needed() {
+        let err = DeleteGroupSamlLink::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupSamlLinkBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = DeleteGroupSamlLink::builder()
+            .saml_group_name("saml-group")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupSamlLinkBuilderError, "group");
+    }
+
+    #[test]
+    fn saml_group_name_is_needed() {
+        let err = DeleteGroupSamlLink::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupSamlLinkBuilderError, "saml_group_name");
+    }
+
+    #[test]
+    fn group_and_saml_group_name_are_sufficient() {
+        DeleteGroupSamlLink::builder()
+            .group(1)
+            .saml_group_name("saml-group")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/saml_group_links/saml%2Fgroup")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroupSamlLink::builder()
+            .group("simple/group")
+            .saml_group_name("saml/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
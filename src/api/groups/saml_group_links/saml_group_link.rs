@@ -0,0 +1,96 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a SAML link of a group.
+#[derive(Debug, Builder, Clone)]
+pub struct GroupSamlLink<'a> {
+    /// The group to query for the SAML link.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The name of the SAML group.
+    #[builder(setter(into))]
+    saml_group_name: Cow<'a, str>,
+}
+
+impl<'a> GroupSamlLink<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupSamlLinkBuilder<'a> {
+        GroupSamlLinkBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for GroupSamlLink<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/saml_group_links/{}",
+            self.group,
+            common::path_escaped(&self.saml_group_name),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::saml_group_links::{GroupSamlLink, GroupSamlLinkBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_saml_group_name_are_needed() {
+        let err = GroupSamlLink::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupSamlLinkBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupSamlLink::builder()
+            .saml_group_name("saml-group")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupSamlLinkBuilderError, "group");
+    }
+
+    #[test]
+    fn saml_group_name_is_needed() {
+        let err = GroupSamlLink::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupSamlLinkBuilderError, "saml_group_name");
+    }
+
+    #[test]
+    fn group_and_saml_group_name_are_sufficient() {
+        GroupSamlLink::builder()
+            .group(1)
+            .saml_group_name("saml-group")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/saml_group_links/saml%2Fgroup")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupSamlLink::builder()
+            .group("simple/group")
+            .saml_group_name("saml/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,142 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Transfer a group to a new parent group.
+///
+/// If no parent group is given, the group becomes a top-level group. This requires the user to
+/// be allowed to create top-level groups on the instance; otherwise GitLab responds with
+/// `403 Forbidden`, which is reported as an `ApiError::Gitlab` error with the message from GitLab.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct TransferGroup<'a> {
+    /// The group to transfer.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// The ID of the new parent group.
+    ///
+    /// If not given, the group becomes a top-level group.
+    #[builder(default)]
+    group_id: Option<u64>,
+}
+
+impl<'a> TransferGroup<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> TransferGroupBuilder<'a> {
+        TransferGroupBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for TransferGroup<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/transfer", self.group).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push_opt("group_id", self.group_id);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{Method, StatusCode};
+    use serde_json::json;
+
+    use crate::api::groups::{TransferGroup, TransferGroupBuilderError};
+    use crate::api::{self, ApiError, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = TransferGroup::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, TransferGroupBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        TransferGroup::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/transfer")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TransferGroup::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_group_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/transfer")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("group_id=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TransferGroup::builder()
+            .group("simple/group")
+            .group_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_top_level_forbidden() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/transfer")
+            .content_type("application/x-www-form-urlencoded")
+            .status(StatusCode::FORBIDDEN)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "403 Forbidden",
+            }),
+        );
+
+        let endpoint = TransferGroup::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        let err = api::ignore(endpoint).query(&client).unwrap_err();
+        if let ApiError::Gitlab {
+            msg, ..
+        } = err
+        {
+            assert_eq!(msg, "403 Forbidden");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
@@ -0,0 +1,332 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{header, Method, Request, Response};
+use serde::de::DeserializeOwned;
+
+use crate::api::{query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, RestClient};
+
+/// The header used to convey the idempotency key.
+const IDEMPOTENCY_KEY: &str = "idempotency-key";
+
+/// A query modifier that attaches an idempotency key to a request.
+///
+/// Retrying a request which creates a resource may otherwise create duplicates if the first
+/// attempt reached the server. The key is sent in the `Idempotency-Key` header; reusing the same
+/// key for every attempt of a logical request allows the server (or an intermediate layer) to
+/// detect retries. Requests using safe methods (`GET` and `HEAD`) do not get the header.
+///
+/// The result is deserialized as with any other endpoint. To discard it, query for
+/// `serde::de::IgnoredAny`; an empty response body (e.g., `204 No Content`) is treated as
+/// `null`.
+#[derive(Debug, Clone)]
+pub struct Idempotent<'a, E> {
+    endpoint: E,
+    key: Cow<'a, str>,
+}
+
+/// Attach an idempotency key to a query on an endpoint.
+pub fn idempotent<'a, E, K>(endpoint: E, key: K) -> Idempotent<'a, E>
+where
+    K: Into<Cow<'a, str>>,
+{
+    Idempotent {
+        endpoint,
+        key: key.into(),
+    }
+}
+
+impl<'a, E> Idempotent<'a, E>
+where
+    E: Endpoint,
+{
+    fn request<C>(
+        &self,
+        client: &C,
+    ) -> Result<(http::request::Builder, Vec<u8>), ApiError<C::Error>>
+    where
+        C: RestClient,
+    {
+        let mut url = self
+            .endpoint
+            .url_base()
+            .endpoint_for(client, &self.endpoint.endpoint())?;
+        self.endpoint.parameters().add_to_url(&mut url);
+
+        let method = self.endpoint.method();
+        let safe = method == Method::GET || method == Method::HEAD;
+        let req = Request::builder()
+            .method(method)
+            .uri(query::url_to_http_uri(url));
+        let req = if safe {
+            req
+        } else {
+            req.header(IDEMPOTENCY_KEY, self.key.as_ref())
+        };
+        Ok(if let Some((mime, data)) = self.endpoint.body()? {
+            let req = req.header(header::CONTENT_TYPE, mime);
+            (req, data)
+        } else {
+            (req, Vec::new())
+        })
+    }
+}
+
+fn handle_response<T, E>(rsp: Response<Bytes>) -> Result<T, ApiError<E>>
+where
+    T: DeserializeOwned,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let status = rsp.status();
+    if !status.is_success() {
+        let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
+            v
+        } else {
+            return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
+        };
+        return Err(ApiError::from_gitlab(v, rsp.headers()));
+    } else if status == http::StatusCode::MOVED_PERMANENTLY {
+        return Err(ApiError::moved_permanently(
+            rsp.headers().get(header::LOCATION),
+        ));
+    }
+
+    // Endpoints answering with `204 No Content` have no body to parse.
+    let v = if rsp.body().is_empty() {
+        serde_json::Value::Null
+    } else if let Ok(v) = serde_json::from_slice(rsp.body()) {
+        v
+    } else {
+        return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
+    };
+
+    serde_json::from_value::<T>(v).map_err(ApiError::data_type::<T>)
+}
+
+impl<'a, E, T, C> Query<T, C> for Idempotent<'a, E>
+where
+    E: Endpoint,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let (req, data) = self.request(client)?;
+        let rsp = client.rest(req, data)?;
+        handle_response(rsp)
+    }
+}
+
+#[async_trait]
+impl<'a, E, T, C> AsyncQuery<T, C> for Idempotent<'a, E>
+where
+    E: Endpoint + Sync,
+    T: DeserializeOwned + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let (req, data) = self.request(client)?;
+        let rsp = client.rest_async(req, data).await?;
+        handle_response(rsp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use serde::de::IgnoredAny;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    struct Dummy {
+        method: Method,
+    }
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            self.method.clone()
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+
+        fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+            if self.method == Method::GET {
+                Ok(None)
+            } else {
+                let mut params = FormParams::default();
+                params.push("value", 1);
+                params.into_body()
+            }
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct DummyResult {
+        value: u8,
+    }
+
+    #[test]
+    fn test_idempotency_key() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("dummy")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("value=1")
+            .add_header("idempotency-key", "create-dummy-1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "value": 1,
+            }),
+        );
+
+        let endpoint = Dummy {
+            method: Method::POST,
+        };
+        let res: DummyResult = api::idempotent(endpoint, "create-dummy-1")
+            .query(&client)
+            .unwrap();
+        assert_eq!(res.value, 1);
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_async() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("dummy")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("value=1")
+            .add_header("idempotency-key", "edit-dummy-1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "value": 1,
+            }),
+        );
+
+        let endpoint = Dummy {
+            method: Method::PUT,
+        };
+        let res: DummyResult = api::idempotent(endpoint, "edit-dummy-1")
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert_eq!(res.value, 1);
+    }
+
+    #[test]
+    fn test_get_is_unchanged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_absent_header("idempotency-key")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "value": 0,
+            }),
+        );
+
+        let endpoint = Dummy {
+            method: Method::GET,
+        };
+        let res: DummyResult = api::idempotent(endpoint, "unused").query(&client).unwrap();
+        assert_eq!(res.value, 0);
+    }
+
+    #[test]
+    fn test_ignored_result() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("dummy")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("value=1")
+            .add_header("idempotency-key", "create-dummy-1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "value": 1,
+            }),
+        );
+
+        let endpoint = Dummy {
+            method: Method::POST,
+        };
+        let _: IgnoredAny = api::idempotent(endpoint, "create-dummy-1")
+            .query(&client)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_no_content() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("dummy")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("value=1")
+            .add_header("idempotency-key", "create-dummy-1")
+            .status(StatusCode::NO_CONTENT)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Dummy {
+            method: Method::POST,
+        };
+        let _: IgnoredAny = api::idempotent(endpoint, "create-dummy-1")
+            .query(&client)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_gitlab_error_detection() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("dummy")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("value=1")
+            .status(StatusCode::CONFLICT)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let endpoint = Dummy {
+            method: Method::POST,
+        };
+        let res: Result<DummyResult, _> =
+            api::idempotent(endpoint, "create-dummy-1").query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::Gitlab {
+            msg, ..
+        } = err
+        {
+            assert_eq!(msg, "dummy error message");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
@@ -0,0 +1,30 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Import API endpoints.
+//!
+//! These endpoints are used for importing repositories from other hosting services. Imports run
+//! asynchronously; use `api::projects::ImportStatus` to poll the progress of an import.
+
+mod bitbucket;
+mod bitbucket_server;
+mod github;
+
+pub use self::bitbucket::ImportBitbucketRepo;
+pub use self::bitbucket::ImportBitbucketRepoBuilder;
+pub use self::bitbucket::ImportBitbucketRepoBuilderError;
+
+pub use self::bitbucket_server::ImportBitbucketServerRepo;
+pub use self::bitbucket_server::ImportBitbucketServerRepoBuilder;
+pub use self::bitbucket_server::ImportBitbucketServerRepoBuilderError;
+
+pub use self::github::GithubImportStage;
+pub use self::github::ImportGithubRepo;
+pub use self::github::ImportGithubRepoBuilder;
+pub use self::github::ImportGithubRepoBuilderError;
+pub use self::github::ImportTimeoutStrategy;
@@ -0,0 +1,201 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Import a repository from Bitbucket Cloud.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct ImportBitbucketRepo<'a> {
+    /// The username to use on Bitbucket Cloud.
+    #[builder(setter(into))]
+    bitbucket_username: Cow<'a, str>,
+    /// A Bitbucket Cloud app password with access to the repository.
+    #[builder(setter(into))]
+    bitbucket_app_password: Cow<'a, str>,
+    /// The path of the repository (e.g., `workspace/repo`).
+    #[builder(setter(into))]
+    repo_path: Cow<'a, str>,
+    /// The namespace to import the repository into.
+    #[builder(setter(into))]
+    target_namespace: Cow<'a, str>,
+
+    /// The name of the new project.
+    #[builder(setter(into), default)]
+    new_name: Option<Cow<'a, str>>,
+}
+
+impl<'a> ImportBitbucketRepo<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ImportBitbucketRepoBuilder<'a> {
+        ImportBitbucketRepoBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ImportBitbucketRepo<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "import/bitbucket".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("bitbucket_username", &self.bitbucket_username)
+            .push("bitbucket_app_password", &self.bitbucket_app_password)
+            .push("repo_path", &self.repo_path)
+            .push("target_namespace", &self.target_namespace)
+            .push_opt("new_name", self.new_name.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::import::{ImportBitbucketRepo, ImportBitbucketRepoBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = ImportBitbucketRepo::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ImportBitbucketRepoBuilderError,
+            "bitbucket_username",
+        );
+    }
+
+    #[test]
+    fn bitbucket_username_is_needed() {
+        let err = ImportBitbucketRepo::builder()
+            .bitbucket_app_password("password")
+            .repo_path("workspace/repo")
+            .target_namespace("group")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ImportBitbucketRepoBuilderError,
+            "bitbucket_username",
+        );
+    }
+
+    #[test]
+    fn bitbucket_app_password_is_needed() {
+        let err = ImportBitbucketRepo::builder()
+            .bitbucket_username("user")
+            .repo_path("workspace/repo")
+            .target_namespace("group")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ImportBitbucketRepoBuilderError,
+            "bitbucket_app_password",
+        );
+    }
+
+    #[test]
+    fn repo_path_is_needed() {
+        let err = ImportBitbucketRepo::builder()
+            .bitbucket_username("user")
+            .bitbucket_app_password("password")
+            .target_namespace("group")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ImportBitbucketRepoBuilderError, "repo_path");
+    }
+
+    #[test]
+    fn target_namespace_is_needed() {
+        let err = ImportBitbucketRepo::builder()
+            .bitbucket_username("user")
+            .bitbucket_app_password("password")
+            .repo_path("workspace/repo")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ImportBitbucketRepoBuilderError,
+            "target_namespace",
+        );
+    }
+
+    #[test]
+    fn required_parameters_are_sufficient() {
+        ImportBitbucketRepo::builder()
+            .bitbucket_username("user")
+            .bitbucket_app_password("password")
+            .repo_path("workspace/repo")
+            .target_namespace("group")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("import/bitbucket")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "bitbucket_username=user",
+                "&bitbucket_app_password=password",
+                "&repo_path=workspace%2Frepo",
+                "&target_namespace=group",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ImportBitbucketRepo::builder()
+            .bitbucket_username("user")
+            .bitbucket_app_password("password")
+            .repo_path("workspace/repo")
+            .target_namespace("group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_new_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("import/bitbucket")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "bitbucket_username=user",
+                "&bitbucket_app_password=password",
+                "&repo_path=workspace%2Frepo",
+                "&target_namespace=group",
+                "&new_name=imported",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ImportBitbucketRepo::builder()
+            .bitbucket_username("user")
+            .bitbucket_app_password("password")
+            .repo_path("workspace/repo")
+            .target_namespace("group")
+            .new_name("imported")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
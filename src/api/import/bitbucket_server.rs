@@ -0,0 +1,265 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::import::ImportTimeoutStrategy;
+
+/// Import a repository from a Bitbucket Server instance.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct ImportBitbucketServerRepo<'a> {
+    /// The URL of the Bitbucket Server instance.
+    #[builder(setter(into))]
+    bitbucket_server_url: Cow<'a, str>,
+    /// The username to use on the Bitbucket Server instance.
+    #[builder(setter(into))]
+    bitbucket_server_username: Cow<'a, str>,
+    /// A Bitbucket Server personal access token with access to the repository.
+    #[builder(setter(into))]
+    personal_access_token: Cow<'a, str>,
+    /// The key of the project containing the repository.
+    #[builder(setter(into))]
+    bitbucket_server_project: Cow<'a, str>,
+    /// The slug of the repository.
+    #[builder(setter(into))]
+    bitbucket_server_repo: Cow<'a, str>,
+
+    /// The name of the new project.
+    #[builder(setter(into), default)]
+    new_name: Option<Cow<'a, str>>,
+    /// The namespace to import the repository into.
+    ///
+    /// Defaults to the namespace of the current user.
+    #[builder(setter(into), default)]
+    new_namespace: Option<Cow<'a, str>>,
+    /// How to handle timeouts during the import.
+    #[builder(default)]
+    timeout_strategy: Option<ImportTimeoutStrategy>,
+}
+
+impl<'a> ImportBitbucketServerRepo<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ImportBitbucketServerRepoBuilder<'a> {
+        ImportBitbucketServerRepoBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ImportBitbucketServerRepo<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "import/bitbucket_server".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("bitbucket_server_url", &self.bitbucket_server_url)
+            .push("bitbucket_server_username", &self.bitbucket_server_username)
+            .push("personal_access_token", &self.personal_access_token)
+            .push("bitbucket_server_project", &self.bitbucket_server_project)
+            .push("bitbucket_server_repo", &self.bitbucket_server_repo)
+            .push_opt("new_name", self.new_name.as_ref())
+            .push_opt("new_namespace", self.new_namespace.as_ref())
+            .push_opt("timeout_strategy", self.timeout_strategy);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::import::{
+        ImportBitbucketServerRepo, ImportBitbucketServerRepoBuilder,
+        ImportBitbucketServerRepoBuilderError, ImportTimeoutStrategy,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    fn builder() -> ImportBitbucketServerRepoBuilder<'static> {
+        let mut builder = ImportBitbucketServerRepo::builder();
+        builder
+            .bitbucket_server_url("https://bitbucket.example.com")
+            .bitbucket_server_username("user")
+            .personal_access_token("token")
+            .bitbucket_server_project("PROJ")
+            .bitbucket_server_repo("repo");
+        builder
+    }
+
+    const BODY: &str = concat!(
+        "bitbucket_server_url=https%3A%2F%2Fbitbucket.example.com",
+        "&bitbucket_server_username=user",
+        "&personal_access_token=token",
+        "&bitbucket_server_project=PROJ",
+        "&bitbucket_server_repo=repo",
+    );
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = ImportBitbucketServerRepo::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ImportBitbucketServerRepoBuilderError,
+            "bitbucket_server_url",
+        );
+    }
+
+    #[test]
+    fn bitbucket_server_url_is_needed() {
+        let err = ImportBitbucketServerRepo::builder()
+            .bitbucket_server_username("user")
+            .personal_access_token("token")
+            .bitbucket_server_project("PROJ")
+            .bitbucket_server_repo("repo")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ImportBitbucketServerRepoBuilderError,
+            "bitbucket_server_url",
+        );
+    }
+
+    #[test]
+    fn bitbucket_server_username_is_needed() {
+        let err = ImportBitbucketServerRepo::builder()
+            .bitbucket_server_url("https://bitbucket.example.com")
+            .personal_access_token("token")
+            .bitbucket_server_project("PROJ")
+            .bitbucket_server_repo("repo")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ImportBitbucketServerRepoBuilderError,
+            "bitbucket_server_username",
+        );
+    }
+
+    #[test]
+    fn personal_access_token_is_needed() {
+        let err = ImportBitbucketServerRepo::builder()
+            .bitbucket_server_url("https://bitbucket.example.com")
+            .bitbucket_server_username("user")
+            .bitbucket_server_project("PROJ")
+            .bitbucket_server_repo("repo")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ImportBitbucketServerRepoBuilderError,
+            "personal_access_token",
+        );
+    }
+
+    #[test]
+    fn bitbucket_server_project_is_needed() {
+        let err = ImportBitbucketServerRepo::builder()
+            .bitbucket_server_url("https://bitbucket.example.com")
+            .bitbucket_server_username("user")
+            .personal_access_token("token")
+            .bitbucket_server_repo("repo")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ImportBitbucketServerRepoBuilderError,
+            "bitbucket_server_project",
+        );
+    }
+
+    #[test]
+    fn bitbucket_server_repo_is_needed() {
+        let err = ImportBitbucketServerRepo::builder()
+            .bitbucket_server_url("https://bitbucket.example.com")
+            .bitbucket_server_username("user")
+            .personal_access_token("token")
+            .bitbucket_server_project("PROJ")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ImportBitbucketServerRepoBuilderError,
+            "bitbucket_server_repo",
+        );
+    }
+
+    #[test]
+    fn required_parameters_are_sufficient() {
+        builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("import/bitbucket_server")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(BODY)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_new_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("import/bitbucket_server")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(&format!("{}&new_name=imported", BODY))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = builder().new_name("imported").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_new_namespace() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("import/bitbucket_server")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(&format!("{}&new_namespace=group%2Fsubgroup", BODY))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = builder().new_namespace("group/subgroup").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_timeout_strategy() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("import/bitbucket_server")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(&format!("{}&timeout_strategy=optimistic", BODY))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = builder()
+            .timeout_strategy(ImportTimeoutStrategy::Optimistic)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
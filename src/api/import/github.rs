@@ -0,0 +1,375 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Strategies for handling timeouts during an import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ImportTimeoutStrategy {
+    /// Continue the import when a stage times out.
+    Optimistic,
+    /// Fail the import when a stage times out.
+    Pessimistic,
+}
+
+impl ImportTimeoutStrategy {
+    /// The timeout strategy as a query parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            ImportTimeoutStrategy::Optimistic => "optimistic",
+            ImportTimeoutStrategy::Pessimistic => "pessimistic",
+        }
+    }
+}
+
+impl ParamValue<'static> for ImportTimeoutStrategy {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Optional stages of a GitHub import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum GithubImportStage {
+    /// Import issue events one by one rather than in bulk.
+    SingleEndpointIssueEvents,
+    /// Import notes one by one rather than in bulk.
+    SingleEndpointNotes,
+    /// Import Markdown attachments.
+    Attachments,
+    /// Import collaborators as project members.
+    Collaborators,
+}
+
+impl GithubImportStage {
+    /// The stage as a query parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            GithubImportStage::SingleEndpointIssueEvents => "single_endpoint_issue_events_import",
+            GithubImportStage::SingleEndpointNotes => "single_endpoint_notes_import",
+            GithubImportStage::Attachments => "attachments_import",
+            GithubImportStage::Collaborators => "collaborators_import",
+        }
+    }
+}
+
+/// Import a repository from GitHub.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct ImportGithubRepo<'a> {
+    /// A GitHub personal access token with access to the repository.
+    #[builder(setter(into))]
+    personal_access_token: Cow<'a, str>,
+    /// The ID of the repository on GitHub.
+    repo_id: u64,
+    /// The namespace to import the repository into.
+    #[builder(setter(into))]
+    target_namespace: Cow<'a, str>,
+
+    /// The name of the new project.
+    #[builder(setter(into), default)]
+    new_name: Option<Cow<'a, str>>,
+    /// The hostname of a GitHub Enterprise instance to import from.
+    #[builder(setter(into), default)]
+    github_hostname: Option<Cow<'a, str>>,
+    /// Optional stages of the import to perform.
+    #[builder(setter(name = "_optional_stages"), default, private)]
+    optional_stages: BTreeSet<GithubImportStage>,
+    /// How to handle timeouts during the import.
+    #[builder(default)]
+    timeout_strategy: Option<ImportTimeoutStrategy>,
+}
+
+impl<'a> ImportGithubRepo<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ImportGithubRepoBuilder<'a> {
+        ImportGithubRepoBuilder::default()
+    }
+}
+
+impl<'a> ImportGithubRepoBuilder<'a> {
+    /// Perform an optional stage of the import.
+    pub fn optional_stage(&mut self, stage: GithubImportStage) -> &mut Self {
+        self.optional_stages
+            .get_or_insert_with(BTreeSet::new)
+            .insert(stage);
+        self
+    }
+
+    /// Perform optional stages of the import.
+    pub fn optional_stages<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = GithubImportStage>,
+    {
+        self.optional_stages
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter);
+        self
+    }
+}
+
+impl<'a> Endpoint for ImportGithubRepo<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "import/github".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("personal_access_token", &self.personal_access_token)
+            .push("repo_id", self.repo_id)
+            .push("target_namespace", &self.target_namespace)
+            .push_opt("new_name", self.new_name.as_ref())
+            .push_opt("github_hostname", self.github_hostname.as_ref())
+            .extend(
+                self.optional_stages
+                    .iter()
+                    .map(|stage| (format!("optional_stages[{}]", stage.as_str()), true)),
+            )
+            .push_opt("timeout_strategy", self.timeout_strategy);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::import::{
+        GithubImportStage, ImportGithubRepo, ImportGithubRepoBuilderError, ImportTimeoutStrategy,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn import_timeout_strategy_as_str() {
+        let items = &[
+            (ImportTimeoutStrategy::Optimistic, "optimistic"),
+            (ImportTimeoutStrategy::Pessimistic, "pessimistic"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn github_import_stage_as_str() {
+        let items = &[
+            (
+                GithubImportStage::SingleEndpointIssueEvents,
+                "single_endpoint_issue_events_import",
+            ),
+            (
+                GithubImportStage::SingleEndpointNotes,
+                "single_endpoint_notes_import",
+            ),
+            (GithubImportStage::Attachments, "attachments_import"),
+            (GithubImportStage::Collaborators, "collaborators_import"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = ImportGithubRepo::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ImportGithubRepoBuilderError,
+            "personal_access_token",
+        );
+    }
+
+    #[test]
+    fn personal_access_token_is_needed() {
+        let err = ImportGithubRepo::builder()
+            .repo_id(1)
+            .target_namespace("group")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ImportGithubRepoBuilderError,
+            "personal_access_token",
+        );
+    }
+
+    #[test]
+    fn repo_id_is_needed() {
+        let err = ImportGithubRepo::builder()
+            .personal_access_token("token")
+            .target_namespace("group")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ImportGithubRepoBuilderError, "repo_id");
+    }
+
+    #[test]
+    fn target_namespace_is_needed() {
+        let err = ImportGithubRepo::builder()
+            .personal_access_token("token")
+            .repo_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ImportGithubRepoBuilderError, "target_namespace");
+    }
+
+    #[test]
+    fn required_parameters_are_sufficient() {
+        ImportGithubRepo::builder()
+            .personal_access_token("token")
+            .repo_id(1)
+            .target_namespace("group")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("import/github")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "personal_access_token=token",
+                "&repo_id=1",
+                "&target_namespace=group%2Fsubgroup",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ImportGithubRepo::builder()
+            .personal_access_token("token")
+            .repo_id(1)
+            .target_namespace("group/subgroup")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_new_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("import/github")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "personal_access_token=token",
+                "&repo_id=1",
+                "&target_namespace=group",
+                "&new_name=imported",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ImportGithubRepo::builder()
+            .personal_access_token("token")
+            .repo_id(1)
+            .target_namespace("group")
+            .new_name("imported")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_github_hostname() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("import/github")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "personal_access_token=token",
+                "&repo_id=1",
+                "&target_namespace=group",
+                "&github_hostname=https%3A%2F%2Fgithub.example.com",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ImportGithubRepo::builder()
+            .personal_access_token("token")
+            .repo_id(1)
+            .target_namespace("group")
+            .github_hostname("https://github.example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_optional_stages() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("import/github")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "personal_access_token=token",
+                "&repo_id=1",
+                "&target_namespace=group",
+                "&optional_stages%5Bsingle_endpoint_notes_import%5D=true",
+                "&optional_stages%5Battachments_import%5D=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ImportGithubRepo::builder()
+            .personal_access_token("token")
+            .repo_id(1)
+            .target_namespace("group")
+            .optional_stage(GithubImportStage::Attachments)
+            .optional_stages([GithubImportStage::SingleEndpointNotes].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_timeout_strategy() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("import/github")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "personal_access_token=token",
+                "&repo_id=1",
+                "&target_namespace=group",
+                "&timeout_strategy=pessimistic",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ImportGithubRepo::builder()
+            .personal_access_token("token")
+            .repo_id(1)
+            .target_namespace("group")
+            .timeout_strategy(ImportTimeoutStrategy::Pessimistic)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
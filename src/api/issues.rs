@@ -6,17 +6,26 @@
 
 //! Issue API endpoints and types.
 //!
-//! These endpoints are used for querying issues from projects, groups or the whole instance.
+//! These endpoints are used for querying issues (or statistics about them) from projects, groups
+//! or the whole instance.
 
 use std::collections::BTreeSet;
 
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
+pub use group_statistics::{
+    GroupIssuesStatistics, GroupIssuesStatisticsBuilder, GroupIssuesStatisticsBuilderError,
+};
 pub use groups::{GroupIssues, GroupIssuesBuilder, GroupIssuesBuilderError};
+pub use project_statistics::{
+    ProjectIssuesStatistics, ProjectIssuesStatisticsBuilder, ProjectIssuesStatisticsBuilderError,
+};
 pub use projects::{ProjectIssues, ProjectIssuesBuilder, ProjectIssuesBuilderError};
 
+mod group_statistics;
 mod groups;
+mod project_statistics;
 mod projects;
 
 /// Filters for issue states.
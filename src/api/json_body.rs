@@ -0,0 +1,121 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::api::endpoint_prelude::*;
+use crate::api::{FormParams, UrlBase};
+
+/// A modifier which sends the form body of an endpoint as JSON.
+///
+/// Bodies which are not URL-encoded forms are passed through unchanged.
+#[derive(Debug, Clone)]
+pub struct JsonBody<E> {
+    /// The endpoint to send a JSON body for.
+    endpoint: E,
+}
+
+/// Send the form body of an endpoint as JSON instead.
+///
+/// Form keys using bracket notation (e.g., `attributes[key]` or `list[]`) are expanded into
+/// nested objects and arrays. See `FormParams::into_json_body` for details.
+pub fn json_body<E>(endpoint: E) -> JsonBody<E> {
+    JsonBody {
+        endpoint,
+    }
+}
+
+impl<E> Endpoint for JsonBody<E>
+where
+    E: Endpoint,
+{
+    fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.endpoint.endpoint()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        self.endpoint.url_base()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        self.endpoint.parameters()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        match self.endpoint.body()? {
+            Some(("application/x-www-form-urlencoded", data)) => {
+                let decoded: Vec<(String, String)> = serde_urlencoded::from_bytes(&data)?;
+                let mut params = FormParams::default();
+                params.extend(decoded.into_iter());
+                params.into_json_body()
+            },
+            body => Ok(body),
+        }
+    }
+}
+
+impl<E> Pageable for JsonBody<E>
+where
+    E: Pageable,
+{
+    fn use_keyset_pagination(&self) -> bool {
+        self.endpoint.use_keyset_pagination()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+    use serde_json::json;
+
+    use crate::api::projects::{
+        ContainerExpirationCadence, ContainerExpirationPolicy, CreateProject, Project,
+    };
+    use crate::api::{self, Endpoint, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn json_body_create_project() {
+        let expected = json!({
+            "name": "name",
+            "container_expiration_policy_attributes": {
+                "cadence": "7d",
+                "enabled": "true",
+            },
+            "topics": ["a", "b"],
+        });
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects")
+            .content_type("application/json")
+            .body_str(&serde_json::to_string(&expected).unwrap())
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProject::builder()
+            .name("name")
+            .container_expiration_policy_attributes(
+                ContainerExpirationPolicy::builder()
+                    .cadence(ContainerExpirationCadence::OneWeek)
+                    .enabled(true)
+                    .build()
+                    .unwrap(),
+            )
+            .topics(["a", "b"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(api::json_body(endpoint)).query(&client).unwrap();
+    }
+
+    #[test]
+    fn json_body_no_body() {
+        let endpoint = Project::builder().project("simple/project").build().unwrap();
+        assert_eq!(api::json_body(endpoint).body().unwrap(), None);
+    }
+}
@@ -0,0 +1,56 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Instance metadata API endpoint.
+//!
+//! This endpoint is used for querying metadata about the GitLab instance such as its version,
+//! edition, and the status of the Kubernetes agent server (KAS).
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for metadata about the instance.
+#[derive(Debug, Builder, Clone)]
+pub struct Metadata {}
+
+impl Metadata {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MetadataBuilder {
+        MetadataBuilder::default()
+    }
+}
+
+impl Endpoint for Metadata {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "metadata".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::metadata::Metadata;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn default_is_sufficient() {
+        Metadata::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder().endpoint("metadata").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Metadata::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
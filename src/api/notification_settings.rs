@@ -0,0 +1,24 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Notification settings API endpoints.
+//!
+//! These endpoints are used for querying and modifying the global notification settings of the
+//! current user. Project- and group-level settings are available in the `projects` and `groups`
+//! modules.
+
+mod edit;
+mod settings;
+
+pub use self::edit::EditNotificationSettings;
+pub use self::edit::EditNotificationSettingsBuilder;
+pub use self::edit::EditNotificationSettingsBuilderError;
+pub use self::edit::NotificationEvent;
+pub use self::edit::NotificationLevel;
+
+pub use self::settings::NotificationSettings;
+pub use self::settings::NotificationSettingsBuilder;
+pub use self::settings::NotificationSettingsBuilderError;
@@ -0,0 +1,351 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Notification levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NotificationLevel {
+    /// No notifications are sent.
+    Disabled,
+    /// Notifications are sent for threads the user participates in.
+    Participating,
+    /// Notifications are sent for all activity.
+    Watch,
+    /// The global notification settings are used.
+    Global,
+    /// Notifications are sent when the user is mentioned.
+    Mention,
+    /// Notifications are sent for participating threads and selected events.
+    Custom,
+}
+
+impl NotificationLevel {
+    /// The notification level as a query parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Disabled => "disabled",
+            Self::Participating => "participating",
+            Self::Watch => "watch",
+            Self::Global => "global",
+            Self::Mention => "mention",
+            Self::Custom => "custom",
+        }
+    }
+}
+
+impl ParamValue<'static> for NotificationLevel {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Events which may be enabled for the `custom` notification level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum NotificationEvent {
+    /// A comment is added.
+    NewNote,
+    /// An issue is created.
+    NewIssue,
+    /// An issue is reopened.
+    ReopenIssue,
+    /// An issue is closed.
+    CloseIssue,
+    /// An issue is reassigned.
+    ReassignIssue,
+    /// An issue is due tomorrow.
+    IssueDue,
+    /// A merge request is created.
+    NewMergeRequest,
+    /// A merge request receives a push.
+    PushToMergeRequest,
+    /// A merge request is reopened.
+    ReopenMergeRequest,
+    /// A merge request is closed.
+    CloseMergeRequest,
+    /// A merge request is reassigned.
+    ReassignMergeRequest,
+    /// The reviewers of a merge request change.
+    ChangeReviewerMergeRequest,
+    /// A merge request is merged.
+    MergeMergeRequest,
+    /// A pipeline fails.
+    FailedPipeline,
+    /// A pipeline is fixed.
+    FixedPipeline,
+    /// A pipeline succeeds.
+    SuccessPipeline,
+    /// A project is moved.
+    MovedProject,
+    /// A merge request is set to merge when its pipeline succeeds.
+    MergeWhenPipelineSucceeds,
+    /// An epic is created.
+    NewEpic,
+}
+
+impl NotificationEvent {
+    /// The notification event as a query parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::NewNote => "new_note",
+            Self::NewIssue => "new_issue",
+            Self::ReopenIssue => "reopen_issue",
+            Self::CloseIssue => "close_issue",
+            Self::ReassignIssue => "reassign_issue",
+            Self::IssueDue => "issue_due",
+            Self::NewMergeRequest => "new_merge_request",
+            Self::PushToMergeRequest => "push_to_merge_request",
+            Self::ReopenMergeRequest => "reopen_merge_request",
+            Self::CloseMergeRequest => "close_merge_request",
+            Self::ReassignMergeRequest => "reassign_merge_request",
+            Self::ChangeReviewerMergeRequest => "change_reviewer_merge_request",
+            Self::MergeMergeRequest => "merge_merge_request",
+            Self::FailedPipeline => "failed_pipeline",
+            Self::FixedPipeline => "fixed_pipeline",
+            Self::SuccessPipeline => "success_pipeline",
+            Self::MovedProject => "moved_project",
+            Self::MergeWhenPipelineSucceeds => "merge_when_pipeline_succeeds",
+            Self::NewEpic => "new_epic",
+        }
+    }
+
+    pub(crate) fn add_params<'a>(events: &BTreeMap<Self, bool>, params: &mut FormParams<'a>) {
+        params.extend(
+            events
+                .iter()
+                .map(|(event, &enabled)| (event.as_str(), enabled)),
+        );
+    }
+}
+
+/// Edit the global notification settings of the current user.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditNotificationSettings<'a> {
+    /// The notification level.
+    #[builder(default)]
+    level: Option<NotificationLevel>,
+    /// The email address to send notifications to.
+    #[builder(setter(into), default)]
+    notification_email: Option<Cow<'a, str>>,
+
+    /// Events to enable or disable for the `custom` notification level.
+    #[builder(setter(name = "_events"), default, private)]
+    events: BTreeMap<NotificationEvent, bool>,
+}
+
+impl<'a> EditNotificationSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditNotificationSettingsBuilder<'a> {
+        EditNotificationSettingsBuilder::default()
+    }
+}
+
+impl<'a> EditNotificationSettingsBuilder<'a> {
+    /// Enable or disable notifications for an event.
+    pub fn event(&mut self, event: NotificationEvent, enabled: bool) -> &mut Self {
+        self.events
+            .get_or_insert_with(BTreeMap::new)
+            .insert(event, enabled);
+        self
+    }
+
+    /// Enable or disable notifications for multiple events.
+    pub fn events<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = (NotificationEvent, bool)>,
+    {
+        self.events.get_or_insert_with(BTreeMap::new).extend(iter);
+        self
+    }
+}
+
+impl<'a> Endpoint for EditNotificationSettings<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "notification_settings".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("level", self.level)
+            .push_opt("notification_email", self.notification_email.as_ref());
+        NotificationEvent::add_params(&self.events, &mut params);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::notification_settings::{
+        EditNotificationSettings, NotificationEvent, NotificationLevel,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn notification_level_as_str() {
+        let items = &[
+            (NotificationLevel::Disabled, "disabled"),
+            (NotificationLevel::Participating, "participating"),
+            (NotificationLevel::Watch, "watch"),
+            (NotificationLevel::Global, "global"),
+            (NotificationLevel::Mention, "mention"),
+            (NotificationLevel::Custom, "custom"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn notification_event_as_str() {
+        let items = &[
+            (NotificationEvent::NewNote, "new_note"),
+            (NotificationEvent::NewIssue, "new_issue"),
+            (NotificationEvent::ReopenIssue, "reopen_issue"),
+            (NotificationEvent::CloseIssue, "close_issue"),
+            (NotificationEvent::ReassignIssue, "reassign_issue"),
+            (NotificationEvent::IssueDue, "issue_due"),
+            (NotificationEvent::NewMergeRequest, "new_merge_request"),
+            (
+                NotificationEvent::PushToMergeRequest,
+                "push_to_merge_request",
+            ),
+            (
+                NotificationEvent::ReopenMergeRequest,
+                "reopen_merge_request",
+            ),
+            (NotificationEvent::CloseMergeRequest, "close_merge_request"),
+            (
+                NotificationEvent::ReassignMergeRequest,
+                "reassign_merge_request",
+            ),
+            (
+                NotificationEvent::ChangeReviewerMergeRequest,
+                "change_reviewer_merge_request",
+            ),
+            (NotificationEvent::MergeMergeRequest, "merge_merge_request"),
+            (NotificationEvent::FailedPipeline, "failed_pipeline"),
+            (NotificationEvent::FixedPipeline, "fixed_pipeline"),
+            (NotificationEvent::SuccessPipeline, "success_pipeline"),
+            (NotificationEvent::MovedProject, "moved_project"),
+            (
+                NotificationEvent::MergeWhenPipelineSucceeds,
+                "merge_when_pipeline_succeeds",
+            ),
+            (NotificationEvent::NewEpic, "new_epic"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn defaults_are_sufficient() {
+        EditNotificationSettings::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditNotificationSettings::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_level() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("level=participating")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditNotificationSettings::builder()
+            .level(NotificationLevel::Participating)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_notification_email() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("notification_email=bot%40example.com")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditNotificationSettings::builder()
+            .notification_email("bot@example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_events() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "level=custom",
+                "&new_note=false",
+                "&new_merge_request=true",
+                "&failed_pipeline=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditNotificationSettings::builder()
+            .level(NotificationLevel::Custom)
+            .event(NotificationEvent::FailedPipeline, true)
+            .events(
+                [
+                    (NotificationEvent::NewNote, false),
+                    (NotificationEvent::NewMergeRequest, true),
+                ]
+                .iter()
+                .cloned(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,220 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{header, Request, Response, StatusCode};
+use serde::de::DeserializeOwned;
+
+use crate::api::{query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, RestClient};
+
+/// A query modifier that treats a missing resource as a successful query.
+///
+/// If the endpoint returns `404 Not Found`, the query results in `None` rather than an error.
+#[derive(Debug, Clone)]
+pub struct Optional<E> {
+    endpoint: E,
+}
+
+/// Query an endpoint, mapping a `404 Not Found` response to `None`.
+pub fn optional<E>(endpoint: E) -> Optional<E> {
+    Optional {
+        endpoint,
+    }
+}
+
+impl<E> Optional<E>
+where
+    E: Endpoint,
+{
+    fn request<C>(
+        &self,
+        client: &C,
+    ) -> Result<(http::request::Builder, Vec<u8>), ApiError<C::Error>>
+    where
+        C: RestClient,
+    {
+        let mut url = self
+            .endpoint
+            .url_base()
+            .endpoint_for(client, &self.endpoint.endpoint())?;
+        self.endpoint.parameters().add_to_url(&mut url);
+
+        let req = Request::builder()
+            .method(self.endpoint.method())
+            .uri(query::url_to_http_uri(url));
+        Ok(if let Some((mime, data)) = self.endpoint.body()? {
+            let req = req.header(header::CONTENT_TYPE, mime);
+            (req, data)
+        } else {
+            (req, Vec::new())
+        })
+    }
+}
+
+fn handle_response<T, E>(rsp: Response<Bytes>) -> Result<Option<T>, ApiError<E>>
+where
+    T: DeserializeOwned,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let status = rsp.status();
+    if status == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+
+    let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
+        v
+    } else {
+        return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
+    };
+    if !status.is_success() {
+        return Err(ApiError::from_gitlab(v, rsp.headers()));
+    } else if status == StatusCode::MOVED_PERMANENTLY {
+        return Err(ApiError::moved_permanently(
+            rsp.headers().get(header::LOCATION),
+        ));
+    }
+
+    serde_json::from_value::<T>(v)
+        .map(Some)
+        .map_err(ApiError::data_type::<T>)
+}
+
+impl<E, T, C> Query<Option<T>, C> for Optional<E>
+where
+    E: Endpoint,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Option<T>, ApiError<C::Error>> {
+        let (req, data) = self.request(client)?;
+        let rsp = client.rest(req, data)?;
+        handle_response(rsp)
+    }
+}
+
+#[async_trait]
+impl<E, T, C> AsyncQuery<Option<T>, C> for Optional<E>
+where
+    E: Endpoint + Sync,
+    T: DeserializeOwned + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Option<T>, ApiError<C::Error>> {
+        let (req, data) = self.request(client)?;
+        let rsp = client.rest_async(req, data).await?;
+        handle_response(rsp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct DummyResult {
+        value: u8,
+    }
+
+    #[test]
+    fn test_found() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "value": 0,
+            }),
+        );
+
+        let res: Option<DummyResult> = api::optional(Dummy).query(&client).unwrap();
+        assert_eq!(
+            res,
+            Some(DummyResult {
+                value: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_not_found() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "404 Not found",
+            }),
+        );
+
+        let res: Option<DummyResult> = api::optional(Dummy).query(&client).unwrap();
+        assert_eq!(res, None);
+    }
+
+    #[tokio::test]
+    async fn test_not_found_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "404 Not found",
+            }),
+        );
+
+        let res: Option<DummyResult> = api::optional(Dummy).query_async(&client).await.unwrap();
+        assert_eq!(res, None);
+    }
+
+    #[test]
+    fn test_gitlab_error_detection() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::FORBIDDEN)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let res: Result<Option<DummyResult>, _> = api::optional(Dummy).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::Gitlab {
+            msg, ..
+        } = err
+        {
+            assert_eq!(msg, "dummy error message");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
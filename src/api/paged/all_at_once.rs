@@ -110,10 +110,10 @@ where
             let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
                 v
             } else {
-                return Err(ApiError::server_error(status, rsp.body()));
+                return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
             };
             if !status.is_success() {
-                return Err(ApiError::from_gitlab(v));
+                return Err(ApiError::from_gitlab(v, rsp.headers()));
             } else if status == http::StatusCode::MOVED_PERMANENTLY {
                 return Err(ApiError::moved_permanently(
                     rsp.headers().get(http::header::LOCATION),
@@ -251,7 +251,7 @@ mod tests {
         let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All).query(&client);
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
-            msg,
+            msg, ..
         } = err
         {
             assert_eq!(msg, "dummy error message");
@@ -279,7 +279,7 @@ mod tests {
         let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All).query(&client);
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
-            msg,
+            msg, ..
         } = err
         {
             assert_eq!(msg, "dummy error message");
@@ -305,7 +305,7 @@ mod tests {
         let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All).query(&client);
         let err = res.unwrap_err();
         if let ApiError::GitlabUnrecognized {
-            obj,
+            obj, ..
         } = err
         {
             assert_eq!(obj, err_obj);
@@ -282,10 +282,10 @@ where
         let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
             v
         } else {
-            return Err(ApiError::server_error(status, rsp.body()));
+            return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
         };
         if !status.is_success() {
-            return Err(ApiError::from_gitlab(v));
+            return Err(ApiError::from_gitlab(v, rsp.headers()));
         } else if status == http::StatusCode::MOVED_PERMANENTLY {
             return Err(ApiError::moved_permanently(
                 rsp.headers().get(http::header::LOCATION),
@@ -594,7 +594,7 @@ mod tests {
             .collect();
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
-            msg,
+            msg, ..
         } = err
         {
             assert_eq!(msg, "dummy error message");
@@ -625,7 +625,7 @@ mod tests {
             .await;
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
-            msg,
+            msg, ..
         } = err
         {
             assert_eq!(msg, "dummy error message");
@@ -655,7 +655,7 @@ mod tests {
             .collect();
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
-            msg,
+            msg, ..
         } = err
         {
             assert_eq!(msg, "dummy error message");
@@ -686,7 +686,7 @@ mod tests {
             .await;
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
-            msg,
+            msg, ..
         } = err
         {
             assert_eq!(msg, "dummy error message");
@@ -714,7 +714,7 @@ mod tests {
             .collect();
         let err = res.unwrap_err();
         if let ApiError::GitlabUnrecognized {
-            obj,
+            obj, ..
         } = err
         {
             assert_eq!(obj, err_obj);
@@ -743,7 +743,7 @@ mod tests {
             .await;
         let err = res.unwrap_err();
         if let ApiError::GitlabUnrecognized {
-            obj,
+            obj, ..
         } = err
         {
             assert_eq!(obj, err_obj);
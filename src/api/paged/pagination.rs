@@ -26,6 +26,17 @@ pub enum PaginationError {
         #[from]
         source: url::ParseError,
     },
+    /// The total number of results was not provided.
+    ///
+    /// GitLab omits the total for large result sets.
+    #[error("the total number of results was not provided")]
+    MissingTotal,
+    /// The total number of results could not be parsed.
+    #[error("failed to parse the total number of results: {}", value)]
+    InvalidTotal {
+        /// The value of the total header.
+        value: String,
+    },
 }
 
 /// Pagination options for GitLab.
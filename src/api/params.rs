@@ -12,7 +12,7 @@
 use std::borrow::Cow;
 
 use chrono::{DateTime, NaiveDate, Utc};
-use serde_json::Value;
+use serde_json::{Map, Value};
 use url::Url;
 
 use crate::api::BodyError;
@@ -141,6 +141,85 @@ impl<'a> FormParams<'a> {
             body.into_bytes(),
         )))
     }
+
+    /// Encode the parameters into a JSON request body.
+    ///
+    /// Keys using bracket notation are expanded into nested values the same way GitLab (Rails)
+    /// parses them from form data: `a[b]` becomes a key `b` within an object `a`, `a[]` appends to
+    /// an array `a`, and `a[][b]` builds an array of objects, starting a new object whenever `b`
+    /// is already set in the last one. All values are sent as strings.
+    pub fn into_json_body(self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut root = Value::Object(Map::new());
+        for (key, value) in self.params {
+            let path = split_form_key(&key);
+            insert_form_value(&mut root, &path, value.into_owned());
+        }
+        JsonParams::into_body(&root)
+    }
+}
+
+/// Split a form key using bracket notation into its path components.
+///
+/// Keys which do not follow the notation are treated as a single component.
+fn split_form_key(key: &str) -> Vec<&str> {
+    let (base, mut rest) = match key.find('[') {
+        Some(idx) if idx > 0 => key.split_at(idx),
+        _ => return vec![key],
+    };
+
+    let mut path = vec![base];
+    while !rest.is_empty() {
+        let end = match (rest.strip_prefix('['), rest.find(']')) {
+            (Some(_), Some(end)) => end,
+            _ => return vec![key],
+        };
+        path.push(&rest[1..end]);
+        rest = &rest[end + 1..];
+    }
+
+    path
+}
+
+/// Insert a value into a JSON value at a form key path.
+fn insert_form_value(target: &mut Value, path: &[&str], value: String) {
+    let (segment, rest) = if let Some((segment, rest)) = path.split_first() {
+        (*segment, rest)
+    } else {
+        *target = Value::String(value);
+        return;
+    };
+
+    if segment.is_empty() {
+        if !target.is_array() {
+            *target = Value::Array(Vec::new());
+        }
+        let array = target.as_array_mut().expect("just made an array");
+
+        if let Some(next) = rest.first() {
+            let reuse_last = array
+                .last()
+                .and_then(Value::as_object)
+                .map(|obj| !obj.contains_key(*next))
+                .unwrap_or(false);
+            if !reuse_last {
+                array.push(Value::Object(Map::new()));
+            }
+            let last = array.last_mut().expect("just ensured an element");
+            insert_form_value(last, rest, value);
+        } else {
+            array.push(Value::String(value));
+        }
+    } else {
+        if !target.is_object() {
+            *target = Value::Object(Map::new());
+        }
+        let entry = target
+            .as_object_mut()
+            .expect("just made an object")
+            .entry(segment)
+            .or_insert(Value::Null);
+        insert_form_value(entry, rest, value);
+    }
 }
 
 /// A structure for JSON parameters.
@@ -171,6 +250,145 @@ impl JsonParams {
     }
 }
 
+/// The boundary between parts of a `multipart/form-data` body.
+const MULTIPART_BOUNDARY: &str = "gitlab-rs-multipart-7a3c9e1f5b2d4086";
+/// The content type of a `multipart/form-data` body using `MULTIPART_BOUNDARY`.
+const MULTIPART_CONTENT_TYPE: &str =
+    "multipart/form-data; boundary=gitlab-rs-multipart-7a3c9e1f5b2d4086";
+
+#[derive(Debug, Clone)]
+enum MultipartValue<'a> {
+    Text(Cow<'a, str>),
+    File {
+        filename: Cow<'a, str>,
+        contents: Cow<'a, [u8]>,
+    },
+}
+
+/// A structure for `multipart/form-data` parameters.
+///
+/// This is required by endpoints which accept file uploads.
+#[derive(Debug, Default, Clone)]
+pub struct MultipartParams<'a> {
+    params: Vec<(Cow<'a, str>, MultipartValue<'a>)>,
+}
+
+impl<'a> MultipartParams<'a> {
+    /// Push a single parameter.
+    pub fn push<'b, K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: ParamValue<'b>,
+        'b: 'a,
+    {
+        self.params
+            .push((key.into(), MultipartValue::Text(value.as_value())));
+        self
+    }
+
+    /// Push a single parameter.
+    pub fn push_opt<'b, K, V>(&mut self, key: K, value: Option<V>) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: ParamValue<'b>,
+        'b: 'a,
+    {
+        if let Some(value) = value {
+            self.push(key, value);
+        }
+        self
+    }
+
+    /// Push a file.
+    pub fn push_file<K, F, C>(&mut self, key: K, filename: F, contents: C) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        F: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, [u8]>>,
+    {
+        self.params.push((
+            key.into(),
+            MultipartValue::File {
+                filename: filename.into(),
+                contents: contents.into(),
+            },
+        ));
+        self
+    }
+
+    /// Encode the parameters into a request body.
+    pub fn into_body(self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut body = Vec::new();
+        for (key, value) in self.params {
+            body.extend_from_slice(b"--");
+            body.extend_from_slice(MULTIPART_BOUNDARY.as_bytes());
+            body.extend_from_slice(b"\r\nContent-Disposition: form-data; name=\"");
+            body.extend_from_slice(multipart_quote(&key).as_bytes());
+            body.push(b'"');
+
+            let contents = match &value {
+                MultipartValue::Text(text) => text.as_bytes(),
+                MultipartValue::File {
+                    filename,
+                    contents,
+                } => {
+                    body.extend_from_slice(b"; filename=\"");
+                    body.extend_from_slice(multipart_quote(filename).as_bytes());
+                    body.extend_from_slice(b"\"\r\nContent-Type: application/octet-stream");
+                    contents.as_ref()
+                },
+            };
+
+            if contains(contents, MULTIPART_BOUNDARY.as_bytes()) {
+                return Err(BodyError::MultipartBoundary {
+                    name: key.into_owned(),
+                });
+            }
+
+            body.extend_from_slice(b"\r\n\r\n");
+            body.extend_from_slice(contents);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(b"--");
+        body.extend_from_slice(MULTIPART_BOUNDARY.as_bytes());
+        body.extend_from_slice(b"--\r\n");
+
+        Ok(Some((MULTIPART_CONTENT_TYPE, body)))
+    }
+}
+
+impl<'a> From<FormParams<'a>> for MultipartParams<'a> {
+    fn from(form: FormParams<'a>) -> Self {
+        Self {
+            params: form
+                .params
+                .into_iter()
+                .map(|(key, value)| (key, MultipartValue::Text(value)))
+                .collect(),
+        }
+    }
+}
+
+/// Escape a name or filename for use in a `Content-Disposition` header.
+fn multipart_quote(value: &str) -> Cow<str> {
+    if value.contains(['"', '\r', '\n']) {
+        value
+            .replace('"', "%22")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+            .into()
+    } else {
+        value.into()
+    }
+}
+
+/// Whether `haystack` contains `needle`.
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
 /// A structure for query parameters.
 #[derive(Debug, Default, Clone)]
 pub struct QueryParams<'a> {
@@ -224,9 +442,9 @@ impl<'a> QueryParams<'a> {
 
 #[cfg(test)]
 mod tests {
-    use serde_json::json;
+    use serde_json::{json, Value};
 
-    use crate::api::{JsonParams, ParamValue};
+    use crate::api::{BodyError, FormParams, JsonParams, MultipartParams, ParamValue};
 
     #[test]
     fn bool_str() {
@@ -265,4 +483,197 @@ mod tests {
 
         assert_eq!(JsonParams::clean(dirty), clean);
     }
+
+    fn form_json(params: FormParams) -> Value {
+        let (mime, body) = params.into_json_body().unwrap().unwrap();
+        assert_eq!(mime, "application/json");
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[test]
+    fn form_params_json_flat() {
+        let mut params = FormParams::default();
+        params.push("name", "name").push("count", 1);
+
+        assert_eq!(
+            form_json(params),
+            json!({
+                "name": "name",
+                "count": "1",
+            }),
+        );
+    }
+
+    #[test]
+    fn form_params_json_nested() {
+        let mut params = FormParams::default();
+        params
+            .push("policy[cadence]", "7d")
+            .push("policy[enabled]", true)
+            .push("a[b][c]", "d");
+
+        assert_eq!(
+            form_json(params),
+            json!({
+                "policy": {
+                    "cadence": "7d",
+                    "enabled": "true",
+                },
+                "a": {
+                    "b": {
+                        "c": "d",
+                    },
+                },
+            }),
+        );
+    }
+
+    #[test]
+    fn form_params_json_array() {
+        let mut params = FormParams::default();
+        params.extend(["a", "b"].iter().map(|&v| ("tags[]", v)));
+
+        assert_eq!(
+            form_json(params),
+            json!({
+                "tags": ["a", "b"],
+            }),
+        );
+    }
+
+    #[test]
+    fn form_params_json_array_of_objects() {
+        let mut params = FormParams::default();
+        params
+            .push("vars[][key]", "k1")
+            .push("vars[][value]", "v1")
+            .push("vars[][key]", "k2")
+            .push("vars[][value]", "v2");
+
+        assert_eq!(
+            form_json(params),
+            json!({
+                "vars": [
+                    {
+                        "key": "k1",
+                        "value": "v1",
+                    },
+                    {
+                        "key": "k2",
+                        "value": "v2",
+                    },
+                ],
+            }),
+        );
+    }
+
+    #[test]
+    fn form_params_json_malformed_keys() {
+        let mut params = FormParams::default();
+        params
+            .push("[leading]", "a")
+            .push("unclosed[key", "b")
+            .push("trailing[key]x", "c");
+
+        assert_eq!(
+            form_json(params),
+            json!({
+                "[leading]": "a",
+                "unclosed[key": "b",
+                "trailing[key]x": "c",
+            }),
+        );
+    }
+
+    #[test]
+    fn multipart_content_type_boundary() {
+        assert_eq!(
+            super::MULTIPART_CONTENT_TYPE,
+            format!(
+                "multipart/form-data; boundary={}",
+                super::MULTIPART_BOUNDARY
+            ),
+        );
+    }
+
+    #[test]
+    fn multipart_params() {
+        let mut params = MultipartParams::default();
+        params
+            .push("name", "value")
+            .push_opt("missing", None::<bool>)
+            .push_opt("flag", Some(true))
+            .push_file("file", "image.png", &b"\x89PNG\r\n"[..]);
+
+        let (content_type, body) = params.into_body().unwrap().unwrap();
+        assert_eq!(
+            content_type,
+            "multipart/form-data; boundary=gitlab-rs-multipart-7a3c9e1f5b2d4086",
+        );
+
+        let mut expected = concat!(
+            "--gitlab-rs-multipart-7a3c9e1f5b2d4086\r\n",
+            "Content-Disposition: form-data; name=\"name\"\r\n",
+            "\r\n",
+            "value\r\n",
+            "--gitlab-rs-multipart-7a3c9e1f5b2d4086\r\n",
+            "Content-Disposition: form-data; name=\"flag\"\r\n",
+            "\r\n",
+            "true\r\n",
+            "--gitlab-rs-multipart-7a3c9e1f5b2d4086\r\n",
+            "Content-Disposition: form-data; name=\"file\"; filename=\"image.png\"\r\n",
+            "Content-Type: application/octet-stream\r\n",
+            "\r\n",
+        )
+        .as_bytes()
+        .to_vec();
+        expected.extend_from_slice(b"\x89PNG\r\n");
+        expected.extend_from_slice(b"\r\n--gitlab-rs-multipart-7a3c9e1f5b2d4086--\r\n");
+        assert_eq!(body, expected);
+    }
+
+    #[test]
+    fn multipart_params_from_form() {
+        let mut form = FormParams::default();
+        form.push("name", "value");
+        let mut params = MultipartParams::from(form);
+        params.push_file("file", "a\"b\r\n.txt", &b"contents"[..]);
+
+        let (_, body) = params.into_body().unwrap().unwrap();
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            concat!(
+                "--gitlab-rs-multipart-7a3c9e1f5b2d4086\r\n",
+                "Content-Disposition: form-data; name=\"name\"\r\n",
+                "\r\n",
+                "value\r\n",
+                "--gitlab-rs-multipart-7a3c9e1f5b2d4086\r\n",
+                "Content-Disposition: form-data; name=\"file\"; filename=\"a%22b%0D%0A.txt\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "contents\r\n",
+                "--gitlab-rs-multipart-7a3c9e1f5b2d4086--\r\n",
+            ),
+        );
+    }
+
+    #[test]
+    fn multipart_params_boundary_in_contents() {
+        let mut params = MultipartParams::default();
+        params.push_file(
+            "file",
+            "evil.bin",
+            &b"--gitlab-rs-multipart-7a3c9e1f5b2d4086--"[..],
+        );
+
+        let err = params.into_body().unwrap_err();
+        if let BodyError::MultipartBoundary {
+            name,
+        } = err
+        {
+            assert_eq!(name, "file");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
 }
@@ -10,6 +10,7 @@
 //!
 //! These endpoints are used for querying and modifying personal access tokens.
 
+mod expires_within;
 mod personal_access_token;
 mod personal_access_token_self;
 mod personal_access_tokens;
@@ -18,6 +19,10 @@ mod revoke_self;
 mod rotate;
 mod rotate_self;
 
+pub use self::expires_within::TokenExpiresWithin;
+pub use self::expires_within::TokenExpiresWithinBuilder;
+pub use self::expires_within::TokenExpiresWithinBuilderError;
+
 pub use self::personal_access_token::PersonalAccessToken;
 pub use self::personal_access_token::PersonalAccessTokenBuilder;
 pub use self::personal_access_token::PersonalAccessTokenBuilderError;
@@ -0,0 +1,206 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use derive_builder::Builder;
+use serde::Deserialize;
+
+use crate::api::personal_access_tokens::PersonalAccessTokenSelf;
+use crate::api::{ApiError, AsyncClient, AsyncQuery, Client, Query};
+
+/// Token information as returned by the `PersonalAccessTokenSelf` endpoint.
+#[derive(Debug, Deserialize)]
+struct TokenInfo {
+    expires_at: Option<NaiveDate>,
+}
+
+impl TokenInfo {
+    fn expires_within(&self, within: Duration, now: DateTime<Utc>) -> Option<Duration> {
+        let expires_at = self.expires_at?;
+        // Tokens expire at midnight (UTC) at the start of their expiration date.
+        let expires_at = Utc.from_utc_datetime(
+            &expires_at
+                .and_hms_opt(0, 0, 0)
+                .expect("midnight is always a valid time"),
+        );
+        // Tokens which have already expired have no time left.
+        let remaining = (expires_at - now)
+            .to_std()
+            .unwrap_or_else(|_| Duration::from_secs(0));
+
+        if remaining <= within {
+            Some(remaining)
+        } else {
+            None
+        }
+    }
+}
+
+/// Query whether the current personal access token expires soon.
+///
+/// The current token is fetched using `PersonalAccessTokenSelf`. The result is the time left
+/// before the token expires if it expires within the given window and `None` otherwise
+/// (including for tokens which never expire). Tokens which have already expired are reported as
+/// having no time left.
+#[derive(Debug, Builder, Clone)]
+pub struct TokenExpiresWithin {
+    /// The window to check for an upcoming expiration.
+    within: Duration,
+}
+
+impl TokenExpiresWithin {
+    /// Create a builder for the query.
+    pub fn builder() -> TokenExpiresWithinBuilder {
+        TokenExpiresWithinBuilder::default()
+    }
+
+    fn endpoint(&self) -> PersonalAccessTokenSelf {
+        PersonalAccessTokenSelf::builder()
+            .build()
+            .expect("no fields are required")
+    }
+}
+
+impl<C> Query<Option<Duration>, C> for TokenExpiresWithin
+where
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Option<Duration>, ApiError<C::Error>> {
+        let info: TokenInfo = self.endpoint().query(client)?;
+        Ok(info.expires_within(self.within, Utc::now()))
+    }
+}
+
+#[async_trait]
+impl<C> AsyncQuery<Option<Duration>, C> for TokenExpiresWithin
+where
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Option<Duration>, ApiError<C::Error>> {
+        let info: TokenInfo = self.endpoint().query_async(client).await?;
+        Ok(info.expires_within(self.within, Utc::now()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use serde_json::json;
+
+    use crate::api::personal_access_tokens::{TokenExpiresWithin, TokenExpiresWithinBuilderError};
+    use crate::api::{AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    use super::TokenInfo;
+
+    const DAY: u64 = 24 * 60 * 60;
+
+    fn client(expires_at: Option<NaiveDate>) -> SingleTestClient {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("personal_access_tokens/self")
+            .build()
+            .unwrap();
+        SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "id": 42,
+                "name": "ci-token",
+                "scopes": ["api"],
+                "active": true,
+                "expires_at": expires_at,
+            }),
+        )
+    }
+
+    fn in_days(days: i64) -> NaiveDate {
+        Utc::now().date_naive() + chrono::Duration::days(days)
+    }
+
+    fn query(days: u64) -> TokenExpiresWithin {
+        TokenExpiresWithin::builder()
+            .within(Duration::from_secs(days * DAY))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn within_is_needed() {
+        let err = TokenExpiresWithin::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, TokenExpiresWithinBuilderError, "within");
+    }
+
+    #[test]
+    fn within_is_sufficient() {
+        TokenExpiresWithin::builder()
+            .within(Duration::from_secs(DAY))
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn expiring_token() {
+        let client = client(Some(in_days(2)));
+
+        let remaining = query(7).query(&client).unwrap().unwrap();
+        assert!(remaining > Duration::from_secs(DAY));
+        assert!(remaining <= Duration::from_secs(2 * DAY));
+    }
+
+    #[test]
+    fn token_expiring_outside_window() {
+        let client = client(Some(in_days(30)));
+
+        assert_eq!(query(7).query(&client).unwrap(), None);
+    }
+
+    #[test]
+    fn non_expiring_token() {
+        let client = client(None);
+
+        assert_eq!(query(7).query(&client).unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn expiring_token_async() {
+        let client = client(Some(in_days(2)));
+
+        let remaining = query(7).query_async(&client).await.unwrap().unwrap();
+        assert!(remaining > Duration::from_secs(DAY));
+        assert!(remaining <= Duration::from_secs(2 * DAY));
+    }
+
+    #[test]
+    fn expires_within_exact() {
+        let info = TokenInfo {
+            expires_at: NaiveDate::from_ymd_opt(2024, 8, 1),
+        };
+        let now = Utc.with_ymd_and_hms(2024, 7, 31, 18, 0, 0).unwrap();
+
+        assert_eq!(
+            info.expires_within(Duration::from_secs(DAY), now),
+            Some(Duration::from_secs(6 * 60 * 60)),
+        );
+        assert_eq!(info.expires_within(Duration::from_secs(60 * 60), now), None);
+    }
+
+    #[test]
+    fn expires_within_expired() {
+        let info = TokenInfo {
+            expires_at: NaiveDate::from_ymd_opt(2024, 8, 1),
+        };
+        let now = Utc.with_ymd_and_hms(2024, 8, 2, 0, 0, 0).unwrap();
+
+        assert_eq!(
+            info.expires_within(Duration::from_secs(DAY), now),
+            Some(Duration::from_secs(0)),
+        );
+    }
+}
@@ -12,42 +12,73 @@
 
 pub mod access_requests;
 pub mod access_tokens;
+pub mod approval_rules;
+pub mod approvals;
 mod archive;
+mod audit_events;
+mod avatar;
+pub mod badges;
+pub mod boards;
+pub mod cluster_agents;
 mod create;
+pub mod custom_attributes;
 mod delete;
+mod dependencies;
 pub mod deploy_keys;
 pub mod deployments;
 mod edit;
 pub mod environments;
+pub mod error_tracking;
+mod events;
+mod exists;
+pub mod feature_flags;
+pub mod freeze_periods;
 pub mod hooks;
+mod import_status;
+pub mod integrations;
 pub mod issues;
+pub mod job_token_scope;
 pub mod jobs;
 pub mod labels;
+mod languages;
 pub mod members;
 pub mod merge_requests;
 pub mod merge_trains;
 pub mod milestones;
+pub mod notification_settings;
 pub mod packages;
 pub mod pipeline_schedules;
 pub mod pipelines;
 mod project;
 mod projects;
 pub mod protected_branches;
+pub mod protected_environments;
 pub mod protected_tags;
 pub mod push_rule;
 pub mod registry;
 pub mod releases;
 pub mod repository;
+pub mod repository_storage_moves;
 pub mod runners;
 mod share;
 mod unarchive;
 mod unshare;
 pub mod variables;
+mod vulnerabilities;
+pub mod wikis;
 
 pub use self::archive::ArchiveProject;
 pub use self::archive::ArchiveProjectBuilder;
 pub use self::archive::ArchiveProjectBuilderError;
 
+pub use self::audit_events::ProjectAuditEvents;
+pub use self::audit_events::ProjectAuditEventsBuilder;
+pub use self::audit_events::ProjectAuditEventsBuilderError;
+
+pub use self::avatar::ProjectAvatar;
+pub use self::avatar::ProjectAvatarBuilder;
+pub use self::avatar::ProjectAvatarBuilderError;
+
 pub use self::create::AutoDevOpsDeployStrategy;
 pub use self::create::BuildGitStrategy;
 pub use self::create::ContainerExpirationCadence;
@@ -68,10 +99,31 @@ pub use self::delete::DeleteProject;
 pub use self::delete::DeleteProjectBuilder;
 pub use self::delete::DeleteProjectBuilderError;
 
+pub use self::dependencies::DependencyPackageManager;
+pub use self::dependencies::ProjectDependencies;
+pub use self::dependencies::ProjectDependenciesBuilder;
+pub use self::dependencies::ProjectDependenciesBuilderError;
+
 pub use self::edit::EditProject;
 pub use self::edit::EditProjectBuilder;
 pub use self::edit::EditProjectBuilderError;
 
+pub use self::events::ProjectEvents;
+pub use self::events::ProjectEventsBuilder;
+pub use self::events::ProjectEventsBuilderError;
+
+pub use self::exists::ProjectExists;
+pub use self::exists::ProjectExistsBuilder;
+pub use self::exists::ProjectExistsBuilderError;
+
+pub use self::import_status::ImportStatus;
+pub use self::import_status::ImportStatusBuilder;
+pub use self::import_status::ImportStatusBuilderError;
+
+pub use self::languages::ProjectLanguages;
+pub use self::languages::ProjectLanguagesBuilder;
+pub use self::languages::ProjectLanguagesBuilderError;
+
 pub use self::project::Project;
 pub use self::project::ProjectBuilder;
 pub use self::project::ProjectBuilderError;
@@ -92,3 +144,7 @@ pub use self::unarchive::UnarchiveProjectBuilderError;
 pub use self::unshare::UnshareProject;
 pub use self::unshare::UnshareProjectBuilder;
 pub use self::unshare::UnshareProjectBuilderError;
+
+pub use self::vulnerabilities::ProjectVulnerabilities;
+pub use self::vulnerabilities::ProjectVulnerabilitiesBuilder;
+pub use self::vulnerabilities::ProjectVulnerabilitiesBuilderError;
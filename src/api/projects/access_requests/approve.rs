@@ -232,4 +232,24 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_by_id_maintainer() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/1/access_requests/5/approve")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("access_level=40")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectAccessRequestsApprove::builder()
+            .project(1)
+            .user_id(5)
+            .access_level(ProjectAccessLevel::Maintainer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
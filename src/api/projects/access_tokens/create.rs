@@ -13,7 +13,7 @@ use crate::api::common::{AccessLevel, NameOrId};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
-/// Scopes for personal access tokens.
+/// Scopes for project and group access tokens.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[non_exhaustive]
 pub enum ProjectAccessTokenScope {
@@ -88,21 +88,21 @@ impl From<ProjectAccessTokenAccessLevel> for AccessLevel {
     }
 }
 
-/// Create a new personal access token for the authenticated user.
+/// Create a new access token for a project.
 #[derive(Debug, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct CreateProjectAccessToken<'a> {
     /// The project to create the access token for.
     #[builder(setter(into))]
     project: NameOrId<'a>,
-    /// The name of the personal access token.
+    /// The name of the access token.
     #[builder(setter(into))]
     name: Cow<'a, str>,
     /// The scopes to allow the token to access.
     #[builder(setter(name = "_scopes"), private)]
     scopes: BTreeSet<ProjectAccessTokenScope>,
 
-    /// When the token expires.
+    /// The access level of the token within the project.
     #[builder(default)]
     access_level: Option<ProjectAccessTokenAccessLevel>,
     /// When the token expires.
@@ -173,7 +173,7 @@ mod tests {
     use crate::test::client::{ExpectedUrl, SingleTestClient};
 
     #[test]
-    fn personal_access_token_create_scope_as_str() {
+    fn project_access_token_scope_as_str() {
         let items = &[
             (ProjectAccessTokenScope::Api, "api"),
             (ProjectAccessTokenScope::ReadApi, "read_api"),
@@ -332,4 +332,38 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_scopes() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=name",
+                "&scopes%5B%5D=api",
+                "&scopes%5B%5D=read_repository",
+                "&scopes%5B%5D=write_repository",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectAccessToken::builder()
+            .project("simple/project")
+            .name("name")
+            .scope(ProjectAccessTokenScope::WriteRepository)
+            .scopes(
+                [
+                    ProjectAccessTokenScope::Api,
+                    ProjectAccessTokenScope::ReadRepository,
+                    ProjectAccessTokenScope::Api,
+                ]
+                .iter()
+                .cloned(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
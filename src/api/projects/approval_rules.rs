@@ -0,0 +1,36 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project approval rule API endpoints.
+//!
+//! These endpoints are used for querying and modifying project-level merge request approval rules.
+//! Approval rules require GitLab Premium or Ultimate.
+
+mod approval_rule;
+mod approval_rules;
+mod create;
+mod delete;
+mod edit;
+
+pub use self::approval_rule::ProjectApprovalRule;
+pub use self::approval_rule::ProjectApprovalRuleBuilder;
+pub use self::approval_rule::ProjectApprovalRuleBuilderError;
+
+pub use self::approval_rules::ProjectApprovalRules;
+pub use self::approval_rules::ProjectApprovalRulesBuilder;
+pub use self::approval_rules::ProjectApprovalRulesBuilderError;
+
+pub use self::create::CreateProjectApprovalRule;
+pub use self::create::CreateProjectApprovalRuleBuilder;
+pub use self::create::CreateProjectApprovalRuleBuilderError;
+
+pub use self::delete::DeleteProjectApprovalRule;
+pub use self::delete::DeleteProjectApprovalRuleBuilder;
+pub use self::delete::DeleteProjectApprovalRuleBuilderError;
+
+pub use self::edit::EditProjectApprovalRule;
+pub use self::edit::EditProjectApprovalRuleBuilder;
+pub use self::edit::EditProjectApprovalRuleBuilderError;
@@ -0,0 +1,99 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for a single approval rule of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ProjectApprovalRule<'a> {
+    /// The project to query for the approval rule.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the approval rule.
+    approval_rule: u64,
+}
+
+impl<'a> ProjectApprovalRule<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectApprovalRuleBuilder<'a> {
+        ProjectApprovalRuleBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectApprovalRule<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/approval_rules/{}",
+            self.project, self.approval_rule,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::approval_rules::{
+        ProjectApprovalRule, ProjectApprovalRuleBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_approval_rule_are_needed() {
+        let err = ProjectApprovalRule::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectApprovalRuleBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectApprovalRule::builder()
+            .approval_rule(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectApprovalRuleBuilderError, "project");
+    }
+
+    #[test]
+    fn approval_rule_is_needed() {
+        let err = ProjectApprovalRule::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectApprovalRuleBuilderError, "approval_rule");
+    }
+
+    #[test]
+    fn project_and_approval_rule_are_sufficient() {
+        ProjectApprovalRule::builder()
+            .project(1)
+            .approval_rule(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/approval_rules/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectApprovalRule::builder()
+            .project("simple/project")
+            .approval_rule(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,319 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Create an approval rule for a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreateProjectApprovalRule<'a> {
+    /// The project to create the approval rule within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the approval rule.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    /// The number of approvals required by the rule.
+    approvals_required: u64,
+
+    /// Whether the rule applies to all protected branches.
+    ///
+    /// Ignores `protected_branch_ids` if set.
+    #[builder(default)]
+    applies_to_all_protected_branches: Option<bool>,
+    /// The IDs of users eligible to approve.
+    #[builder(setter(name = "_user_ids"), default, private)]
+    user_ids: BTreeSet<u64>,
+    /// The IDs of groups whose members are eligible to approve.
+    #[builder(setter(name = "_group_ids"), default, private)]
+    group_ids: BTreeSet<u64>,
+    /// The IDs of protected branches the rule applies to.
+    #[builder(setter(name = "_protected_branch_ids"), default, private)]
+    protected_branch_ids: BTreeSet<u64>,
+}
+
+impl<'a> CreateProjectApprovalRule<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateProjectApprovalRuleBuilder<'a> {
+        CreateProjectApprovalRuleBuilder::default()
+    }
+}
+
+impl<'a> CreateProjectApprovalRuleBuilder<'a> {
+    /// Add a user eligible to approve.
+    pub fn user_id(&mut self, user: u64) -> &mut Self {
+        self.user_ids.get_or_insert_with(BTreeSet::new).insert(user);
+        self
+    }
+
+    /// Add a set of users eligible to approve.
+    pub fn user_ids<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = u64>,
+    {
+        self.user_ids.get_or_insert_with(BTreeSet::new).extend(iter);
+        self
+    }
+
+    /// Add a group whose members are eligible to approve.
+    pub fn group_id(&mut self, group: u64) -> &mut Self {
+        self.group_ids
+            .get_or_insert_with(BTreeSet::new)
+            .insert(group);
+        self
+    }
+
+    /// Add a set of groups whose members are eligible to approve.
+    pub fn group_ids<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = u64>,
+    {
+        self.group_ids.get_or_insert_with(BTreeSet::new).extend(iter);
+        self
+    }
+
+    /// Add a protected branch the rule applies to.
+    pub fn protected_branch_id(&mut self, branch: u64) -> &mut Self {
+        self.protected_branch_ids
+            .get_or_insert_with(BTreeSet::new)
+            .insert(branch);
+        self
+    }
+
+    /// Add a set of protected branches the rule applies to.
+    pub fn protected_branch_ids<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = u64>,
+    {
+        self.protected_branch_ids
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter);
+        self
+    }
+}
+
+impl<'a> Endpoint for CreateProjectApprovalRule<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/approval_rules", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("name", &self.name)
+            .push("approvals_required", self.approvals_required)
+            .push_opt(
+                "applies_to_all_protected_branches",
+                self.applies_to_all_protected_branches,
+            )
+            .extend(self.user_ids.iter().map(|&value| ("user_ids[]", value)))
+            .extend(self.group_ids.iter().map(|&value| ("group_ids[]", value)))
+            .extend(
+                self.protected_branch_ids
+                    .iter()
+                    .map(|&value| ("protected_branch_ids[]", value)),
+            );
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::approval_rules::{
+        CreateProjectApprovalRule, CreateProjectApprovalRuleBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_name_and_approvals_required_are_needed() {
+        let err = CreateProjectApprovalRule::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateProjectApprovalRuleBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateProjectApprovalRule::builder()
+            .name("rule")
+            .approvals_required(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateProjectApprovalRuleBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = CreateProjectApprovalRule::builder()
+            .project(1)
+            .approvals_required(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateProjectApprovalRuleBuilderError, "name");
+    }
+
+    #[test]
+    fn approvals_required_is_needed() {
+        let err = CreateProjectApprovalRule::builder()
+            .project(1)
+            .name("rule")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateProjectApprovalRuleBuilderError,
+            "approvals_required",
+        );
+    }
+
+    #[test]
+    fn project_name_and_approvals_required_are_sufficient() {
+        CreateProjectApprovalRule::builder()
+            .project(1)
+            .name("rule")
+            .approvals_required(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/approval_rules")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=rule", "&approvals_required=2"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectApprovalRule::builder()
+            .project("simple/project")
+            .name("rule")
+            .approvals_required(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_applies_to_all_protected_branches() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/approval_rules")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=rule",
+                "&approvals_required=2",
+                "&applies_to_all_protected_branches=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectApprovalRule::builder()
+            .project("simple/project")
+            .name("rule")
+            .approvals_required(2)
+            .applies_to_all_protected_branches(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_user_ids() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/approval_rules")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=rule",
+                "&approvals_required=2",
+                "&user_ids%5B%5D=1",
+                "&user_ids%5B%5D=2",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectApprovalRule::builder()
+            .project("simple/project")
+            .name("rule")
+            .approvals_required(2)
+            .user_id(1)
+            .user_ids([2].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_group_ids() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/approval_rules")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=rule",
+                "&approvals_required=2",
+                "&group_ids%5B%5D=1",
+                "&group_ids%5B%5D=2",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectApprovalRule::builder()
+            .project("simple/project")
+            .name("rule")
+            .approvals_required(2)
+            .group_id(1)
+            .group_ids([2].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_protected_branch_ids() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/approval_rules")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=rule",
+                "&approvals_required=2",
+                "&protected_branch_ids%5B%5D=1",
+                "&protected_branch_ids%5B%5D=2",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectApprovalRule::builder()
+            .project("simple/project")
+            .name("rule")
+            .approvals_required(2)
+            .protected_branch_id(1)
+            .protected_branch_ids([2].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
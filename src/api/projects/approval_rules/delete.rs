@@ -0,0 +1,106 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete an approval rule of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteProjectApprovalRule<'a> {
+    /// The project to delete the approval rule from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the approval rule to delete.
+    approval_rule: u64,
+}
+
+impl<'a> DeleteProjectApprovalRule<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteProjectApprovalRuleBuilder<'a> {
+        DeleteProjectApprovalRuleBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteProjectApprovalRule<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/approval_rules/{}",
+            self.project, self.approval_rule,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::approval_rules::{
+        DeleteProjectApprovalRule, DeleteProjectApprovalRuleBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_approval_rule_are_needed() {
+        let err = DeleteProjectApprovalRule::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectApprovalRuleBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteProjectApprovalRule::builder()
+            .approval_rule(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectApprovalRuleBuilderError, "project");
+    }
+
+    #[test]
+    fn approval_rule_is_needed() {
+        let err = DeleteProjectApprovalRule::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            DeleteProjectApprovalRuleBuilderError,
+            "approval_rule"
+        );
+    }
+
+    #[test]
+    fn project_and_approval_rule_are_sufficient() {
+        DeleteProjectApprovalRule::builder()
+            .project(1)
+            .approval_rule(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/approval_rules/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteProjectApprovalRule::builder()
+            .project("simple/project")
+            .approval_rule(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
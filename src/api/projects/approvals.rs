@@ -0,0 +1,21 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project approval configuration API endpoints.
+//!
+//! These endpoints are used for querying and modifying the project-level merge request approval
+//! settings. Approval rules are managed through the `approval_rules` module.
+
+mod approvals;
+mod edit;
+
+pub use self::approvals::ProjectApprovalSettings;
+pub use self::approvals::ProjectApprovalSettingsBuilder;
+pub use self::approvals::ProjectApprovalSettingsBuilderError;
+
+pub use self::edit::EditProjectApprovalSettings;
+pub use self::edit::EditProjectApprovalSettingsBuilder;
+pub use self::edit::EditProjectApprovalSettingsBuilderError;
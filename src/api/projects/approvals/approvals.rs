@@ -0,0 +1,70 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the merge request approval settings of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ProjectApprovalSettings<'a> {
+    /// The project to query for approval settings.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ProjectApprovalSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectApprovalSettingsBuilder<'a> {
+        ProjectApprovalSettingsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectApprovalSettings<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/approvals", self.project).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::approvals::{
+        ProjectApprovalSettings, ProjectApprovalSettingsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectApprovalSettings::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectApprovalSettingsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectApprovalSettings::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/approvals")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectApprovalSettings::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
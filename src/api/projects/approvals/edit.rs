@@ -0,0 +1,215 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Edit the merge request approval settings of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditProjectApprovalSettings<'a> {
+    /// The project to edit the approval settings of.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Whether approvals are reset when new commits are pushed.
+    #[builder(default)]
+    reset_approvals_on_push: Option<bool>,
+    /// Whether approval rules may not be overridden per merge request.
+    #[builder(default)]
+    disable_overriding_approvers_per_merge_request: Option<bool>,
+    /// Whether merge request authors may approve their own merge requests.
+    #[builder(default)]
+    merge_requests_author_approval: Option<bool>,
+    /// Whether committers to a merge request are prevented from approving it.
+    #[builder(default)]
+    merge_requests_disable_committers_approval: Option<bool>,
+    /// Whether users must enter their password to approve.
+    #[builder(default)]
+    require_password_to_approve: Option<bool>,
+}
+
+impl<'a> EditProjectApprovalSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditProjectApprovalSettingsBuilder<'a> {
+        EditProjectApprovalSettingsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for EditProjectApprovalSettings<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/approvals", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("reset_approvals_on_push", self.reset_approvals_on_push)
+            .push_opt(
+                "disable_overriding_approvers_per_merge_request",
+                self.disable_overriding_approvers_per_merge_request,
+            )
+            .push_opt(
+                "merge_requests_author_approval",
+                self.merge_requests_author_approval,
+            )
+            .push_opt(
+                "merge_requests_disable_committers_approval",
+                self.merge_requests_disable_committers_approval,
+            )
+            .push_opt(
+                "require_password_to_approve",
+                self.require_password_to_approve,
+            );
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::approvals::{
+        EditProjectApprovalSettings, EditProjectApprovalSettingsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditProjectApprovalSettings::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditProjectApprovalSettingsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        EditProjectApprovalSettings::builder()
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/approvals")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProjectApprovalSettings::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_reset_approvals_on_push() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/approvals")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("reset_approvals_on_push=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProjectApprovalSettings::builder()
+            .project("simple/project")
+            .reset_approvals_on_push(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_disable_overriding_approvers_per_merge_request() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/approvals")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("disable_overriding_approvers_per_merge_request=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProjectApprovalSettings::builder()
+            .project("simple/project")
+            .disable_overriding_approvers_per_merge_request(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_merge_requests_author_approval() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/approvals")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("merge_requests_author_approval=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProjectApprovalSettings::builder()
+            .project("simple/project")
+            .merge_requests_author_approval(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_merge_requests_disable_committers_approval() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/approvals")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("merge_requests_disable_committers_approval=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProjectApprovalSettings::builder()
+            .project("simple/project")
+            .merge_requests_disable_committers_approval(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_require_password_to_approve() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/approvals")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("require_password_to_approve=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProjectApprovalSettings::builder()
+            .project("simple/project")
+            .require_password_to_approve(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,131 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project audit events API endpoint.
+//!
+//! This endpoint is used for querying the audit events of a project.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for audit events of a project.
+///
+/// Audit events are only available on GitLab Premium and Ultimate instances.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct ProjectAuditEvents<'a> {
+    /// The project to query for audit events.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Filter events created after a time.
+    #[builder(default)]
+    created_after: Option<DateTime<Utc>>,
+    /// Filter events created before a time.
+    #[builder(default)]
+    created_before: Option<DateTime<Utc>>,
+}
+
+impl<'a> ProjectAuditEvents<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectAuditEventsBuilder<'a> {
+        ProjectAuditEventsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectAuditEvents<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/audit_events", self.project).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("created_after", self.created_after)
+            .push_opt("created_before", self.created_before);
+
+        params
+    }
+}
+
+impl<'a> Pageable for ProjectAuditEvents<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::api::projects::{ProjectAuditEvents, ProjectAuditEventsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectAuditEvents::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectAuditEventsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectAuditEvents::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/audit_events")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectAuditEvents::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/1/audit_events")
+            .add_query_params(&[("created_after", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectAuditEvents::builder()
+            .project(1)
+            .created_after(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/1/audit_events")
+            .add_query_params(&[("created_before", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectAuditEvents::builder()
+            .project(1)
+            .created_before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
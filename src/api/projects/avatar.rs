@@ -0,0 +1,71 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Download the avatar of a project.
+///
+/// The response is the image itself; use `api::raw` to retrieve it.
+#[derive(Debug, Builder, Clone)]
+pub struct ProjectAvatar<'a> {
+    /// The project to get the avatar of.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ProjectAvatar<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectAvatarBuilder<'a> {
+        ProjectAvatarBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectAvatar<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/avatar", self.project).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::{ProjectAvatar, ProjectAvatarBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectAvatar::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectAvatarBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectAvatar::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/avatar")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, &b"\x89PNG\r\n\x1a\n"[..]);
+
+        let endpoint = ProjectAvatar::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        let avatar = api::raw(endpoint).query(&client).unwrap();
+        assert_eq!(avatar, b"\x89PNG\r\n\x1a\n");
+    }
+}
@@ -0,0 +1,42 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project badge API endpoints.
+//!
+//! These endpoints are used for querying and modifying badges of a project. Badge URLs may
+//! contain placeholders (e.g., `%{project_path}` or `%{default_branch}`) which are expanded by
+//! GitLab in the `rendered_link_url` and `rendered_image_url` fields of the response.
+
+mod add;
+mod badge;
+mod badges;
+mod delete;
+mod edit;
+mod render;
+
+pub use self::add::AddProjectBadge;
+pub use self::add::AddProjectBadgeBuilder;
+pub use self::add::AddProjectBadgeBuilderError;
+
+pub use self::badge::ProjectBadge;
+pub use self::badge::ProjectBadgeBuilder;
+pub use self::badge::ProjectBadgeBuilderError;
+
+pub use self::badges::ProjectBadges;
+pub use self::badges::ProjectBadgesBuilder;
+pub use self::badges::ProjectBadgesBuilderError;
+
+pub use self::delete::DeleteProjectBadge;
+pub use self::delete::DeleteProjectBadgeBuilder;
+pub use self::delete::DeleteProjectBadgeBuilderError;
+
+pub use self::edit::EditProjectBadge;
+pub use self::edit::EditProjectBadgeBuilder;
+pub use self::edit::EditProjectBadgeBuilderError;
+
+pub use self::render::RenderProjectBadge;
+pub use self::render::RenderProjectBadgeBuilder;
+pub use self::render::RenderProjectBadgeBuilderError;
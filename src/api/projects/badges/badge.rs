@@ -0,0 +1,83 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for a badge of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ProjectBadge<'a> {
+    /// The project of the badge.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the badge.
+    badge: u64,
+}
+
+impl<'a> ProjectBadge<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectBadgeBuilder<'a> {
+        ProjectBadgeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectBadge<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/badges/{}", self.project, self.badge).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::badges::{ProjectBadge, ProjectBadgeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_badge_are_needed() {
+        let err = ProjectBadge::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectBadgeBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectBadge::builder().badge(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectBadgeBuilderError, "project");
+    }
+
+    #[test]
+    fn badge_is_needed() {
+        let err = ProjectBadge::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectBadgeBuilderError, "badge");
+    }
+
+    #[test]
+    fn project_and_badge_are_sufficient() {
+        ProjectBadge::builder().project(1).badge(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/badges/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectBadge::builder()
+            .project("simple/project")
+            .badge(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
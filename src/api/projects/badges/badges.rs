@@ -0,0 +1,100 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for badges of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct ProjectBadges<'a> {
+    /// The project to query for badges.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Filter badges by name.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+}
+
+impl<'a> ProjectBadges<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectBadgesBuilder<'a> {
+        ProjectBadgesBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectBadges<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/badges", self.project).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("name", self.name.as_ref());
+
+        params
+    }
+}
+
+impl<'a> Pageable for ProjectBadges<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::badges::{ProjectBadges, ProjectBadgesBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectBadges::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectBadgesBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectBadges::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/badges")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectBadges::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/badges")
+            .add_query_params(&[("name", "coverage")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectBadges::builder()
+            .project("simple/project")
+            .name("coverage")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,93 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete a badge from a project.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteProjectBadge<'a> {
+    /// The project of the badge.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the badge.
+    badge: u64,
+}
+
+impl<'a> DeleteProjectBadge<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteProjectBadgeBuilder<'a> {
+        DeleteProjectBadgeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteProjectBadge<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/badges/{}", self.project, self.badge).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::badges::{DeleteProjectBadge, DeleteProjectBadgeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_badge_are_needed() {
+        let err = DeleteProjectBadge::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectBadgeBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteProjectBadge::builder().badge(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectBadgeBuilderError, "project");
+    }
+
+    #[test]
+    fn badge_is_needed() {
+        let err = DeleteProjectBadge::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectBadgeBuilderError, "badge");
+    }
+
+    #[test]
+    fn project_and_badge_are_sufficient() {
+        DeleteProjectBadge::builder()
+            .project(1)
+            .badge(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/badges/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteProjectBadge::builder()
+            .project("simple/project")
+            .badge(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
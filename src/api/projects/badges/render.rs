@@ -0,0 +1,127 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Preview how badge URLs are rendered for a project.
+///
+/// Placeholders in the URLs are expanded as they would be for a badge on the project.
+#[derive(Debug, Builder, Clone)]
+pub struct RenderProjectBadge<'a> {
+    /// The project to render the badge for.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The URL the badge links to.
+    #[builder(setter(into))]
+    link_url: Cow<'a, str>,
+    /// The URL of the badge image.
+    #[builder(setter(into))]
+    image_url: Cow<'a, str>,
+}
+
+impl<'a> RenderProjectBadge<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RenderProjectBadgeBuilder<'a> {
+        RenderProjectBadgeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for RenderProjectBadge<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/badges/render", self.project).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push("link_url", &self.link_url)
+            .push("image_url", &self.image_url);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::badges::{RenderProjectBadge, RenderProjectBadgeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_link_url_and_image_url_are_needed() {
+        let err = RenderProjectBadge::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RenderProjectBadgeBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = RenderProjectBadge::builder()
+            .link_url("https://example.com")
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RenderProjectBadgeBuilderError, "project");
+    }
+
+    #[test]
+    fn link_url_is_needed() {
+        let err = RenderProjectBadge::builder()
+            .project(1)
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RenderProjectBadgeBuilderError, "link_url");
+    }
+
+    #[test]
+    fn image_url_is_needed() {
+        let err = RenderProjectBadge::builder()
+            .project(1)
+            .link_url("https://example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RenderProjectBadgeBuilderError, "image_url");
+    }
+
+    #[test]
+    fn project_link_url_and_image_url_are_sufficient() {
+        RenderProjectBadge::builder()
+            .project(1)
+            .link_url("https://example.com")
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/badges/render")
+            .add_query_params(&[
+                ("link_url", "https://example.com/%{project_path}"),
+                ("image_url", "https://example.com/%{default_branch}.svg"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RenderProjectBadge::builder()
+            .project("simple/project")
+            .link_url("https://example.com/%{project_path}")
+            .image_url("https://example.com/%{default_branch}.svg")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
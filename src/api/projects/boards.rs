@@ -0,0 +1,36 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project issue board API endpoints.
+//!
+//! These endpoints are used for querying and modifying project issue boards and their lists.
+
+mod board;
+mod boards;
+mod create;
+mod delete;
+mod edit;
+pub mod lists;
+
+pub use self::board::IssueBoard;
+pub use self::board::IssueBoardBuilder;
+pub use self::board::IssueBoardBuilderError;
+
+pub use self::boards::IssueBoards;
+pub use self::boards::IssueBoardsBuilder;
+pub use self::boards::IssueBoardsBuilderError;
+
+pub use self::create::CreateIssueBoard;
+pub use self::create::CreateIssueBoardBuilder;
+pub use self::create::CreateIssueBoardBuilderError;
+
+pub use self::delete::DeleteIssueBoard;
+pub use self::delete::DeleteIssueBoardBuilder;
+pub use self::delete::DeleteIssueBoardBuilderError;
+
+pub use self::edit::EditIssueBoard;
+pub use self::edit::EditIssueBoardBuilder;
+pub use self::edit::EditIssueBoardBuilderError;
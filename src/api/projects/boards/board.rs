@@ -0,0 +1,83 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for a single issue board of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct IssueBoard<'a> {
+    /// The project to query for the issue board.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the issue board.
+    board: u64,
+}
+
+impl<'a> IssueBoard<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> IssueBoardBuilder<'a> {
+        IssueBoardBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for IssueBoard<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/boards/{}", self.project, self.board,).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::boards::{IssueBoard, IssueBoardBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_board_are_needed() {
+        let err = IssueBoard::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = IssueBoard::builder().board(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = IssueBoard::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueBoardBuilderError, "board");
+    }
+
+    #[test]
+    fn project_and_board_are_sufficient() {
+        IssueBoard::builder().project(1).board(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = IssueBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,70 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for issue boards of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct IssueBoards<'a> {
+    /// The project to query for issue boards.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> IssueBoards<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> IssueBoardsBuilder<'a> {
+        IssueBoardsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for IssueBoards<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/boards", self.project).into()
+    }
+}
+
+impl<'a> Pageable for IssueBoards<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::boards::{IssueBoards, IssueBoardsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = IssueBoards::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueBoardsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        IssueBoards::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/boards")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = IssueBoards::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,104 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Create a new issue board on a project.
+#[derive(Debug, Builder, Clone)]
+pub struct CreateIssueBoard<'a> {
+    /// The project to create the issue board on.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the issue board.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl<'a> CreateIssueBoard<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateIssueBoardBuilder<'a> {
+        CreateIssueBoardBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateIssueBoard<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/boards", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("name", &self.name);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::boards::{CreateIssueBoard, CreateIssueBoardBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_name_are_needed() {
+        let err = CreateIssueBoard::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateIssueBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateIssueBoard::builder()
+            .name("name")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateIssueBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = CreateIssueBoard::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateIssueBoardBuilderError, "name");
+    }
+
+    #[test]
+    fn project_and_name_are_sufficient() {
+        CreateIssueBoard::builder()
+            .project(1)
+            .name("name")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/boards")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=name")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateIssueBoard::builder()
+            .project("simple/project")
+            .name("name")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
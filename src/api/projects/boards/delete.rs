@@ -0,0 +1,90 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete an issue board of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteIssueBoard<'a> {
+    /// The project to delete the issue board from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the issue board to delete.
+    board: u64,
+}
+
+impl<'a> DeleteIssueBoard<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteIssueBoardBuilder<'a> {
+        DeleteIssueBoardBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteIssueBoard<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/boards/{}", self.project, self.board,).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::boards::{DeleteIssueBoard, DeleteIssueBoardBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_board_are_needed() {
+        let err = DeleteIssueBoard::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteIssueBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteIssueBoard::builder().board(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteIssueBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = DeleteIssueBoard::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteIssueBoardBuilderError, "board");
+    }
+
+    #[test]
+    fn project_and_board_are_sufficient() {
+        DeleteIssueBoard::builder()
+            .project(1)
+            .board(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteIssueBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
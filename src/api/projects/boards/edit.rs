@@ -0,0 +1,298 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{CommaSeparatedList, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Edit an issue board of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditIssueBoard<'a> {
+    /// The project of the issue board.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the issue board.
+    board: u64,
+
+    /// The new name of the issue board.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+    /// The assignee the board is scoped to.
+    #[builder(default)]
+    assignee_id: Option<u64>,
+    /// The milestone the board is scoped to.
+    #[builder(default)]
+    milestone_id: Option<u64>,
+    /// The labels the board is scoped to.
+    #[builder(setter(name = "_labels"), default, private)]
+    labels: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// The weight the board is scoped to.
+    #[builder(default)]
+    weight: Option<u64>,
+    /// Hide the "Open" list.
+    #[builder(default)]
+    hide_backlog_list: Option<bool>,
+    /// Hide the "Closed" list.
+    #[builder(default)]
+    hide_closed_list: Option<bool>,
+}
+
+impl<'a> EditIssueBoard<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditIssueBoardBuilder<'a> {
+        EditIssueBoardBuilder::default()
+    }
+}
+
+impl<'a> EditIssueBoardBuilder<'a> {
+    /// Scope the board to a label.
+    pub fn label<L>(&mut self, label: L) -> &mut Self
+    where
+        L: Into<Cow<'a, str>>,
+    {
+        self.labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(label.into());
+        self
+    }
+
+    /// Scope the board to multiple labels.
+    pub fn labels<I, L>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = L>,
+        L: Into<Cow<'a, str>>,
+    {
+        self.labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> Endpoint for EditIssueBoard<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/boards/{}", self.project, self.board).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("name", self.name.as_ref())
+            .push_opt("assignee_id", self.assignee_id)
+            .push_opt("milestone_id", self.milestone_id)
+            .push_opt("labels", self.labels.as_ref())
+            .push_opt("weight", self.weight)
+            .push_opt("hide_backlog_list", self.hide_backlog_list)
+            .push_opt("hide_closed_list", self.hide_closed_list);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::boards::{EditIssueBoard, EditIssueBoardBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_board_are_needed() {
+        let err = EditIssueBoard::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditIssueBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditIssueBoard::builder().board(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditIssueBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = EditIssueBoard::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditIssueBoardBuilderError, "board");
+    }
+
+    #[test]
+    fn project_and_board_are_sufficient() {
+        EditIssueBoard::builder()
+            .project(1)
+            .board(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditIssueBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=name")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditIssueBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .name("name")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_assignee_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("assignee_id=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditIssueBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .assignee_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_milestone_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("milestone_id=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditIssueBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .milestone_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_labels() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("labels=label%2Clabel1%2Clabel2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditIssueBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .label("label")
+            .labels(["label1", "label2"].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_weight() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("weight=3")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditIssueBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .weight(3)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_hide_backlog_list() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("hide_backlog_list=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditIssueBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .hide_backlog_list(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_hide_closed_list() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("hide_closed_list=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditIssueBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .hide_closed_list(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
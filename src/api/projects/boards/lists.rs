@@ -0,0 +1,35 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project issue board list API endpoints.
+//!
+//! These endpoints are used for querying and modifying the lists (columns) of an issue board.
+
+mod create;
+mod delete;
+mod edit;
+mod list;
+mod lists;
+
+pub use self::create::CreateIssueBoardList;
+pub use self::create::CreateIssueBoardListBuilder;
+pub use self::create::CreateIssueBoardListBuilderError;
+
+pub use self::delete::DeleteIssueBoardList;
+pub use self::delete::DeleteIssueBoardListBuilder;
+pub use self::delete::DeleteIssueBoardListBuilderError;
+
+pub use self::edit::EditIssueBoardList;
+pub use self::edit::EditIssueBoardListBuilder;
+pub use self::edit::EditIssueBoardListBuilderError;
+
+pub use self::list::IssueBoardList;
+pub use self::list::IssueBoardListBuilder;
+pub use self::list::IssueBoardListBuilderError;
+
+pub use self::lists::IssueBoardLists;
+pub use self::lists::IssueBoardListsBuilder;
+pub use self::lists::IssueBoardListsBuilderError;
@@ -0,0 +1,252 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Add a list to an issue board.
+///
+/// Exactly one of the label, assignee, milestone, or iteration must be given.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateIssueBoardList<'a> {
+    /// The project of the issue board.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the issue board.
+    board: u64,
+
+    /// The ID of a label to list issues for.
+    #[builder(default)]
+    label_id: Option<u64>,
+    /// The ID of a user to list assigned issues for.
+    #[builder(default)]
+    assignee_id: Option<u64>,
+    /// The ID of a milestone to list issues for.
+    #[builder(default)]
+    milestone_id: Option<u64>,
+    /// The ID of an iteration to list issues for.
+    #[builder(default)]
+    iteration_id: Option<u64>,
+}
+
+impl<'a> CreateIssueBoardList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateIssueBoardListBuilder<'a> {
+        CreateIssueBoardListBuilder::default()
+    }
+}
+
+impl<'a> CreateIssueBoardListBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateIssueBoardListBuilderError> {
+        let count = [
+            &self.label_id,
+            &self.assignee_id,
+            &self.milestone_id,
+            &self.iteration_id,
+        ]
+        .iter()
+        .filter(|id| matches!(id, Some(Some(_))))
+        .count();
+
+        if count != 1 {
+            return Err(CreateIssueBoardListBuilderError::ValidationError(
+                "exactly one of `label_id`, `assignee_id`, `milestone_id`, or `iteration_id` is \
+                 required"
+                    .into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateIssueBoardList<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/boards/{}/lists", self.project, self.board).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("label_id", self.label_id)
+            .push_opt("assignee_id", self.assignee_id)
+            .push_opt("milestone_id", self.milestone_id)
+            .push_opt("iteration_id", self.iteration_id);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::boards::lists::{
+        CreateIssueBoardList, CreateIssueBoardListBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_board_are_necessary() {
+        let err = CreateIssueBoardList::builder()
+            .label_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateIssueBoardListBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = CreateIssueBoardList::builder()
+            .board(1)
+            .label_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateIssueBoardListBuilderError, "project");
+    }
+
+    #[test]
+    fn board_is_necessary() {
+        let err = CreateIssueBoardList::builder()
+            .project(1)
+            .label_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateIssueBoardListBuilderError, "board");
+    }
+
+    #[test]
+    fn list_kind_is_necessary() {
+        let err = CreateIssueBoardList::builder()
+            .project(1)
+            .board(1)
+            .build()
+            .unwrap_err();
+        if let CreateIssueBoardListBuilderError::ValidationError(message) = err {
+            assert_eq!(
+                message,
+                "exactly one of `label_id`, `assignee_id`, `milestone_id`, or `iteration_id` is \
+                 required",
+            );
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn list_kinds_are_exclusive() {
+        let err = CreateIssueBoardList::builder()
+            .project(1)
+            .board(1)
+            .label_id(1)
+            .milestone_id(1)
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            CreateIssueBoardListBuilderError::ValidationError(_),
+        ));
+    }
+
+    #[test]
+    fn project_board_and_label_are_sufficient() {
+        CreateIssueBoardList::builder()
+            .project(1)
+            .board(1)
+            .label_id(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint_label_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/boards/1/lists")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("label_id=2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateIssueBoardList::builder()
+            .project("simple/project")
+            .board(1)
+            .label_id(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_assignee_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/boards/1/lists")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("assignee_id=2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateIssueBoardList::builder()
+            .project("simple/project")
+            .board(1)
+            .assignee_id(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_milestone_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/boards/1/lists")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("milestone_id=2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateIssueBoardList::builder()
+            .project("simple/project")
+            .board(1)
+            .milestone_id(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_iteration_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/boards/1/lists")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("iteration_id=2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateIssueBoardList::builder()
+            .project("simple/project")
+            .board(1)
+            .iteration_id(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
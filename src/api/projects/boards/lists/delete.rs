@@ -0,0 +1,118 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete a list from an issue board.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteIssueBoardList<'a> {
+    /// The project of the issue board.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the issue board.
+    board: u64,
+    /// The ID of the list.
+    list: u64,
+}
+
+impl<'a> DeleteIssueBoardList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteIssueBoardListBuilder<'a> {
+        DeleteIssueBoardListBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteIssueBoardList<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/boards/{}/lists/{}",
+            self.project, self.board, self.list,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::boards::lists::{
+        DeleteIssueBoardList, DeleteIssueBoardListBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_board_and_list_are_necessary() {
+        let err = DeleteIssueBoardList::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteIssueBoardListBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = DeleteIssueBoardList::builder()
+            .board(1)
+            .list(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteIssueBoardListBuilderError, "project");
+    }
+
+    #[test]
+    fn board_is_necessary() {
+        let err = DeleteIssueBoardList::builder()
+            .project(1)
+            .list(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteIssueBoardListBuilderError, "board");
+    }
+
+    #[test]
+    fn list_is_necessary() {
+        let err = DeleteIssueBoardList::builder()
+            .project(1)
+            .board(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteIssueBoardListBuilderError, "list");
+    }
+
+    #[test]
+    fn project_board_and_list_are_sufficient() {
+        DeleteIssueBoardList::builder()
+            .project(1)
+            .board(1)
+            .list(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/boards/1/lists/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteIssueBoardList::builder()
+            .project("simple/project")
+            .board(1)
+            .list(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,144 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Move a list of an issue board to a new position.
+#[derive(Debug, Builder, Clone)]
+pub struct EditIssueBoardList<'a> {
+    /// The project of the issue board.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the issue board.
+    board: u64,
+    /// The ID of the list.
+    list: u64,
+    /// The new position of the list.
+    position: u64,
+}
+
+impl<'a> EditIssueBoardList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditIssueBoardListBuilder<'a> {
+        EditIssueBoardListBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for EditIssueBoardList<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/boards/{}/lists/{}",
+            self.project, self.board, self.list,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("position", self.position);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::boards::lists::{EditIssueBoardList, EditIssueBoardListBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_board_list_and_position_are_necessary() {
+        let err = EditIssueBoardList::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditIssueBoardListBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = EditIssueBoardList::builder()
+            .board(1)
+            .list(1)
+            .position(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditIssueBoardListBuilderError, "project");
+    }
+
+    #[test]
+    fn board_is_necessary() {
+        let err = EditIssueBoardList::builder()
+            .project(1)
+            .list(1)
+            .position(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditIssueBoardListBuilderError, "board");
+    }
+
+    #[test]
+    fn list_is_necessary() {
+        let err = EditIssueBoardList::builder()
+            .project(1)
+            .board(1)
+            .position(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditIssueBoardListBuilderError, "list");
+    }
+
+    #[test]
+    fn position_is_necessary() {
+        let err = EditIssueBoardList::builder()
+            .project(1)
+            .board(1)
+            .list(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditIssueBoardListBuilderError, "position");
+    }
+
+    #[test]
+    fn project_board_list_and_position_are_sufficient() {
+        EditIssueBoardList::builder()
+            .project(1)
+            .board(1)
+            .list(1)
+            .position(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1/lists/2")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("position=3")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditIssueBoardList::builder()
+            .project("simple/project")
+            .board(1)
+            .list(2)
+            .position(3)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
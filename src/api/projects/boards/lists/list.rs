@@ -0,0 +1,114 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for a list of an issue board.
+#[derive(Debug, Builder, Clone)]
+pub struct IssueBoardList<'a> {
+    /// The project of the issue board.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the issue board.
+    board: u64,
+    /// The ID of the list.
+    list: u64,
+}
+
+impl<'a> IssueBoardList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> IssueBoardListBuilder<'a> {
+        IssueBoardListBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for IssueBoardList<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/boards/{}/lists/{}",
+            self.project, self.board, self.list,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::api::projects::boards::lists::{IssueBoardList, IssueBoardListBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_board_and_list_are_necessary() {
+        let err = IssueBoardList::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueBoardListBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = IssueBoardList::builder()
+            .board(1)
+            .list(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, IssueBoardListBuilderError, "project");
+    }
+
+    #[test]
+    fn board_is_necessary() {
+        let err = IssueBoardList::builder()
+            .project(1)
+            .list(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, IssueBoardListBuilderError, "board");
+    }
+
+    #[test]
+    fn list_is_necessary() {
+        let err = IssueBoardList::builder()
+            .project(1)
+            .board(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, IssueBoardListBuilderError, "list");
+    }
+
+    #[test]
+    fn project_board_and_list_are_sufficient() {
+        IssueBoardList::builder()
+            .project(1)
+            .board(1)
+            .list(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/boards/1/lists/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = IssueBoardList::builder()
+            .project("simple/project")
+            .board(1)
+            .list(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,89 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the lists of an issue board.
+///
+/// The "Open" and "Closed" lists are not included.
+#[derive(Debug, Builder, Clone)]
+pub struct IssueBoardLists<'a> {
+    /// The project of the issue board.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the issue board.
+    board: u64,
+}
+
+impl<'a> IssueBoardLists<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> IssueBoardListsBuilder<'a> {
+        IssueBoardListsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for IssueBoardLists<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/boards/{}/lists", self.project, self.board).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::boards::lists::{IssueBoardLists, IssueBoardListsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_board_are_needed() {
+        let err = IssueBoardLists::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueBoardListsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = IssueBoardLists::builder().board(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueBoardListsBuilderError, "project");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = IssueBoardLists::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueBoardListsBuilderError, "board");
+    }
+
+    #[test]
+    fn project_and_board_are_sufficient() {
+        IssueBoardLists::builder()
+            .project(1)
+            .board(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/boards/1/lists")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = IssueBoardLists::builder()
+            .project("simple/project")
+            .board(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
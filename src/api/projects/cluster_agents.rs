@@ -0,0 +1,32 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project cluster agent API endpoints.
+//!
+//! These endpoints are used for querying and managing the Kubernetes agents registered with a
+//! project.
+
+mod cluster_agent;
+mod cluster_agents;
+mod create;
+mod delete;
+pub mod tokens;
+
+pub use self::cluster_agent::ClusterAgent;
+pub use self::cluster_agent::ClusterAgentBuilder;
+pub use self::cluster_agent::ClusterAgentBuilderError;
+
+pub use self::cluster_agents::ClusterAgents;
+pub use self::cluster_agents::ClusterAgentsBuilder;
+pub use self::cluster_agents::ClusterAgentsBuilderError;
+
+pub use self::create::CreateClusterAgent;
+pub use self::create::CreateClusterAgentBuilder;
+pub use self::create::CreateClusterAgentBuilderError;
+
+pub use self::delete::DeleteClusterAgent;
+pub use self::delete::DeleteClusterAgentBuilder;
+pub use self::delete::DeleteClusterAgentBuilderError;
@@ -0,0 +1,83 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for a Kubernetes agent registered with a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ClusterAgent<'a> {
+    /// The project of the agent.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the agent.
+    agent: u64,
+}
+
+impl<'a> ClusterAgent<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ClusterAgentBuilder<'a> {
+        ClusterAgentBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ClusterAgent<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/cluster_agents/{}", self.project, self.agent).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::cluster_agents::{ClusterAgent, ClusterAgentBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_agent_are_needed() {
+        let err = ClusterAgent::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ClusterAgentBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ClusterAgent::builder().agent(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, ClusterAgentBuilderError, "project");
+    }
+
+    #[test]
+    fn agent_is_needed() {
+        let err = ClusterAgent::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, ClusterAgentBuilderError, "agent");
+    }
+
+    #[test]
+    fn project_and_agent_are_sufficient() {
+        ClusterAgent::builder().project(1).agent(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/cluster_agents/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ClusterAgent::builder()
+            .project("simple/project")
+            .agent(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,70 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for Kubernetes agents registered with a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ClusterAgents<'a> {
+    /// The project to query for agents.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ClusterAgents<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ClusterAgentsBuilder<'a> {
+        ClusterAgentsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ClusterAgents<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/cluster_agents", self.project).into()
+    }
+}
+
+impl<'a> Pageable for ClusterAgents<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::cluster_agents::{ClusterAgents, ClusterAgentsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ClusterAgents::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ClusterAgentsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ClusterAgents::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/cluster_agents")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ClusterAgents::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
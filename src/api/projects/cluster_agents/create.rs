@@ -0,0 +1,109 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Register a Kubernetes agent with a project.
+#[derive(Debug, Builder, Clone)]
+pub struct CreateClusterAgent<'a> {
+    /// The project to register the agent with.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the agent.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl<'a> CreateClusterAgent<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateClusterAgentBuilder<'a> {
+        CreateClusterAgentBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateClusterAgent<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/cluster_agents", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("name", self.name.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::cluster_agents::{
+        CreateClusterAgent, CreateClusterAgentBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_name_are_needed() {
+        let err = CreateClusterAgent::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateClusterAgentBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateClusterAgent::builder()
+            .name("agent-1")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateClusterAgentBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = CreateClusterAgent::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateClusterAgentBuilderError, "name");
+    }
+
+    #[test]
+    fn project_and_name_are_sufficient() {
+        CreateClusterAgent::builder()
+            .project(1)
+            .name("agent-1")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/cluster_agents")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=agent-1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateClusterAgent::builder()
+            .project("simple/project")
+            .name("agent-1")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
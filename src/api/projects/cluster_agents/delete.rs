@@ -0,0 +1,95 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete a Kubernetes agent registered with a project.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteClusterAgent<'a> {
+    /// The project of the agent.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the agent.
+    agent: u64,
+}
+
+impl<'a> DeleteClusterAgent<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteClusterAgentBuilder<'a> {
+        DeleteClusterAgentBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteClusterAgent<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/cluster_agents/{}", self.project, self.agent).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::cluster_agents::{
+        DeleteClusterAgent, DeleteClusterAgentBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_agent_are_needed() {
+        let err = DeleteClusterAgent::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteClusterAgentBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteClusterAgent::builder().agent(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteClusterAgentBuilderError, "project");
+    }
+
+    #[test]
+    fn agent_is_needed() {
+        let err = DeleteClusterAgent::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteClusterAgentBuilderError, "agent");
+    }
+
+    #[test]
+    fn project_and_agent_are_sufficient() {
+        DeleteClusterAgent::builder()
+            .project(1)
+            .agent(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/cluster_agents/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteClusterAgent::builder()
+            .project("simple/project")
+            .agent(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,30 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project cluster agent token API endpoints.
+//!
+//! These endpoints are used for querying and managing the tokens of a Kubernetes agent.
+
+mod create;
+mod revoke;
+mod token;
+mod tokens;
+
+pub use self::create::CreateClusterAgentToken;
+pub use self::create::CreateClusterAgentTokenBuilder;
+pub use self::create::CreateClusterAgentTokenBuilderError;
+
+pub use self::revoke::RevokeClusterAgentToken;
+pub use self::revoke::RevokeClusterAgentTokenBuilder;
+pub use self::revoke::RevokeClusterAgentTokenBuilderError;
+
+pub use self::token::ClusterAgentToken;
+pub use self::token::ClusterAgentTokenBuilder;
+pub use self::token::ClusterAgentTokenBuilderError;
+
+pub use self::tokens::ClusterAgentTokens;
+pub use self::tokens::ClusterAgentTokensBuilder;
+pub use self::tokens::ClusterAgentTokensBuilderError;
@@ -0,0 +1,159 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Create a token for a Kubernetes agent.
+///
+/// The token secret is only available in the response to this endpoint.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreateClusterAgentToken<'a> {
+    /// The project of the agent.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the agent.
+    agent: u64,
+    /// The name of the token.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The description of the token.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreateClusterAgentToken<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateClusterAgentTokenBuilder<'a> {
+        CreateClusterAgentTokenBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateClusterAgentToken<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/cluster_agents/{}/tokens",
+            self.project, self.agent,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("name", self.name.as_ref())
+            .push_opt("description", self.description.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::cluster_agents::tokens::{
+        CreateClusterAgentToken, CreateClusterAgentTokenBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_agent_and_name_are_needed() {
+        let err = CreateClusterAgentToken::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateClusterAgentTokenBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateClusterAgentToken::builder()
+            .agent(1)
+            .name("token")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateClusterAgentTokenBuilderError, "project");
+    }
+
+    #[test]
+    fn agent_is_needed() {
+        let err = CreateClusterAgentToken::builder()
+            .project(1)
+            .name("token")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateClusterAgentTokenBuilderError, "agent");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = CreateClusterAgentToken::builder()
+            .project(1)
+            .agent(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateClusterAgentTokenBuilderError, "name");
+    }
+
+    #[test]
+    fn project_agent_and_name_are_sufficient() {
+        CreateClusterAgentToken::builder()
+            .project(1)
+            .agent(1)
+            .name("token")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/cluster_agents/1/tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=token")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateClusterAgentToken::builder()
+            .project("simple/project")
+            .agent(1)
+            .name("token")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/cluster_agents/1/tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=token&description=description")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateClusterAgentToken::builder()
+            .project("simple/project")
+            .agent(1)
+            .name("token")
+            .description("description")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,115 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for a token of a Kubernetes agent.
+#[derive(Debug, Builder, Clone)]
+pub struct ClusterAgentToken<'a> {
+    /// The project of the agent.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the agent.
+    agent: u64,
+    /// The ID of the token.
+    token: u64,
+}
+
+impl<'a> ClusterAgentToken<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ClusterAgentTokenBuilder<'a> {
+        ClusterAgentTokenBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ClusterAgentToken<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/cluster_agents/{}/tokens/{}",
+            self.project, self.agent, self.token,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::cluster_agents::tokens::{
+        ClusterAgentToken, ClusterAgentTokenBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_agent_and_token_are_needed() {
+        let err = ClusterAgentToken::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ClusterAgentTokenBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ClusterAgentToken::builder()
+            .agent(1)
+            .token(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ClusterAgentTokenBuilderError, "project");
+    }
+
+    #[test]
+    fn agent_is_needed() {
+        let err = ClusterAgentToken::builder()
+            .project(1)
+            .token(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ClusterAgentTokenBuilderError, "agent");
+    }
+
+    #[test]
+    fn token_is_needed() {
+        let err = ClusterAgentToken::builder()
+            .project(1)
+            .agent(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ClusterAgentTokenBuilderError, "token");
+    }
+
+    #[test]
+    fn project_agent_and_token_are_sufficient() {
+        ClusterAgentToken::builder()
+            .project(1)
+            .agent(1)
+            .token(2)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/cluster_agents/1/tokens/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ClusterAgentToken::builder()
+            .project("simple/project")
+            .agent(1)
+            .token(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
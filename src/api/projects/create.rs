@@ -501,7 +501,7 @@ impl<'a> ProjectName<'a> {
 
 /// Create a new project on an instance.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateProject<'a> {
     /// The name and/or path of the project.
     #[builder(private)]
@@ -664,15 +664,14 @@ pub struct CreateProject<'a> {
     /// Whether users may request access to the repository or not.
     #[builder(default)]
     request_access_enabled: Option<bool>,
-    /// A list of tags to apply to the repository.
-    #[builder(setter(name = "_tag_list"), default, private)]
-    tag_list: BTreeSet<Cow<'a, str>>,
     /// A list of topics to apply to the repository.
     #[builder(setter(name = "_topics"), default, private)]
     topics: BTreeSet<Cow<'a, str>>,
-    // TODO: Figure out how to actually use this.
-    // avatar   mixed   no  Image file for avatar of the project
-    // avatar: ???,
+    /// An image file to use as the avatar of the project.
+    ///
+    /// When set, the request is sent as `multipart/form-data`.
+    #[builder(setter(name = "_avatar"), default, private)]
+    avatar: Option<(Cow<'a, str>, Cow<'a, [u8]>)>,
     /// Whether to show a link to create or view a merge request when pushing a branch from the
     /// command line or not.
     #[builder(default)]
@@ -713,6 +712,8 @@ pub struct CreateProject<'a> {
     #[builder(setter(into), default)]
     external_authorization_classification_label: Option<Cow<'a, str>>,
     /// Whether to enable pull mirroring for the project or not.
+    ///
+    /// Requires `import_url`.
     #[builder(default)]
     mirror: Option<bool>,
     /// Whether mirror updates trigger CI builds ir not.
@@ -724,12 +725,18 @@ pub struct CreateProject<'a> {
     #[builder(default)]
     initialize_with_readme: Option<bool>,
     /// The name of a template project to use.
+    ///
+    /// Mutually exclusive with `template_project_id`.
     #[builder(setter(into), default)]
     template_name: Option<Cow<'a, str>>,
     /// The ID of the template project to use.
+    ///
+    /// Mutually exclusive with `template_name`.
     #[builder(default)]
     template_project_id: Option<u64>,
     /// Whether to use a custom instance or group template.
+    ///
+    /// Requires either `template_name` or `template_project_id`.
     #[builder(default)]
     use_custom_template: Option<bool>,
     /// Whether the template project should come from the group or the instance.
@@ -810,31 +817,29 @@ impl<'a> CreateProjectBuilder<'a> {
     }
 
     /// Add a tag.
+    ///
+    /// GitLab now calls these topics; this is an alias for `topic`.
     #[deprecated(note = "use `topic` instead")]
     pub fn tag<T>(&mut self, tag: T) -> &mut Self
     where
         T: Into<Cow<'a, str>>,
     {
-        self.tag_list
-            .get_or_insert_with(BTreeSet::new)
-            .insert(tag.into());
-        self
+        self.topic(tag)
     }
 
     /// Add multiple tags.
+    ///
+    /// GitLab now calls these topics; this is an alias for `topics`.
     #[deprecated(note = "use `topics` instead")]
     pub fn tags<I, T>(&mut self, iter: I) -> &mut Self
     where
         I: Iterator<Item = T>,
         T: Into<Cow<'a, str>>,
     {
-        self.tag_list
-            .get_or_insert_with(BTreeSet::new)
-            .extend(iter.map(Into::into));
-        self
+        self.topics(iter)
     }
 
-    /// Add a optic.
+    /// Add a topic.
     pub fn topic<T>(&mut self, topic: T) -> &mut Self
     where
         T: Into<Cow<'a, str>>,
@@ -857,6 +862,16 @@ impl<'a> CreateProjectBuilder<'a> {
         self
     }
 
+    /// Upload an image file to use as the avatar of the project.
+    pub fn avatar<F, C>(&mut self, filename: F, contents: C) -> &mut Self
+    where
+        F: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, [u8]>>,
+    {
+        self.avatar = Some(Some((filename.into(), contents.into())));
+        self
+    }
+
     /// Whether the template project should come from the group or the instance.
     ///
     /// Note that setting this also sets `use_custom_template` to `true` automatically.
@@ -865,6 +880,35 @@ impl<'a> CreateProjectBuilder<'a> {
         self.use_custom_template(true);
         self
     }
+
+    fn validate(&self) -> Result<(), CreateProjectBuilderError> {
+        let has_template_name = matches!(self.template_name, Some(Some(_)));
+        let has_template_project_id = matches!(self.template_project_id, Some(Some(_)));
+
+        if has_template_name && has_template_project_id {
+            return Err(CreateProjectBuilderError::ValidationError(
+                "`template_name` and `template_project_id` are mutually exclusive".into(),
+            ));
+        }
+
+        if matches!(self.use_custom_template, Some(Some(true)))
+            && !has_template_name
+            && !has_template_project_id
+        {
+            return Err(CreateProjectBuilderError::ValidationError(
+                "`use_custom_template` requires either `template_name` or `template_project_id`"
+                    .into(),
+            ));
+        }
+
+        if matches!(self.mirror, Some(Some(true))) && !matches!(self.import_url, Some(Some(_))) {
+            return Err(CreateProjectBuilderError::ValidationError(
+                "`mirror` requires `import_url`".into(),
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for CreateProject<'a> {
@@ -984,7 +1028,6 @@ impl<'a> Endpoint for CreateProject<'a> {
             )
             .push_opt("lfs_enabled", self.lfs_enabled)
             .push_opt("request_access_enabled", self.request_access_enabled)
-            .extend(self.tag_list.iter().map(|value| ("tag_list[]", value)))
             .extend(self.topics.iter().map(|value| ("topics[]", value)))
             .push_opt(
                 "printing_merge_request_link_enabled",
@@ -1042,6 +1085,12 @@ impl<'a> Endpoint for CreateProject<'a> {
                 .push_opt("approvals_before_merge", self.approvals_before_merge);
         }
 
+        if let Some((filename, contents)) = self.avatar.as_ref() {
+            let mut params = MultipartParams::from(params);
+            params.push_file("avatar", filename.as_ref(), contents.as_ref());
+            return params.into_body();
+        }
+
         params.into_body()
     }
 }
@@ -1256,6 +1305,82 @@ mod tests {
         CreateProject::builder().path("path").build().unwrap();
     }
 
+    #[test]
+    fn template_name_and_template_project_id_are_exclusive() {
+        let err = CreateProject::builder()
+            .name("name")
+            .template_name("template")
+            .template_project_id(1)
+            .build()
+            .unwrap_err();
+        if let CreateProjectBuilderError::ValidationError(message) = err {
+            assert_eq!(
+                message,
+                "`template_name` and `template_project_id` are mutually exclusive",
+            );
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn use_custom_template_needs_a_template() {
+        let err = CreateProject::builder()
+            .name("name")
+            .use_custom_template(true)
+            .build()
+            .unwrap_err();
+        if let CreateProjectBuilderError::ValidationError(message) = err {
+            assert_eq!(
+                message,
+                "`use_custom_template` requires either `template_name` or `template_project_id`",
+            );
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn group_with_project_templates_id_needs_a_template() {
+        let err = CreateProject::builder()
+            .name("name")
+            .group_with_project_templates_id(1)
+            .build()
+            .unwrap_err();
+        if let CreateProjectBuilderError::ValidationError(message) = err {
+            assert_eq!(
+                message,
+                "`use_custom_template` requires either `template_name` or `template_project_id`",
+            );
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn use_custom_template_with_template_project_id_is_sufficient() {
+        CreateProject::builder()
+            .name("name")
+            .use_custom_template(true)
+            .template_project_id(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn mirror_needs_import_url() {
+        let err = CreateProject::builder()
+            .name("name")
+            .mirror(true)
+            .build()
+            .unwrap_err();
+        if let CreateProjectBuilderError::ValidationError(message) = err {
+            assert_eq!(message, "`mirror` requires `import_url`");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
     #[test]
     fn endpoint_name() {
         let endpoint = ExpectedUrl::builder()
@@ -2460,15 +2585,16 @@ mod tests {
 
     #[test]
     #[allow(deprecated)]
-    fn endpoint_tag_list() {
+    fn endpoint_tags_are_topics() {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects")
             .content_type("application/x-www-form-urlencoded")
             .body_str(concat!(
                 "name=name",
-                "&tag_list%5B%5D=tag1",
-                "&tag_list%5B%5D=tag2",
+                "&topics%5B%5D=tag1",
+                "&topics%5B%5D=tag2",
+                "&topics%5B%5D=topic1",
             ))
             .build()
             .unwrap();
@@ -2478,6 +2604,7 @@ mod tests {
             .name("name")
             .tag("tag1")
             .tags(["tag1", "tag2"].iter().copied())
+            .topic("topic1")
             .build()
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
@@ -2733,13 +2860,18 @@ mod tests {
             .method(Method::POST)
             .endpoint("projects")
             .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!("name=name", "&mirror=true"))
+            .body_str(concat!(
+                "name=name",
+                "&import_url=https%3A%2F%2Ftest.invalid%2Fpath%3Fsome%3Dfoo",
+                "&mirror=true",
+            ))
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
 
         let endpoint = CreateProject::builder()
             .name("name")
+            .import_url("https://test.invalid/path?some=foo")
             .mirror(true)
             .build()
             .unwrap();
@@ -2849,6 +2981,7 @@ mod tests {
             .content_type("application/x-www-form-urlencoded")
             .body_str(concat!(
                 "name=name",
+                "&template_name=template",
                 "&use_custom_template=true",
                 "&group_with_project_templates_id=1",
             ))
@@ -2858,6 +2991,7 @@ mod tests {
 
         let endpoint = CreateProject::builder()
             .name("name")
+            .template_name("template")
             .group_with_project_templates_id(1)
             .build()
             .unwrap();
@@ -3001,4 +3135,34 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_avatar() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects")
+            .content_type("multipart/form-data; boundary=gitlab-rs-multipart-7a3c9e1f5b2d4086")
+            .body_str(concat!(
+                "--gitlab-rs-multipart-7a3c9e1f5b2d4086\r\n",
+                "Content-Disposition: form-data; name=\"name\"\r\n",
+                "\r\n",
+                "name\r\n",
+                "--gitlab-rs-multipart-7a3c9e1f5b2d4086\r\n",
+                "Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "avatar data\r\n",
+                "--gitlab-rs-multipart-7a3c9e1f5b2d4086--\r\n",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProject::builder()
+            .name("name")
+            .avatar("avatar.png", &b"avatar data"[..])
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
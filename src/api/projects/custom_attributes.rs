@@ -0,0 +1,31 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project custom attribute API endpoints.
+//!
+//! These endpoints are used for querying and modifying custom attributes on projects. Custom
+//! attributes are key/value pairs which may only be managed by administrators.
+
+mod custom_attribute;
+mod custom_attributes;
+mod delete;
+mod set;
+
+pub use self::custom_attribute::ProjectCustomAttribute;
+pub use self::custom_attribute::ProjectCustomAttributeBuilder;
+pub use self::custom_attribute::ProjectCustomAttributeBuilderError;
+
+pub use self::custom_attributes::ProjectCustomAttributes;
+pub use self::custom_attributes::ProjectCustomAttributesBuilder;
+pub use self::custom_attributes::ProjectCustomAttributesBuilderError;
+
+pub use self::delete::DeleteProjectCustomAttribute;
+pub use self::delete::DeleteProjectCustomAttributeBuilder;
+pub use self::delete::DeleteProjectCustomAttributeBuilderError;
+
+pub use self::set::SetProjectCustomAttribute;
+pub use self::set::SetProjectCustomAttributeBuilder;
+pub use self::set::SetProjectCustomAttributeBuilderError;
@@ -0,0 +1,117 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a custom attribute on a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ProjectCustomAttribute<'a> {
+    /// The project to query for the custom attribute.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The key of the custom attribute.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+}
+
+impl<'a> ProjectCustomAttribute<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectCustomAttributeBuilder<'a> {
+        ProjectCustomAttributeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectCustomAttribute<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/custom_attributes/{}",
+            self.project,
+            common::path_escaped(&self.key),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::custom_attributes::{
+        ProjectCustomAttribute, ProjectCustomAttributeBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_key_are_necessary() {
+        let err = ProjectCustomAttribute::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectCustomAttributeBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = ProjectCustomAttribute::builder()
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectCustomAttributeBuilderError, "project");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = ProjectCustomAttribute::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectCustomAttributeBuilderError, "key");
+    }
+
+    #[test]
+    fn project_and_key_are_sufficient() {
+        ProjectCustomAttribute::builder()
+            .project(1)
+            .key("key")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/custom_attributes/key")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectCustomAttribute::builder()
+            .project("simple/project")
+            .key("key")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_key_escaped() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/custom_attributes/external%2Fid")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectCustomAttribute::builder()
+            .project("simple/project")
+            .key("external/id")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,73 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for custom attributes on a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ProjectCustomAttributes<'a> {
+    /// The project to query for custom attributes.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ProjectCustomAttributes<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectCustomAttributesBuilder<'a> {
+        ProjectCustomAttributesBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectCustomAttributes<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/custom_attributes", self.project).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::custom_attributes::{
+        ProjectCustomAttributes, ProjectCustomAttributesBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectCustomAttributes::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectCustomAttributesBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectCustomAttributes::builder()
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/custom_attributes")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectCustomAttributes::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
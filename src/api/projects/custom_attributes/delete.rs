@@ -0,0 +1,129 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a custom attribute from a project.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteProjectCustomAttribute<'a> {
+    /// The project to delete the custom attribute from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The key of the custom attribute.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+}
+
+impl<'a> DeleteProjectCustomAttribute<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteProjectCustomAttributeBuilder<'a> {
+        DeleteProjectCustomAttributeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteProjectCustomAttribute<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/custom_attributes/{}",
+            self.project,
+            common::path_escaped(&self.key),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::custom_attributes::{
+        DeleteProjectCustomAttribute, DeleteProjectCustomAttributeBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_key_are_necessary() {
+        let err = DeleteProjectCustomAttribute::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            DeleteProjectCustomAttributeBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = DeleteProjectCustomAttribute::builder()
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            DeleteProjectCustomAttributeBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = DeleteProjectCustomAttribute::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectCustomAttributeBuilderError, "key");
+    }
+
+    #[test]
+    fn project_and_key_are_sufficient() {
+        DeleteProjectCustomAttribute::builder()
+            .project(1)
+            .key("key")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/custom_attributes/key")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteProjectCustomAttribute::builder()
+            .project("simple/project")
+            .key("key")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_key_escaped() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/custom_attributes/external%2Fid")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteProjectCustomAttribute::builder()
+            .project("simple/project")
+            .key("external/id")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,219 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Package managers which may provide dependencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum DependencyPackageManager {
+    /// Ruby gems via Bundler.
+    Bundler,
+    /// PHP packages via Composer.
+    Composer,
+    /// C and C++ packages via Conan.
+    Conan,
+    /// Go modules.
+    Go,
+    /// Java packages via Gradle.
+    Gradle,
+    /// Java packages via Maven.
+    Maven,
+    /// JavaScript packages via npm.
+    Npm,
+    /// .NET packages via NuGet.
+    Nuget,
+    /// Python packages via pip.
+    Pip,
+    /// Python packages via Pipenv.
+    Pipenv,
+    /// JavaScript packages via pnpm.
+    Pnpm,
+    /// JavaScript packages via Yarn.
+    Yarn,
+    /// Scala packages via sbt.
+    Sbt,
+    /// Python packages via setuptools.
+    Setuptools,
+}
+
+impl DependencyPackageManager {
+    /// The package manager as a query parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            DependencyPackageManager::Bundler => "bundler",
+            DependencyPackageManager::Composer => "composer",
+            DependencyPackageManager::Conan => "conan",
+            DependencyPackageManager::Go => "go",
+            DependencyPackageManager::Gradle => "gradle",
+            DependencyPackageManager::Maven => "maven",
+            DependencyPackageManager::Npm => "npm",
+            DependencyPackageManager::Nuget => "nuget",
+            DependencyPackageManager::Pip => "pip",
+            DependencyPackageManager::Pipenv => "pipenv",
+            DependencyPackageManager::Pnpm => "pnpm",
+            DependencyPackageManager::Yarn => "yarn",
+            DependencyPackageManager::Sbt => "sbt",
+            DependencyPackageManager::Setuptools => "setuptools",
+        }
+    }
+}
+
+impl ParamValue<'static> for DependencyPackageManager {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for the dependencies of a project.
+///
+/// Dependencies are gathered from the latest successful pipeline on the default branch which ran
+/// dependency scanning.
+#[derive(Debug, Builder, Clone)]
+pub struct ProjectDependencies<'a> {
+    /// The project to query for dependencies.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Filter dependencies by package manager.
+    #[builder(setter(name = "_package_managers"), default, private)]
+    package_managers: BTreeSet<DependencyPackageManager>,
+}
+
+impl<'a> ProjectDependencies<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectDependenciesBuilder<'a> {
+        ProjectDependenciesBuilder::default()
+    }
+}
+
+impl<'a> ProjectDependenciesBuilder<'a> {
+    /// Filter dependencies by a package manager.
+    pub fn package_manager(&mut self, package_manager: DependencyPackageManager) -> &mut Self {
+        self.package_managers
+            .get_or_insert_with(BTreeSet::new)
+            .insert(package_manager);
+        self
+    }
+
+    /// Filter dependencies by a set of package managers.
+    pub fn package_managers<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = DependencyPackageManager>,
+    {
+        self.package_managers
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter);
+        self
+    }
+}
+
+impl<'a> Endpoint for ProjectDependencies<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/dependencies", self.project).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.extend(
+            self.package_managers
+                .iter()
+                .map(|&value| ("package_manager[]", value)),
+        );
+
+        params
+    }
+}
+
+impl<'a> Pageable for ProjectDependencies<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::{
+        DependencyPackageManager, ProjectDependencies, ProjectDependenciesBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn dependency_package_manager_as_str() {
+        let items = &[
+            (DependencyPackageManager::Bundler, "bundler"),
+            (DependencyPackageManager::Composer, "composer"),
+            (DependencyPackageManager::Conan, "conan"),
+            (DependencyPackageManager::Go, "go"),
+            (DependencyPackageManager::Gradle, "gradle"),
+            (DependencyPackageManager::Maven, "maven"),
+            (DependencyPackageManager::Npm, "npm"),
+            (DependencyPackageManager::Nuget, "nuget"),
+            (DependencyPackageManager::Pip, "pip"),
+            (DependencyPackageManager::Pipenv, "pipenv"),
+            (DependencyPackageManager::Pnpm, "pnpm"),
+            (DependencyPackageManager::Yarn, "yarn"),
+            (DependencyPackageManager::Sbt, "sbt"),
+            (DependencyPackageManager::Setuptools, "setuptools"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectDependencies::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectDependenciesBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectDependencies::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/dependencies")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectDependencies::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_package_managers() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/dependencies")
+            .add_query_params(&[("package_manager[]", "npm"), ("package_manager[]", "yarn")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectDependencies::builder()
+            .project("simple/project")
+            .package_manager(DependencyPackageManager::Yarn)
+            .package_managers([DependencyPackageManager::Npm].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -15,6 +15,13 @@ use crate::api::projects::{
     FeatureAccessLevelPublic, MergeMethod, SquashOption,
 };
 
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+enum ProjectAvatarChange<'a> {
+    Upload(Cow<'a, str>, Cow<'a, [u8]>),
+    Remove,
+}
+
 /// Edit an existing project.
 #[derive(Debug, Builder, Clone)]
 #[builder(setter(strip_option))]
@@ -197,16 +204,14 @@ pub struct EditProject<'a> {
     /// Whether users may request access to the repository or not.
     #[builder(default)]
     request_access_enabled: Option<bool>,
-    /// A list of tags to apply to the repository.
-    #[builder(setter(name = "_tag_list"), default, private)]
-    tag_list: BTreeSet<Cow<'a, str>>,
     /// A list of topics to apply to the repository.
     #[builder(setter(name = "_topics"), default, private)]
     topics: BTreeSet<Cow<'a, str>>,
-    // TODO: Figure out how to actually use this.
-    // avatar   mixed   no  Image file for avatar of the project
-    // empty string is "delete avatar"
-    // avatar: ???,
+    /// An image file to use as the avatar of the project.
+    ///
+    /// When uploading, the request is sent as `multipart/form-data`.
+    #[builder(setter(name = "_avatar"), default, private)]
+    avatar: Option<ProjectAvatarChange<'a>>,
     /// The default Git strategy for CI jobs of the project.
     #[builder(default)]
     build_git_strategy: Option<BuildGitStrategy>,
@@ -328,28 +333,26 @@ impl<'a> EditProject<'a> {
 
 impl<'a> EditProjectBuilder<'a> {
     /// Add a tag.
+    ///
+    /// GitLab now calls these topics; this is an alias for `topic`.
     #[deprecated(note = "use `topic` instead")]
     pub fn tag<T>(&mut self, tag: T) -> &mut Self
     where
         T: Into<Cow<'a, str>>,
     {
-        self.tag_list
-            .get_or_insert_with(BTreeSet::new)
-            .insert(tag.into());
-        self
+        self.topic(tag)
     }
 
     /// Add multiple tags.
+    ///
+    /// GitLab now calls these topics; this is an alias for `topics`.
     #[deprecated(note = "use `topics` instead")]
     pub fn tags<I, T>(&mut self, iter: I) -> &mut Self
     where
         I: Iterator<Item = T>,
         T: Into<Cow<'a, str>>,
     {
-        self.tag_list
-            .get_or_insert_with(BTreeSet::new)
-            .extend(iter.map(Into::into));
-        self
+        self.topics(iter)
     }
 
     /// Add a topic.
@@ -375,6 +378,25 @@ impl<'a> EditProjectBuilder<'a> {
         self
     }
 
+    /// Upload an image file to use as the avatar of the project.
+    pub fn avatar<F, C>(&mut self, filename: F, contents: C) -> &mut Self
+    where
+        F: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, [u8]>>,
+    {
+        self.avatar = Some(Some(ProjectAvatarChange::Upload(
+            filename.into(),
+            contents.into(),
+        )));
+        self
+    }
+
+    /// Remove the avatar of the project.
+    pub fn remove_avatar(&mut self) -> &mut Self {
+        self.avatar = Some(Some(ProjectAvatarChange::Remove));
+        self
+    }
+
     /// Whether to enable print merge request links if branch/commits are pushed by console
     ///
     /// This was a typo previously; use `printing_merge_request_link_enabled` instead.
@@ -504,7 +526,6 @@ impl<'a> Endpoint for EditProject<'a> {
             )
             .push_opt("lfs_enabled", self.lfs_enabled)
             .push_opt("request_access_enabled", self.request_access_enabled)
-            .extend(self.tag_list.iter().map(|value| ("tag_list[]", value)))
             .extend(self.topics.iter().map(|value| ("topics[]", value)))
             .push_opt("build_git_strategy", self.build_git_strategy)
             .push_opt("build_timeout", self.build_timeout)
@@ -585,6 +606,19 @@ impl<'a> Endpoint for EditProject<'a> {
                 .push_opt("approvals_before_merge", self.approvals_before_merge);
         }
 
+        match self.avatar.as_ref() {
+            Some(ProjectAvatarChange::Upload(filename, contents)) => {
+                let mut params = MultipartParams::from(params);
+                params.push_file("avatar", filename.as_ref(), contents.as_ref());
+                return params.into_body();
+            },
+            Some(ProjectAvatarChange::Remove) => {
+                // An empty value deletes the avatar.
+                params.push("avatar", "");
+            },
+            None => (),
+        }
+
         params.into_body()
     }
 }
@@ -1586,6 +1620,26 @@ mod tests {
         api::ignore(endpoint).query(&client).unwrap();
     }
 
+    #[test]
+    fn endpoint_default_branch_and_merge_method() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("default_branch=main", "&merge_method=ff"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProject::builder()
+            .project("simple/project")
+            .default_branch("main")
+            .merge_method(MergeMethod::FastForward)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
     #[test]
     fn endpoint_squash_option() {
         let endpoint = ExpectedUrl::builder()
@@ -1797,12 +1851,16 @@ mod tests {
 
     #[test]
     #[allow(deprecated)]
-    fn endpoint_tag_list() {
+    fn endpoint_tags_are_topics() {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject")
             .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!("tag_list%5B%5D=tag1", "&tag_list%5B%5D=tag2"))
+            .body_str(concat!(
+                "topics%5B%5D=tag1",
+                "&topics%5B%5D=tag2",
+                "&topics%5B%5D=topic1",
+            ))
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -1811,6 +1869,7 @@ mod tests {
             .project("simple/project")
             .tag("tag1")
             .tags(["tag1", "tag2"].iter().copied())
+            .topic("topic1")
             .build()
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
@@ -2450,4 +2509,49 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_avatar() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject")
+            .content_type("multipart/form-data; boundary=gitlab-rs-multipart-7a3c9e1f5b2d4086")
+            .body_str(concat!(
+                "--gitlab-rs-multipart-7a3c9e1f5b2d4086\r\n",
+                "Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "avatar data\r\n",
+                "--gitlab-rs-multipart-7a3c9e1f5b2d4086--\r\n",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProject::builder()
+            .project("simple/project")
+            .avatar("avatar.png", &b"avatar data"[..])
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_remove_avatar() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("avatar=")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProject::builder()
+            .project("simple/project")
+            .remove_avatar()
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
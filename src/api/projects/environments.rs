@@ -6,13 +6,19 @@
 
 //! Project environments API endpoints.
 //!
-//! These endpoints are used for querying environments.
+//! These endpoints are used for querying and managing environments.
 
+mod delete;
 mod environment;
 mod environments;
+mod stop;
 
 pub use self::environments::EnvironmentState;
 
+pub use self::delete::DeleteEnvironment;
+pub use self::delete::DeleteEnvironmentBuilder;
+pub use self::delete::DeleteEnvironmentBuilderError;
+
 pub use self::environment::Environment;
 pub use self::environment::EnvironmentBuilder;
 pub use self::environment::EnvironmentBuilderError;
@@ -20,3 +26,7 @@ pub use self::environment::EnvironmentBuilderError;
 pub use self::environments::Environments;
 pub use self::environments::EnvironmentsBuilder;
 pub use self::environments::EnvironmentsBuilderError;
+
+pub use self::stop::StopEnvironment;
+pub use self::stop::StopEnvironmentBuilder;
+pub use self::stop::StopEnvironmentBuilderError;
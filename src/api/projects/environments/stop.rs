@@ -0,0 +1,134 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Stop an environment within a project.
+///
+/// This runs the `on_stop` action of the environment's last deployment, if any.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct StopEnvironment<'a> {
+    /// The project of the environment.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the environment.
+    environment: u64,
+
+    /// Stop the environment without running its `on_stop` action.
+    #[builder(default)]
+    force: Option<bool>,
+}
+
+impl<'a> StopEnvironment<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> StopEnvironmentBuilder<'a> {
+        StopEnvironmentBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for StopEnvironment<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/environments/{}/stop",
+            self.project, self.environment,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push_opt("force", self.force);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::environments::{StopEnvironment, StopEnvironmentBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_environment_are_needed() {
+        let err = StopEnvironment::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, StopEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = StopEnvironment::builder()
+            .environment(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, StopEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn environment_is_needed() {
+        let err = StopEnvironment::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, StopEnvironmentBuilderError, "environment");
+    }
+
+    #[test]
+    fn project_and_environment_are_sufficient() {
+        StopEnvironment::builder()
+            .project(1)
+            .environment(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/environments/1/stop")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = StopEnvironment::builder()
+            .project("simple/project")
+            .environment(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_force() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/environments/1/stop")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("force=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = StopEnvironment::builder()
+            .project("simple/project")
+            .environment(1)
+            .force(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,21 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project error tracking API endpoints.
+//!
+//! These endpoints are used for querying and modifying the error tracking settings of a project.
+//! Errors themselves are only available through GitLab's GraphQL API.
+
+mod edit_settings;
+mod settings;
+
+pub use self::edit_settings::EditErrorTrackingSettings;
+pub use self::edit_settings::EditErrorTrackingSettingsBuilder;
+pub use self::edit_settings::EditErrorTrackingSettingsBuilderError;
+
+pub use self::settings::ErrorTrackingSettings;
+pub use self::settings::ErrorTrackingSettingsBuilder;
+pub use self::settings::ErrorTrackingSettingsBuilderError;
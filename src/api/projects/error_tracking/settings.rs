@@ -0,0 +1,70 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the error tracking settings of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ErrorTrackingSettings<'a> {
+    /// The project to query for error tracking settings.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ErrorTrackingSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ErrorTrackingSettingsBuilder<'a> {
+        ErrorTrackingSettingsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ErrorTrackingSettings<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/error_tracking/settings", self.project).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::error_tracking::{
+        ErrorTrackingSettings, ErrorTrackingSettingsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ErrorTrackingSettings::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ErrorTrackingSettingsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ErrorTrackingSettings::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/error_tracking/settings")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ErrorTrackingSettings::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,165 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::de::IgnoredAny;
+
+use crate::api::common::NameOrId;
+use crate::api::projects::Project;
+use crate::api::{self, ApiError, AsyncClient, AsyncQuery, Client, Query};
+
+/// Check whether a project exists.
+///
+/// This queries the project and reports whether it was found. Note that projects which are not
+/// visible to the current user are indistinguishable from projects which do not exist.
+#[derive(Debug, Builder, Clone)]
+pub struct ProjectExists<'a> {
+    /// The project to check for.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ProjectExists<'a> {
+    /// Create a builder for the query.
+    pub fn builder() -> ProjectExistsBuilder<'a> {
+        ProjectExistsBuilder::default()
+    }
+
+    fn endpoint(&self) -> Project<'a> {
+        Project::builder()
+            .project(self.project.clone())
+            .build()
+            .expect("just set the project")
+    }
+}
+
+impl<'a, C> Query<bool, C> for ProjectExists<'a>
+where
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<bool, ApiError<C::Error>> {
+        let project: Option<IgnoredAny> = api::optional(self.endpoint()).query(client)?;
+        Ok(project.is_some())
+    }
+}
+
+#[async_trait]
+impl<'a, C> AsyncQuery<bool, C> for ProjectExists<'a>
+where
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<bool, ApiError<C::Error>> {
+        let project: Option<IgnoredAny> =
+            api::optional(self.endpoint()).query_async(client).await?;
+        Ok(project.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use serde_json::json;
+
+    use crate::api::projects::{ProjectExists, ProjectExistsBuilderError};
+    use crate::api::{AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectExists::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectExistsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectExists::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn exists() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "id": 1,
+                "path_with_namespace": "simple/project",
+            }),
+        );
+
+        let query = ProjectExists::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        assert!(query.query(&client).unwrap());
+    }
+
+    #[test]
+    fn does_not_exist() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "404 Project Not Found",
+            }),
+        );
+
+        let query = ProjectExists::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        assert!(!query.query(&client).unwrap());
+    }
+
+    #[tokio::test]
+    async fn does_not_exist_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "404 Project Not Found",
+            }),
+        );
+
+        let query = ProjectExists::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        assert!(!query.query_async(&client).await.unwrap());
+    }
+
+    #[test]
+    fn error() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject")
+            .status(StatusCode::FORBIDDEN)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "403 Forbidden",
+            }),
+        );
+
+        let query = ProjectExists::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        query.query(&client).unwrap_err();
+    }
+}
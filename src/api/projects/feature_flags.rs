@@ -0,0 +1,43 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project feature flag API endpoints.
+//!
+//! These endpoints are used for querying and managing feature flags of a project.
+
+mod create;
+mod delete;
+mod edit;
+mod feature_flag;
+mod feature_flags;
+
+pub use self::create::CreateFeatureFlag;
+pub use self::create::CreateFeatureFlagBuilder;
+pub use self::create::CreateFeatureFlagBuilderError;
+pub use self::create::FeatureFlagScope;
+pub use self::create::FeatureFlagScopeBuilder;
+pub use self::create::FeatureFlagScopeBuilderError;
+pub use self::create::FeatureFlagStrategy;
+pub use self::create::FeatureFlagStrategyBuilder;
+pub use self::create::FeatureFlagStrategyBuilderError;
+pub use self::create::FeatureFlagStrategyType;
+
+pub use self::delete::DeleteFeatureFlag;
+pub use self::delete::DeleteFeatureFlagBuilder;
+pub use self::delete::DeleteFeatureFlagBuilderError;
+
+pub use self::edit::EditFeatureFlag;
+pub use self::edit::EditFeatureFlagBuilder;
+pub use self::edit::EditFeatureFlagBuilderError;
+
+pub use self::feature_flag::FeatureFlag;
+pub use self::feature_flag::FeatureFlagBuilder;
+pub use self::feature_flag::FeatureFlagBuilderError;
+
+pub use self::feature_flags::FeatureFlags;
+pub use self::feature_flags::FeatureFlagsBuilder;
+pub use self::feature_flags::FeatureFlagsBuilderError;
+pub use self::feature_flags::FeatureFlagsScope;
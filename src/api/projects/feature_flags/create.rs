@@ -0,0 +1,474 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+
+use derive_builder::Builder;
+use serde_json::json;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// The type of a feature flag strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FeatureFlagStrategyType {
+    /// Enable the feature for everyone.
+    Default,
+    /// Enable the feature for a percentage of users based on their ID.
+    ///
+    /// Uses the `groupId` and `percentage` parameters.
+    GradualRolloutUserId,
+    /// Enable the feature for a specific set of users.
+    ///
+    /// Uses the `userIds` parameter (a comma-separated list).
+    UserWithId,
+    /// Enable the feature for a percentage of users based on a stickiness value.
+    ///
+    /// Uses the `groupId`, `rollout`, and `stickiness` parameters.
+    FlexibleRollout,
+    /// Enable the feature for the users in a feature flag user list.
+    GitlabUserList,
+}
+
+impl FeatureFlagStrategyType {
+    /// The strategy type as a string.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FeatureFlagStrategyType::Default => "default",
+            FeatureFlagStrategyType::GradualRolloutUserId => "gradualRolloutUserId",
+            FeatureFlagStrategyType::UserWithId => "userWithId",
+            FeatureFlagStrategyType::FlexibleRollout => "flexibleRollout",
+            FeatureFlagStrategyType::GitlabUserList => "gitlabUserList",
+        }
+    }
+}
+
+impl ParamValue<'static> for FeatureFlagStrategyType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// An environment scope of a feature flag strategy.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct FeatureFlagScope<'a> {
+    /// The environment scope (e.g., `production` or `review/*`).
+    #[builder(setter(into))]
+    environment_scope: Cow<'a, str>,
+
+    /// The ID of an existing scope.
+    ///
+    /// Only used when editing a feature flag.
+    #[builder(default)]
+    id: Option<u64>,
+    /// Remove the scope.
+    ///
+    /// Only used when editing a feature flag; requires `id`.
+    #[builder(default)]
+    destroy: Option<bool>,
+}
+
+impl<'a> FeatureFlagScope<'a> {
+    /// Create a builder for the scope.
+    pub fn builder() -> FeatureFlagScopeBuilder<'a> {
+        FeatureFlagScopeBuilder::default()
+    }
+
+    fn as_json(&self) -> serde_json::Value {
+        JsonParams::clean(json!({
+            "id": self.id,
+            "environment_scope": self.environment_scope,
+            "_destroy": self.destroy,
+        }))
+    }
+}
+
+/// A strategy of a feature flag.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct FeatureFlagStrategy<'a> {
+    /// The type of the strategy.
+    name: FeatureFlagStrategyType,
+
+    /// The parameters of the strategy.
+    #[builder(setter(name = "_parameters"), default, private)]
+    parameters: BTreeMap<Cow<'a, str>, Cow<'a, str>>,
+    /// The environment scopes the strategy applies to.
+    #[builder(setter(name = "_scopes"), default, private)]
+    scopes: Vec<FeatureFlagScope<'a>>,
+
+    /// The ID of an existing strategy.
+    ///
+    /// Only used when editing a feature flag.
+    #[builder(default)]
+    id: Option<u64>,
+    /// Remove the strategy.
+    ///
+    /// Only used when editing a feature flag; requires `id`.
+    #[builder(default)]
+    destroy: Option<bool>,
+}
+
+impl<'a> FeatureFlagStrategy<'a> {
+    /// Create a builder for the strategy.
+    pub fn builder() -> FeatureFlagStrategyBuilder<'a> {
+        FeatureFlagStrategyBuilder::default()
+    }
+
+    pub(super) fn as_json(&self) -> serde_json::Value {
+        JsonParams::clean(json!({
+            "id": self.id,
+            "name": self.name.as_str(),
+            "parameters": self.parameters,
+            "scopes": self.scopes
+                .iter()
+                .map(FeatureFlagScope::as_json)
+                .collect::<Vec<_>>(),
+            "_destroy": self.destroy,
+        }))
+    }
+}
+
+impl<'a> FeatureFlagStrategyBuilder<'a> {
+    /// Set a parameter of the strategy.
+    pub fn parameter<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.parameters
+            .get_or_insert_with(BTreeMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Set parameters of the strategy.
+    pub fn parameters<I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = (K, V)>,
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.parameters
+            .get_or_insert_with(BTreeMap::new)
+            .extend(iter.map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    /// Add an environment scope to the strategy.
+    pub fn scope(&mut self, scope: FeatureFlagScope<'a>) -> &mut Self {
+        self.scopes.get_or_insert_with(Vec::new).push(scope);
+        self
+    }
+
+    /// Add environment scopes to the strategy.
+    pub fn scopes<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = FeatureFlagScope<'a>>,
+    {
+        self.scopes.get_or_insert_with(Vec::new).extend(iter);
+        self
+    }
+}
+
+/// Create a feature flag within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreateFeatureFlag<'a> {
+    /// The project to create the feature flag within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the feature flag.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The description of the feature flag.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// Whether the feature flag is active.
+    #[builder(default)]
+    active: Option<bool>,
+    /// The strategies of the feature flag.
+    #[builder(setter(name = "_strategies"), default, private)]
+    strategies: Vec<FeatureFlagStrategy<'a>>,
+}
+
+impl<'a> CreateFeatureFlag<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateFeatureFlagBuilder<'a> {
+        CreateFeatureFlagBuilder::default()
+    }
+
+    fn as_json(&self) -> serde_json::Value {
+        JsonParams::clean(json!({
+            "name": self.name,
+            "description": self.description,
+            "active": self.active,
+            "strategies": self.strategies
+                .iter()
+                .map(FeatureFlagStrategy::as_json)
+                .collect::<Vec<_>>(),
+        }))
+    }
+}
+
+impl<'a> CreateFeatureFlagBuilder<'a> {
+    /// Add a strategy to the feature flag.
+    pub fn strategy(&mut self, strategy: FeatureFlagStrategy<'a>) -> &mut Self {
+        self.strategies.get_or_insert_with(Vec::new).push(strategy);
+        self
+    }
+
+    /// Add strategies to the feature flag.
+    pub fn strategies<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = FeatureFlagStrategy<'a>>,
+    {
+        self.strategies.get_or_insert_with(Vec::new).extend(iter);
+        self
+    }
+}
+
+impl<'a> Endpoint for CreateFeatureFlag<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/feature_flags", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        JsonParams::into_body(&self.as_json())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::feature_flags::{
+        CreateFeatureFlag, CreateFeatureFlagBuilderError, FeatureFlagScope,
+        FeatureFlagScopeBuilderError, FeatureFlagStrategy, FeatureFlagStrategyBuilderError,
+        FeatureFlagStrategyType,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn feature_flag_strategy_type_as_str() {
+        let items = &[
+            (FeatureFlagStrategyType::Default, "default"),
+            (
+                FeatureFlagStrategyType::GradualRolloutUserId,
+                "gradualRolloutUserId",
+            ),
+            (FeatureFlagStrategyType::UserWithId, "userWithId"),
+            (FeatureFlagStrategyType::FlexibleRollout, "flexibleRollout"),
+            (FeatureFlagStrategyType::GitlabUserList, "gitlabUserList"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn feature_flag_scope_environment_scope_is_needed() {
+        let err = FeatureFlagScope::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, FeatureFlagScopeBuilderError, "environment_scope",);
+    }
+
+    #[test]
+    fn feature_flag_scope_environment_scope_is_sufficient() {
+        FeatureFlagScope::builder()
+            .environment_scope("*")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn feature_flag_strategy_name_is_needed() {
+        let err = FeatureFlagStrategy::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, FeatureFlagStrategyBuilderError, "name");
+    }
+
+    #[test]
+    fn feature_flag_strategy_name_is_sufficient() {
+        FeatureFlagStrategy::builder()
+            .name(FeatureFlagStrategyType::Default)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_and_name_are_needed() {
+        let err = CreateFeatureFlag::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateFeatureFlag::builder()
+            .name("flag")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = CreateFeatureFlag::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFeatureFlagBuilderError, "name");
+    }
+
+    #[test]
+    fn project_and_name_are_sufficient() {
+        CreateFeatureFlag::builder()
+            .project(1)
+            .name("flag")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/feature_flags")
+            .content_type("application/json")
+            .body_str("{\"name\":\"flag\"}")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateFeatureFlag::builder()
+            .project("simple/project")
+            .name("flag")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/feature_flags")
+            .content_type("application/json")
+            .body_str("{\"description\":\"A flag\",\"name\":\"flag\"}")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateFeatureFlag::builder()
+            .project("simple/project")
+            .name("flag")
+            .description("A flag")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_active() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/feature_flags")
+            .content_type("application/json")
+            .body_str("{\"active\":false,\"name\":\"flag\"}")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateFeatureFlag::builder()
+            .project("simple/project")
+            .name("flag")
+            .active(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_strategies() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/feature_flags")
+            .content_type("application/json")
+            .body_str(concat!(
+                "{",
+                "\"name\":\"flag\",",
+                "\"strategies\":[",
+                "{",
+                "\"name\":\"default\",",
+                "\"scopes\":[{\"environment_scope\":\"review/*\"}]",
+                "},",
+                "{",
+                "\"name\":\"gradualRolloutUserId\",",
+                "\"parameters\":{\"groupId\":\"default\",\"percentage\":\"25\"},",
+                "\"scopes\":[",
+                "{\"environment_scope\":\"production\"},",
+                "{\"environment_scope\":\"staging\"}",
+                "]",
+                "},",
+                "{",
+                "\"name\":\"userWithId\",",
+                "\"parameters\":{\"userIds\":\"1,2\"}",
+                "}",
+                "]",
+                "}",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateFeatureFlag::builder()
+            .project("simple/project")
+            .name("flag")
+            .strategy(
+                FeatureFlagStrategy::builder()
+                    .name(FeatureFlagStrategyType::Default)
+                    .scope(
+                        FeatureFlagScope::builder()
+                            .environment_scope("review/*")
+                            .build()
+                            .unwrap(),
+                    )
+                    .build()
+                    .unwrap(),
+            )
+            .strategies(
+                [
+                    FeatureFlagStrategy::builder()
+                        .name(FeatureFlagStrategyType::GradualRolloutUserId)
+                        .parameter("percentage", "25")
+                        .parameter("groupId", "default")
+                        .scopes(["production", "staging"].iter().map(|scope| {
+                            FeatureFlagScope::builder()
+                                .environment_scope(*scope)
+                                .build()
+                                .unwrap()
+                        }))
+                        .build()
+                        .unwrap(),
+                    FeatureFlagStrategy::builder()
+                        .name(FeatureFlagStrategyType::UserWithId)
+                        .parameters([("userIds", "1,2")].iter().copied())
+                        .build()
+                        .unwrap(),
+                ]
+                .iter()
+                .cloned(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,99 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a feature flag from a project.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteFeatureFlag<'a> {
+    /// The project of the feature flag.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the feature flag.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl<'a> DeleteFeatureFlag<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteFeatureFlagBuilder<'a> {
+        DeleteFeatureFlagBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteFeatureFlag<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/feature_flags/{}",
+            self.project,
+            common::path_escaped(&self.name),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::feature_flags::{DeleteFeatureFlag, DeleteFeatureFlagBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_name_are_needed() {
+        let err = DeleteFeatureFlag::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteFeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteFeatureFlag::builder()
+            .name("flag")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteFeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = DeleteFeatureFlag::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteFeatureFlagBuilderError, "name");
+    }
+
+    #[test]
+    fn project_and_name_are_sufficient() {
+        DeleteFeatureFlag::builder()
+            .project(1)
+            .name("flag")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/feature_flags/awesome%2Fflag")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteFeatureFlag::builder()
+            .project("simple/project")
+            .name("awesome/flag")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
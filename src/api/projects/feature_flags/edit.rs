@@ -0,0 +1,282 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+use serde_json::json;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::feature_flags::FeatureFlagStrategy;
+
+/// Edit a feature flag within a project.
+///
+/// Existing strategies and scopes are referred to by their `id`. Strategies or scopes without an
+/// `id` are added and those with `destroy` set are removed.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditFeatureFlag<'a> {
+    /// The project of the feature flag.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the feature flag.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The new name of the feature flag.
+    #[builder(setter(into), default)]
+    new_name: Option<Cow<'a, str>>,
+    /// The description of the feature flag.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// Whether the feature flag is active.
+    #[builder(default)]
+    active: Option<bool>,
+    /// The strategies of the feature flag.
+    #[builder(setter(name = "_strategies"), default, private)]
+    strategies: Vec<FeatureFlagStrategy<'a>>,
+}
+
+impl<'a> EditFeatureFlag<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditFeatureFlagBuilder<'a> {
+        EditFeatureFlagBuilder::default()
+    }
+
+    fn as_json(&self) -> serde_json::Value {
+        JsonParams::clean(json!({
+            "name": self.new_name,
+            "description": self.description,
+            "active": self.active,
+            "strategies": self.strategies
+                .iter()
+                .map(FeatureFlagStrategy::as_json)
+                .collect::<Vec<_>>(),
+        }))
+    }
+}
+
+impl<'a> EditFeatureFlagBuilder<'a> {
+    /// Add or change a strategy of the feature flag.
+    pub fn strategy(&mut self, strategy: FeatureFlagStrategy<'a>) -> &mut Self {
+        self.strategies.get_or_insert_with(Vec::new).push(strategy);
+        self
+    }
+
+    /// Add or change strategies of the feature flag.
+    pub fn strategies<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = FeatureFlagStrategy<'a>>,
+    {
+        self.strategies.get_or_insert_with(Vec::new).extend(iter);
+        self
+    }
+}
+
+impl<'a> Endpoint for EditFeatureFlag<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/feature_flags/{}",
+            self.project,
+            common::path_escaped(&self.name),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        JsonParams::into_body(&self.as_json())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::feature_flags::{
+        EditFeatureFlag, EditFeatureFlagBuilderError, FeatureFlagScope, FeatureFlagStrategy,
+        FeatureFlagStrategyType,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_name_are_needed() {
+        let err = EditFeatureFlag::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditFeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditFeatureFlag::builder().name("flag").build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditFeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = EditFeatureFlag::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditFeatureFlagBuilderError, "name");
+    }
+
+    #[test]
+    fn project_and_name_are_sufficient() {
+        EditFeatureFlag::builder()
+            .project(1)
+            .name("flag")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/feature_flags/awesome%2Fflag")
+            .content_type("application/json")
+            .body_str("{}")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFeatureFlag::builder()
+            .project("simple/project")
+            .name("awesome/flag")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_new_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/feature_flags/flag")
+            .content_type("application/json")
+            .body_str("{\"name\":\"renamed\"}")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFeatureFlag::builder()
+            .project("simple/project")
+            .name("flag")
+            .new_name("renamed")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/feature_flags/flag")
+            .content_type("application/json")
+            .body_str("{\"description\":\"A flag\"}")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFeatureFlag::builder()
+            .project("simple/project")
+            .name("flag")
+            .description("A flag")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_active() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/feature_flags/flag")
+            .content_type("application/json")
+            .body_str("{\"active\":true}")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFeatureFlag::builder()
+            .project("simple/project")
+            .name("flag")
+            .active(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_strategies() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/feature_flags/flag")
+            .content_type("application/json")
+            .body_str(concat!(
+                "{",
+                "\"strategies\":[",
+                "{",
+                "\"id\":1,",
+                "\"name\":\"gradualRolloutUserId\",",
+                "\"parameters\":{\"groupId\":\"default\",\"percentage\":\"50\"},",
+                "\"scopes\":[",
+                "{\"_destroy\":true,\"environment_scope\":\"staging\",\"id\":2},",
+                "{\"environment_scope\":\"production\"}",
+                "]",
+                "},",
+                "{",
+                "\"_destroy\":true,",
+                "\"id\":3,",
+                "\"name\":\"default\"",
+                "}",
+                "]",
+                "}",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFeatureFlag::builder()
+            .project("simple/project")
+            .name("flag")
+            .strategy(
+                FeatureFlagStrategy::builder()
+                    .id(1)
+                    .name(FeatureFlagStrategyType::GradualRolloutUserId)
+                    .parameter("groupId", "default")
+                    .parameter("percentage", "50")
+                    .scope(
+                        FeatureFlagScope::builder()
+                            .id(2)
+                            .environment_scope("staging")
+                            .destroy(true)
+                            .build()
+                            .unwrap(),
+                    )
+                    .scope(
+                        FeatureFlagScope::builder()
+                            .environment_scope("production")
+                            .build()
+                            .unwrap(),
+                    )
+                    .build()
+                    .unwrap(),
+            )
+            .strategy(
+                FeatureFlagStrategy::builder()
+                    .id(3)
+                    .name(FeatureFlagStrategyType::Default)
+                    .destroy(true)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,93 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a feature flag within a project.
+#[derive(Debug, Builder, Clone)]
+pub struct FeatureFlag<'a> {
+    /// The project to query for the feature flag.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the feature flag.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl<'a> FeatureFlag<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> FeatureFlagBuilder<'a> {
+        FeatureFlagBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for FeatureFlag<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/feature_flags/{}",
+            self.project,
+            common::path_escaped(&self.name),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::feature_flags::{FeatureFlag, FeatureFlagBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_name_are_needed() {
+        let err = FeatureFlag::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, FeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = FeatureFlag::builder().name("flag").build().unwrap_err();
+        crate::test::assert_missing_field!(err, FeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = FeatureFlag::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, FeatureFlagBuilderError, "name");
+    }
+
+    #[test]
+    fn project_and_name_are_sufficient() {
+        FeatureFlag::builder()
+            .project(1)
+            .name("flag")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/feature_flags/awesome%2Fflag")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = FeatureFlag::builder()
+            .project("simple/project")
+            .name("awesome/flag")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
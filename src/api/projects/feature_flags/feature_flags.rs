@@ -0,0 +1,140 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Filter feature flags by their state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FeatureFlagsScope {
+    /// Only return enabled feature flags.
+    Enabled,
+    /// Only return disabled feature flags.
+    Disabled,
+}
+
+impl FeatureFlagsScope {
+    fn as_str(self) -> &'static str {
+        match self {
+            FeatureFlagsScope::Enabled => "enabled",
+            FeatureFlagsScope::Disabled => "disabled",
+        }
+    }
+}
+
+impl ParamValue<'static> for FeatureFlagsScope {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for feature flags within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct FeatureFlags<'a> {
+    /// The project to query for feature flags.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Filter feature flags by their state.
+    #[builder(default)]
+    scope: Option<FeatureFlagsScope>,
+}
+
+impl<'a> FeatureFlags<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> FeatureFlagsBuilder<'a> {
+        FeatureFlagsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for FeatureFlags<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/feature_flags", self.project).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("scope", self.scope);
+
+        params
+    }
+}
+
+impl<'a> Pageable for FeatureFlags<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::feature_flags::{
+        FeatureFlags, FeatureFlagsBuilderError, FeatureFlagsScope,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn feature_flags_scope_as_str() {
+        let items = &[
+            (FeatureFlagsScope::Enabled, "enabled"),
+            (FeatureFlagsScope::Disabled, "disabled"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = FeatureFlags::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, FeatureFlagsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        FeatureFlags::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/feature_flags")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = FeatureFlags::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_scope() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/feature_flags")
+            .add_query_params(&[("scope", "enabled")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = FeatureFlags::builder()
+            .project("simple/project")
+            .scope(FeatureFlagsScope::Enabled)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,36 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project deploy freeze period API endpoints.
+//!
+//! These endpoints are used for querying and modifying the deploy freeze periods of a project.
+//! The start and end of a freeze period are given as cron expressions.
+
+mod create;
+mod delete;
+mod edit;
+mod freeze_period;
+mod freeze_periods;
+
+pub use self::create::CreateFreezePeriod;
+pub use self::create::CreateFreezePeriodBuilder;
+pub use self::create::CreateFreezePeriodBuilderError;
+
+pub use self::delete::DeleteFreezePeriod;
+pub use self::delete::DeleteFreezePeriodBuilder;
+pub use self::delete::DeleteFreezePeriodBuilderError;
+
+pub use self::edit::EditFreezePeriod;
+pub use self::edit::EditFreezePeriodBuilder;
+pub use self::edit::EditFreezePeriodBuilderError;
+
+pub use self::freeze_period::FreezePeriod;
+pub use self::freeze_period::FreezePeriodBuilder;
+pub use self::freeze_period::FreezePeriodBuilderError;
+
+pub use self::freeze_periods::FreezePeriods;
+pub use self::freeze_periods::FreezePeriodsBuilder;
+pub use self::freeze_periods::FreezePeriodsBuilderError;
@@ -0,0 +1,159 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Create a deploy freeze period for a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreateFreezePeriod<'a> {
+    /// The project to create the freeze period within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The start of the freeze period as a cron expression.
+    #[builder(setter(into))]
+    freeze_start: Cow<'a, str>,
+    /// The end of the freeze period as a cron expression.
+    #[builder(setter(into))]
+    freeze_end: Cow<'a, str>,
+
+    /// The timezone of the cron expressions.
+    #[builder(setter(into), default)]
+    cron_timezone: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreateFreezePeriod<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateFreezePeriodBuilder<'a> {
+        CreateFreezePeriodBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateFreezePeriod<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/freeze_periods", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("freeze_start", &self.freeze_start)
+            .push("freeze_end", &self.freeze_end)
+            .push_opt("cron_timezone", self.cron_timezone.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::freeze_periods::{
+        CreateFreezePeriod, CreateFreezePeriodBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_freeze_start_and_freeze_end_are_needed() {
+        let err = CreateFreezePeriod::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFreezePeriodBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateFreezePeriod::builder()
+            .freeze_start("0 23 * * 5")
+            .freeze_end("0 8 * * 1")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFreezePeriodBuilderError, "project");
+    }
+
+    #[test]
+    fn freeze_start_is_needed() {
+        let err = CreateFreezePeriod::builder()
+            .project(1)
+            .freeze_end("0 8 * * 1")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFreezePeriodBuilderError, "freeze_start");
+    }
+
+    #[test]
+    fn freeze_end_is_needed() {
+        let err = CreateFreezePeriod::builder()
+            .project(1)
+            .freeze_start("0 23 * * 5")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFreezePeriodBuilderError, "freeze_end");
+    }
+
+    #[test]
+    fn project_freeze_start_and_freeze_end_are_sufficient() {
+        CreateFreezePeriod::builder()
+            .project(1)
+            .freeze_start("0 23 * * 5")
+            .freeze_end("0 8 * * 1")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/freeze_periods")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("freeze_start=0+23+*+*+5", "&freeze_end=0+8+*+*+1",))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateFreezePeriod::builder()
+            .project("simple/project")
+            .freeze_start("0 23 * * 5")
+            .freeze_end("0 8 * * 1")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_cron_timezone() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/freeze_periods")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "freeze_start=0+23+*+*+5",
+                "&freeze_end=0+8+*+*+1",
+                "&cron_timezone=America%2FNew_York",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateFreezePeriod::builder()
+            .project("simple/project")
+            .freeze_start("0 23 * * 5")
+            .freeze_end("0 8 * * 1")
+            .cron_timezone("America/New_York")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,102 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete a deploy freeze period of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteFreezePeriod<'a> {
+    /// The project of the freeze period.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the freeze period.
+    freeze_period: u64,
+}
+
+impl<'a> DeleteFreezePeriod<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteFreezePeriodBuilder<'a> {
+        DeleteFreezePeriodBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteFreezePeriod<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/freeze_periods/{}",
+            self.project, self.freeze_period,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::freeze_periods::{
+        DeleteFreezePeriod, DeleteFreezePeriodBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_freeze_period_are_needed() {
+        let err = DeleteFreezePeriod::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteFreezePeriodBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteFreezePeriod::builder()
+            .freeze_period(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteFreezePeriodBuilderError, "project");
+    }
+
+    #[test]
+    fn freeze_period_is_needed() {
+        let err = DeleteFreezePeriod::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteFreezePeriodBuilderError, "freeze_period");
+    }
+
+    #[test]
+    fn project_and_freeze_period_are_sufficient() {
+        DeleteFreezePeriod::builder()
+            .project(1)
+            .freeze_period(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/freeze_periods/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteFreezePeriod::builder()
+            .project("simple/project")
+            .freeze_period(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
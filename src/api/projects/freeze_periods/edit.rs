@@ -0,0 +1,181 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Edit a deploy freeze period of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditFreezePeriod<'a> {
+    /// The project of the freeze period.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the freeze period.
+    freeze_period: u64,
+
+    /// The start of the freeze period as a cron expression.
+    #[builder(setter(into), default)]
+    freeze_start: Option<Cow<'a, str>>,
+    /// The end of the freeze period as a cron expression.
+    #[builder(setter(into), default)]
+    freeze_end: Option<Cow<'a, str>>,
+    /// The timezone of the cron expressions.
+    #[builder(setter(into), default)]
+    cron_timezone: Option<Cow<'a, str>>,
+}
+
+impl<'a> EditFreezePeriod<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditFreezePeriodBuilder<'a> {
+        EditFreezePeriodBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for EditFreezePeriod<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/freeze_periods/{}",
+            self.project, self.freeze_period,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("freeze_start", self.freeze_start.as_ref())
+            .push_opt("freeze_end", self.freeze_end.as_ref())
+            .push_opt("cron_timezone", self.cron_timezone.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::freeze_periods::{EditFreezePeriod, EditFreezePeriodBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_freeze_period_are_needed() {
+        let err = EditFreezePeriod::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditFreezePeriodBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditFreezePeriod::builder()
+            .freeze_period(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditFreezePeriodBuilderError, "project");
+    }
+
+    #[test]
+    fn freeze_period_is_needed() {
+        let err = EditFreezePeriod::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditFreezePeriodBuilderError, "freeze_period");
+    }
+
+    #[test]
+    fn project_and_freeze_period_are_sufficient() {
+        EditFreezePeriod::builder()
+            .project(1)
+            .freeze_period(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/freeze_periods/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFreezePeriod::builder()
+            .project("simple/project")
+            .freeze_period(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_freeze_start() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/freeze_periods/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("freeze_start=0+22+*+*+5")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFreezePeriod::builder()
+            .project("simple/project")
+            .freeze_period(1)
+            .freeze_start("0 22 * * 5")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_freeze_end() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/freeze_periods/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("freeze_end=0+7+*+*+1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFreezePeriod::builder()
+            .project("simple/project")
+            .freeze_period(1)
+            .freeze_end("0 7 * * 1")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_cron_timezone() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/freeze_periods/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("cron_timezone=Europe%2FBerlin")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFreezePeriod::builder()
+            .project("simple/project")
+            .freeze_period(1)
+            .cron_timezone("Europe/Berlin")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,70 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for deploy freeze periods of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct FreezePeriods<'a> {
+    /// The project to query for freeze periods.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> FreezePeriods<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> FreezePeriodsBuilder<'a> {
+        FreezePeriodsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for FreezePeriods<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/freeze_periods", self.project).into()
+    }
+}
+
+impl<'a> Pageable for FreezePeriods<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::freeze_periods::{FreezePeriods, FreezePeriodsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = FreezePeriods::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, FreezePeriodsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        FreezePeriods::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/freeze_periods")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = FreezePeriods::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
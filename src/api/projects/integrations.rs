@@ -0,0 +1,31 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project integration API endpoints.
+//!
+//! These endpoints are used for querying and configuring integrations (formerly known as
+//! services) for a project.
+
+mod delete;
+mod integration;
+mod integrations;
+mod set;
+
+pub use self::delete::DeleteProjectIntegration;
+pub use self::delete::DeleteProjectIntegrationBuilder;
+pub use self::delete::DeleteProjectIntegrationBuilderError;
+
+pub use self::integration::ProjectIntegration;
+pub use self::integration::ProjectIntegrationBuilder;
+pub use self::integration::ProjectIntegrationBuilderError;
+
+pub use self::integrations::ProjectIntegrations;
+pub use self::integrations::ProjectIntegrationsBuilder;
+pub use self::integrations::ProjectIntegrationsBuilderError;
+
+pub use self::set::SetProjectIntegration;
+pub use self::set::SetProjectIntegrationBuilder;
+pub use self::set::SetProjectIntegrationBuilderError;
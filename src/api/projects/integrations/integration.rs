@@ -0,0 +1,99 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Query for the settings of an integration of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ProjectIntegration<'a> {
+    /// The project of the integration.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The slug of the integration (e.g., `slack` or `jira`).
+    #[builder(setter(into))]
+    integration: Cow<'a, str>,
+}
+
+impl<'a> ProjectIntegration<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectIntegrationBuilder<'a> {
+        ProjectIntegrationBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectIntegration<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/integrations/{}",
+            self.project,
+            common::path_escaped(&self.integration),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::integrations::{ProjectIntegration, ProjectIntegrationBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_integration_are_needed() {
+        let err = ProjectIntegration::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectIntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectIntegration::builder()
+            .integration("slack")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectIntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn integration_is_needed() {
+        let err = ProjectIntegration::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectIntegrationBuilderError, "integration");
+    }
+
+    #[test]
+    fn project_and_integration_are_sufficient() {
+        ProjectIntegration::builder()
+            .project(1)
+            .integration("slack")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/integrations/slack")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIntegration::builder()
+            .project("simple/project")
+            .integration("slack")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,182 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Create or update the settings of an integration of a project.
+///
+/// Each integration has its own set of settings, so they are passed as arbitrary parameters.
+/// See the GitLab documentation for the settings supported by each integration.
+#[derive(Debug, Builder, Clone)]
+pub struct SetProjectIntegration<'a> {
+    /// The project of the integration.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The slug of the integration (e.g., `slack` or `jira`).
+    #[builder(setter(into))]
+    integration: Cow<'a, str>,
+
+    /// The settings for the integration.
+    #[builder(setter(name = "_params"), default, private)]
+    params: BTreeMap<Cow<'a, str>, Cow<'a, str>>,
+}
+
+impl<'a> SetProjectIntegration<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SetProjectIntegrationBuilder<'a> {
+        SetProjectIntegrationBuilder::default()
+    }
+}
+
+impl<'a> SetProjectIntegrationBuilder<'a> {
+    /// Set a setting for the integration.
+    pub fn param<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.params
+            .get_or_insert_with(BTreeMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Set multiple settings for the integration.
+    pub fn params<I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = (K, V)>,
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.params
+            .get_or_insert_with(BTreeMap::new)
+            .extend(iter.map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+}
+
+impl<'a> Endpoint for SetProjectIntegration<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/integrations/{}",
+            self.project,
+            common::path_escaped(&self.integration),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.extend(self.params.iter().map(|(key, value)| (key.as_ref(), value)));
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::integrations::{
+        SetProjectIntegration, SetProjectIntegrationBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_integration_are_needed() {
+        let err = SetProjectIntegration::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SetProjectIntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = SetProjectIntegration::builder()
+            .integration("slack")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetProjectIntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn integration_is_needed() {
+        let err = SetProjectIntegration::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetProjectIntegrationBuilderError, "integration");
+    }
+
+    #[test]
+    fn project_and_integration_are_sufficient() {
+        SetProjectIntegration::builder()
+            .project(1)
+            .integration("slack")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/slack")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetProjectIntegration::builder()
+            .project("simple/project")
+            .integration("slack")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_params() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/slack")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "channel=%23builds",
+                "&notify_only_broken_pipelines=true",
+                "&webhook=https%3A%2F%2Fhooks.slack.com%2Fservices%2Fsecret",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetProjectIntegration::builder()
+            .project("simple/project")
+            .integration("slack")
+            .param("webhook", "https://hooks.slack.com/services/secret")
+            .params(
+                [
+                    ("channel", "#builds"),
+                    ("notify_only_broken_pipelines", "true"),
+                ]
+                .iter()
+                .cloned(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -8,15 +8,31 @@
 //!
 //! These endpoints are used for querying projects issues.
 
+mod add_spent_time;
 pub mod awards;
 mod create;
 mod edit;
 mod issue;
 mod issues;
+pub mod links;
 mod merge_requests_closing;
+mod move_issue;
 pub mod notes;
 mod related_merge_requests;
+mod reorder;
+mod reset_spent_time;
+mod reset_time_estimate;
 mod resource_label_events;
+mod resource_milestone_events;
+mod resource_state_events;
+mod subscribe;
+mod time_estimate;
+mod time_stats;
+mod unsubscribe;
+
+pub use self::add_spent_time::AddIssueSpentTime;
+pub use self::add_spent_time::AddIssueSpentTimeBuilder;
+pub use self::add_spent_time::AddIssueSpentTimeBuilderError;
 
 pub use self::create::CreateIssue;
 pub use self::create::CreateIssueBuilder;
@@ -41,15 +57,58 @@ pub use self::issues::IssueWeight;
 pub use self::issues::Issues;
 pub use self::issues::IssuesBuilder;
 pub use self::issues::IssuesBuilderError;
+pub use self::issues::IssuesStatistics;
+pub use self::issues::IssuesStatisticsBuilder;
+pub use self::issues::IssuesStatisticsBuilderError;
 
 pub use self::merge_requests_closing::MergeRequestsClosing;
 pub use self::merge_requests_closing::MergeRequestsClosingBuilder;
 pub use self::merge_requests_closing::MergeRequestsClosingBuilderError;
 
+pub use self::move_issue::MoveIssue;
+pub use self::move_issue::MoveIssueBuilder;
+pub use self::move_issue::MoveIssueBuilderError;
+
 pub use self::related_merge_requests::RelatedMergeRequests;
 pub use self::related_merge_requests::RelatedMergeRequestsBuilder;
 pub use self::related_merge_requests::RelatedMergeRequestsBuilderError;
 
+pub use self::reorder::ReorderIssue;
+pub use self::reorder::ReorderIssueBuilder;
+pub use self::reorder::ReorderIssueBuilderError;
+
+pub use self::reset_spent_time::ResetIssueSpentTime;
+pub use self::reset_spent_time::ResetIssueSpentTimeBuilder;
+pub use self::reset_spent_time::ResetIssueSpentTimeBuilderError;
+
+pub use self::reset_time_estimate::ResetIssueTimeEstimate;
+pub use self::reset_time_estimate::ResetIssueTimeEstimateBuilder;
+pub use self::reset_time_estimate::ResetIssueTimeEstimateBuilderError;
+
 pub use self::resource_label_events::IssueResourceLabelEvents;
 pub use self::resource_label_events::IssueResourceLabelEventsBuilder;
 pub use self::resource_label_events::IssueResourceLabelEventsBuilderError;
+
+pub use self::resource_milestone_events::IssueResourceMilestoneEvents;
+pub use self::resource_milestone_events::IssueResourceMilestoneEventsBuilder;
+pub use self::resource_milestone_events::IssueResourceMilestoneEventsBuilderError;
+
+pub use self::resource_state_events::IssueResourceStateEvents;
+pub use self::resource_state_events::IssueResourceStateEventsBuilder;
+pub use self::resource_state_events::IssueResourceStateEventsBuilderError;
+
+pub use self::subscribe::SubscribeIssue;
+pub use self::subscribe::SubscribeIssueBuilder;
+pub use self::subscribe::SubscribeIssueBuilderError;
+
+pub use self::time_estimate::SetIssueTimeEstimate;
+pub use self::time_estimate::SetIssueTimeEstimateBuilder;
+pub use self::time_estimate::SetIssueTimeEstimateBuilderError;
+
+pub use self::time_stats::IssueTimeStats;
+pub use self::time_stats::IssueTimeStatsBuilder;
+pub use self::time_stats::IssueTimeStatsBuilderError;
+
+pub use self::unsubscribe::UnsubscribeIssue;
+pub use self::unsubscribe::UnsubscribeIssueBuilder;
+pub use self::unsubscribe::UnsubscribeIssueBuilderError;
@@ -0,0 +1,157 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Add spent time for an issue.
+///
+/// Durations use GitLab's human-readable format (e.g., `3h30m`).
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct AddIssueSpentTime<'a> {
+    /// The project of the issue.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the issue.
+    issue: u64,
+    /// The duration to add as spent time.
+    #[builder(setter(into))]
+    duration: Cow<'a, str>,
+
+    /// A summary of how the time was spent.
+    #[builder(setter(into), default)]
+    summary: Option<Cow<'a, str>>,
+}
+
+impl<'a> AddIssueSpentTime<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AddIssueSpentTimeBuilder<'a> {
+        AddIssueSpentTimeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for AddIssueSpentTime<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/issues/{}/add_spent_time",
+            self.project, self.issue,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("duration", &self.duration)
+            .push_opt("summary", self.summary.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::issues::{AddIssueSpentTime, AddIssueSpentTimeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_issue_and_duration_are_needed() {
+        let err = AddIssueSpentTime::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, AddIssueSpentTimeBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = AddIssueSpentTime::builder()
+            .issue(1)
+            .duration("3h30m")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddIssueSpentTimeBuilderError, "project");
+    }
+
+    #[test]
+    fn issue_is_needed() {
+        let err = AddIssueSpentTime::builder()
+            .project(1)
+            .duration("3h30m")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddIssueSpentTimeBuilderError, "issue");
+    }
+
+    #[test]
+    fn duration_is_needed() {
+        let err = AddIssueSpentTime::builder()
+            .project(1)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddIssueSpentTimeBuilderError, "duration");
+    }
+
+    #[test]
+    fn project_issue_and_duration_are_sufficient() {
+        AddIssueSpentTime::builder()
+            .project(1)
+            .issue(1)
+            .duration("3h30m")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/issues/1/add_spent_time")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("duration=3h30m")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddIssueSpentTime::builder()
+            .project("simple/project")
+            .issue(1)
+            .duration("3h30m")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_summary() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/issues/1/add_spent_time")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("duration=3h30m", "&summary=review"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddIssueSpentTime::builder()
+            .project("simple/project")
+            .issue(1)
+            .duration("3h30m")
+            .summary("review")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -142,6 +142,13 @@ impl<'a> EditIssueBuilder<'a> {
 
     /// Unassign the issue.
     pub fn unassign(&mut self) -> &mut Self {
+        self.clear_assignees()
+    }
+
+    /// Remove all assignees from the issue.
+    ///
+    /// Not setting any assignees leaves the current assignees as-is.
+    pub fn clear_assignees(&mut self) -> &mut Self {
         self.assignee_ids = Some(Some(IssueAssignees::Unassigned));
         self
     }
@@ -464,6 +471,26 @@ mod tests {
         api::ignore(endpoint).query(&client).unwrap();
     }
 
+    #[test]
+    fn endpoint_clear_assignees() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/issues/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("assignee_ids%5B%5D=0")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditIssue::builder()
+            .project("simple/project")
+            .issue(1)
+            .clear_assignees()
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
     #[test]
     fn endpoint_milestone_id() {
         let endpoint = ExpectedUrl::builder()
@@ -33,3 +33,12 @@ pub type Issues<'a> = crate::api::issues::ProjectIssues<'a>;
 pub type IssuesBuilder<'a> = crate::api::issues::ProjectIssuesBuilder<'a>;
 /// Errors for issue builders.
 pub type IssuesBuilderError = crate::api::issues::ProjectIssuesBuilderError;
+
+/// Query for statistics of issues within a project.
+///
+/// TODO: Negation (not) filters are not yet supported.
+pub type IssuesStatistics<'a> = crate::api::issues::ProjectIssuesStatistics<'a>;
+/// Builder for [`IssuesStatistics`].
+pub type IssuesStatisticsBuilder<'a> = crate::api::issues::ProjectIssuesStatisticsBuilder<'a>;
+/// Errors for issue statistics builders.
+pub type IssuesStatisticsBuilderError = crate::api::issues::ProjectIssuesStatisticsBuilderError;
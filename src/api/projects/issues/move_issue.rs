@@ -0,0 +1,124 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Move an issue to another project.
+///
+/// The response is the new issue in the target project.
+#[derive(Debug, Builder, Clone)]
+pub struct MoveIssue<'a> {
+    /// The project of the issue.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the issue.
+    issue: u64,
+    /// The ID of the project to move the issue to.
+    to_project_id: u64,
+}
+
+impl<'a> MoveIssue<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MoveIssueBuilder<'a> {
+        MoveIssueBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for MoveIssue<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/issues/{}/move", self.project, self.issue).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("to_project_id", self.to_project_id);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::issues::{MoveIssue, MoveIssueBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_issue_and_to_project_id_are_needed() {
+        let err = MoveIssue::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, MoveIssueBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = MoveIssue::builder()
+            .issue(1)
+            .to_project_id(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MoveIssueBuilderError, "project");
+    }
+
+    #[test]
+    fn issue_is_needed() {
+        let err = MoveIssue::builder()
+            .project(1)
+            .to_project_id(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MoveIssueBuilderError, "issue");
+    }
+
+    #[test]
+    fn to_project_id_is_needed() {
+        let err = MoveIssue::builder()
+            .project(1)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MoveIssueBuilderError, "to_project_id");
+    }
+
+    #[test]
+    fn project_issue_and_to_project_id_are_sufficient() {
+        MoveIssue::builder()
+            .project(1)
+            .issue(1)
+            .to_project_id(2)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/issues/1/move")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("to_project_id=2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MoveIssue::builder()
+            .project("simple/project")
+            .issue(1)
+            .to_project_id(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
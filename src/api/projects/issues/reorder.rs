@@ -0,0 +1,148 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Reorder an issue relative to other issues.
+///
+/// This affects the order of issues when sorted by relative position (e.g., on issue boards).
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct ReorderIssue<'a> {
+    /// The project of the issue.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the issue.
+    issue: u64,
+
+    /// The global ID of an issue to place this issue after.
+    #[builder(default)]
+    move_after_id: Option<u64>,
+    /// The global ID of an issue to place this issue before.
+    #[builder(default)]
+    move_before_id: Option<u64>,
+}
+
+impl<'a> ReorderIssue<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ReorderIssueBuilder<'a> {
+        ReorderIssueBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ReorderIssue<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/issues/{}/reorder", self.project, self.issue).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("move_after_id", self.move_after_id)
+            .push_opt("move_before_id", self.move_before_id);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::issues::{ReorderIssue, ReorderIssueBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_issue_are_needed() {
+        let err = ReorderIssue::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ReorderIssueBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ReorderIssue::builder().issue(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, ReorderIssueBuilderError, "project");
+    }
+
+    #[test]
+    fn issue_is_needed() {
+        let err = ReorderIssue::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, ReorderIssueBuilderError, "issue");
+    }
+
+    #[test]
+    fn project_and_issue_are_sufficient() {
+        ReorderIssue::builder().project(1).issue(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/issues/1/reorder")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ReorderIssue::builder()
+            .project("simple/project")
+            .issue(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_move_after_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/issues/1/reorder")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("move_after_id=10")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ReorderIssue::builder()
+            .project("simple/project")
+            .issue(1)
+            .move_after_id(10)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_move_before_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/issues/1/reorder")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("move_before_id=20")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ReorderIssue::builder()
+            .project("simple/project")
+            .issue(1)
+            .move_before_id(20)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
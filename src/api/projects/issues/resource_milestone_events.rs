@@ -0,0 +1,109 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for resource milestone events for an issue.
+#[derive(Debug, Builder, Clone)]
+pub struct IssueResourceMilestoneEvents<'a> {
+    /// The project to query for the issue.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the issue.
+    issue: u64,
+}
+
+impl<'a> IssueResourceMilestoneEvents<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> IssueResourceMilestoneEventsBuilder<'a> {
+        IssueResourceMilestoneEventsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for IssueResourceMilestoneEvents<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/issues/{}/resource_milestone_events",
+            self.project, self.issue,
+        )
+        .into()
+    }
+}
+
+impl<'a> Pageable for IssueResourceMilestoneEvents<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::issues::{
+        IssueResourceMilestoneEvents, IssueResourceMilestoneEventsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_issue_are_needed() {
+        let err = IssueResourceMilestoneEvents::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            IssueResourceMilestoneEventsBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = IssueResourceMilestoneEvents::builder()
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            IssueResourceMilestoneEventsBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn issue_is_needed() {
+        let err = IssueResourceMilestoneEvents::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, IssueResourceMilestoneEventsBuilderError, "issue");
+    }
+
+    #[test]
+    fn project_and_issue_are_sufficient() {
+        IssueResourceMilestoneEvents::builder()
+            .project(1)
+            .issue(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues/1/resource_milestone_events")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = IssueResourceMilestoneEvents::builder()
+            .project("simple/project")
+            .issue(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,93 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Subscribe to notifications for an issue.
+///
+/// The response is the updated issue. If the current user is already subscribed, GitLab responds
+/// with `304 Not Modified` which is reported as an error.
+#[derive(Debug, Builder, Clone)]
+pub struct SubscribeIssue<'a> {
+    /// The project of the issue.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the issue.
+    issue: u64,
+}
+
+impl<'a> SubscribeIssue<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SubscribeIssueBuilder<'a> {
+        SubscribeIssueBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for SubscribeIssue<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/issues/{}/subscribe", self.project, self.issue,).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::issues::{SubscribeIssue, SubscribeIssueBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_issue_are_needed() {
+        let err = SubscribeIssue::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SubscribeIssueBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = SubscribeIssue::builder().issue(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, SubscribeIssueBuilderError, "project");
+    }
+
+    #[test]
+    fn issue_is_needed() {
+        let err = SubscribeIssue::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, SubscribeIssueBuilderError, "issue");
+    }
+
+    #[test]
+    fn project_and_issue_are_sufficient() {
+        SubscribeIssue::builder()
+            .project(1)
+            .issue(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/issues/1/subscribe")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SubscribeIssue::builder()
+            .project("simple/project")
+            .issue(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
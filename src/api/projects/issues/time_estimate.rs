@@ -0,0 +1,129 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Set the time estimate for an issue.
+///
+/// Durations use GitLab's human-readable format (e.g., `3h30m`).
+#[derive(Debug, Builder, Clone)]
+pub struct SetIssueTimeEstimate<'a> {
+    /// The project of the issue.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the issue.
+    issue: u64,
+    /// The duration to set as the estimate.
+    #[builder(setter(into))]
+    duration: Cow<'a, str>,
+}
+
+impl<'a> SetIssueTimeEstimate<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SetIssueTimeEstimateBuilder<'a> {
+        SetIssueTimeEstimateBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for SetIssueTimeEstimate<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/issues/{}/time_estimate",
+            self.project, self.issue,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("duration", &self.duration);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::issues::{SetIssueTimeEstimate, SetIssueTimeEstimateBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_issue_and_duration_are_needed() {
+        let err = SetIssueTimeEstimate::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SetIssueTimeEstimateBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = SetIssueTimeEstimate::builder()
+            .issue(1)
+            .duration("3h30m")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetIssueTimeEstimateBuilderError, "project");
+    }
+
+    #[test]
+    fn issue_is_needed() {
+        let err = SetIssueTimeEstimate::builder()
+            .project(1)
+            .duration("3h30m")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetIssueTimeEstimateBuilderError, "issue");
+    }
+
+    #[test]
+    fn duration_is_needed() {
+        let err = SetIssueTimeEstimate::builder()
+            .project(1)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetIssueTimeEstimateBuilderError, "duration");
+    }
+
+    #[test]
+    fn project_issue_and_duration_are_sufficient() {
+        SetIssueTimeEstimate::builder()
+            .project(1)
+            .issue(1)
+            .duration("3h30m")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/issues/1/time_estimate")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("duration=3h30m")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetIssueTimeEstimate::builder()
+            .project("simple/project")
+            .issue(1)
+            .duration("3h30m")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
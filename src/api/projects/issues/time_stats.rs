@@ -0,0 +1,87 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the time tracking statistics of an issue.
+#[derive(Debug, Builder, Clone)]
+pub struct IssueTimeStats<'a> {
+    /// The project of the issue.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the issue.
+    issue: u64,
+}
+
+impl<'a> IssueTimeStats<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> IssueTimeStatsBuilder<'a> {
+        IssueTimeStatsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for IssueTimeStats<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/issues/{}/time_stats", self.project, self.issue,).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::issues::{IssueTimeStats, IssueTimeStatsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_issue_are_needed() {
+        let err = IssueTimeStats::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueTimeStatsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = IssueTimeStats::builder().issue(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueTimeStatsBuilderError, "project");
+    }
+
+    #[test]
+    fn issue_is_needed() {
+        let err = IssueTimeStats::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueTimeStatsBuilderError, "issue");
+    }
+
+    #[test]
+    fn project_and_issue_are_sufficient() {
+        IssueTimeStats::builder()
+            .project(1)
+            .issue(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues/1/time_stats")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = IssueTimeStats::builder()
+            .project("simple/project")
+            .issue(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
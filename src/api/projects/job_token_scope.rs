@@ -0,0 +1,53 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Project CI/CD job token scope API endpoints.
+//!
+//! These endpoints are used for querying and managing which projects and groups may be accessed
+//! using the CI/CD job token of a project.
+
+mod add_group;
+mod add_project;
+mod edit;
+mod groups;
+mod job_token_scope;
+mod projects;
+mod remove_group;
+mod remove_project;
+
+pub use self::add_group::AddJobTokenScopeGroup;
+pub use self::add_group::AddJobTokenScopeGroupBuilder;
+pub use self::add_group::AddJobTokenScopeGroupBuilderError;
+
+pub use self::add_project::AddJobTokenScopeProject;
+pub use self::add_project::AddJobTokenScopeProjectBuilder;
+pub use self::add_project::AddJobTokenScopeProjectBuilderError;
+
+pub use self::edit::EditJobTokenScope;
+pub use self::edit::EditJobTokenScopeBuilder;
+pub use self::edit::EditJobTokenScopeBuilderError;
+
+pub use self::groups::JobTokenScopeGroups;
+pub use self::groups::JobTokenScopeGroupsBuilder;
+pub use self::groups::JobTokenScopeGroupsBuilderError;
+
+pub use self::job_token_scope::JobTokenScope;
+pub use self::job_token_scope::JobTokenScopeBuilder;
+pub use self::job_token_scope::JobTokenScopeBuilderError;
+
+pub use self::projects::JobTokenScopeProjects;
+pub use self::projects::JobTokenScopeProjectsBuilder;
+pub use self::projects::JobTokenScopeProjectsBuilderError;
+
+pub use self::remove_group::RemoveJobTokenScopeGroup;
+pub use self::remove_group::RemoveJobTokenScopeGroupBuilder;
+pub use self::remove_group::RemoveJobTokenScopeGroupBuilderError;
+
+pub use self::remove_project::RemoveJobTokenScopeProject;
+pub use self::remove_project::RemoveJobTokenScopeProjectBuilder;
+pub use self::remove_project::RemoveJobTokenScopeProjectBuilderError;
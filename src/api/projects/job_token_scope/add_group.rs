@@ -0,0 +1,112 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Allow a group to access a project using CI/CD job tokens.
+#[derive(Debug, Builder, Clone)]
+pub struct AddJobTokenScopeGroup<'a> {
+    /// The project to add the group to the job token allowlist of.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the group to allow.
+    target_group_id: u64,
+}
+
+impl<'a> AddJobTokenScopeGroup<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AddJobTokenScopeGroupBuilder<'a> {
+        AddJobTokenScopeGroupBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for AddJobTokenScopeGroup<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/job_token_scope/groups_allowlist", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("target_group_id", self.target_group_id);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::job_token_scope::{
+        AddJobTokenScopeGroup, AddJobTokenScopeGroupBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_target_group_id_are_needed() {
+        let err = AddJobTokenScopeGroup::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, AddJobTokenScopeGroupBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = AddJobTokenScopeGroup::builder()
+            .target_group_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddJobTokenScopeGroupBuilderError, "project");
+    }
+
+    #[test]
+    fn target_group_id_is_needed() {
+        let err = AddJobTokenScopeGroup::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            AddJobTokenScopeGroupBuilderError,
+            "target_group_id"
+        );
+    }
+
+    #[test]
+    fn project_and_target_group_id_are_sufficient() {
+        AddJobTokenScopeGroup::builder()
+            .project(1)
+            .target_group_id(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/job_token_scope/groups_allowlist")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("target_group_id=42")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddJobTokenScopeGroup::builder()
+            .project("simple/project")
+            .target_group_id(42)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
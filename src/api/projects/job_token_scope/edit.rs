@@ -0,0 +1,103 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Edit the CI/CD job token scope settings of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct EditJobTokenScope<'a> {
+    /// The project to edit the job token scope settings of.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// Whether access to the project using job tokens is limited to the allowlist.
+    enabled: bool,
+}
+
+impl<'a> EditJobTokenScope<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditJobTokenScopeBuilder<'a> {
+        EditJobTokenScopeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for EditJobTokenScope<'a> {
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/job_token_scope", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("enabled", self.enabled);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::job_token_scope::{EditJobTokenScope, EditJobTokenScopeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_enabled_are_needed() {
+        let err = EditJobTokenScope::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditJobTokenScopeBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditJobTokenScope::builder()
+            .enabled(true)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditJobTokenScopeBuilderError, "project");
+    }
+
+    #[test]
+    fn enabled_is_needed() {
+        let err = EditJobTokenScope::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditJobTokenScopeBuilderError, "enabled");
+    }
+
+    #[test]
+    fn project_and_enabled_are_sufficient() {
+        EditJobTokenScope::builder()
+            .project(1)
+            .enabled(true)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PATCH)
+            .endpoint("projects/simple%2Fproject/job_token_scope")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("enabled=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditJobTokenScope::builder()
+            .project("simple/project")
+            .enabled(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
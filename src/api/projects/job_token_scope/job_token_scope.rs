@@ -0,0 +1,68 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the CI/CD job token scope settings of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct JobTokenScope<'a> {
+    /// The project to query for its job token scope settings.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> JobTokenScope<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> JobTokenScopeBuilder<'a> {
+        JobTokenScopeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for JobTokenScope<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/job_token_scope", self.project).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::job_token_scope::{JobTokenScope, JobTokenScopeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = JobTokenScope::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobTokenScopeBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        JobTokenScope::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/job_token_scope")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = JobTokenScope::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,72 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the projects which may access a project using CI/CD job tokens.
+#[derive(Debug, Builder, Clone)]
+pub struct JobTokenScopeProjects<'a> {
+    /// The project to query for its job token allowlist.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> JobTokenScopeProjects<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> JobTokenScopeProjectsBuilder<'a> {
+        JobTokenScopeProjectsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for JobTokenScopeProjects<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/job_token_scope/allowlist", self.project).into()
+    }
+}
+
+impl<'a> Pageable for JobTokenScopeProjects<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::job_token_scope::{
+        JobTokenScopeProjects, JobTokenScopeProjectsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = JobTokenScopeProjects::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobTokenScopeProjectsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        JobTokenScopeProjects::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/job_token_scope/allowlist")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = JobTokenScopeProjects::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,106 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Remove a group from the CI/CD job token allowlist of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct RemoveJobTokenScopeGroup<'a> {
+    /// The project to remove the group from the job token allowlist of.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the group to remove.
+    target_group_id: u64,
+}
+
+impl<'a> RemoveJobTokenScopeGroup<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RemoveJobTokenScopeGroupBuilder<'a> {
+        RemoveJobTokenScopeGroupBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for RemoveJobTokenScopeGroup<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/job_token_scope/groups_allowlist/{}",
+            self.project, self.target_group_id,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::job_token_scope::{
+        RemoveJobTokenScopeGroup, RemoveJobTokenScopeGroupBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_target_group_id_are_needed() {
+        let err = RemoveJobTokenScopeGroup::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RemoveJobTokenScopeGroupBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = RemoveJobTokenScopeGroup::builder()
+            .target_group_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RemoveJobTokenScopeGroupBuilderError, "project");
+    }
+
+    #[test]
+    fn target_group_id_is_needed() {
+        let err = RemoveJobTokenScopeGroup::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            RemoveJobTokenScopeGroupBuilderError,
+            "target_group_id"
+        );
+    }
+
+    #[test]
+    fn project_and_target_group_id_are_sufficient() {
+        RemoveJobTokenScopeGroup::builder()
+            .project(1)
+            .target_group_id(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/job_token_scope/groups_allowlist/42")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RemoveJobTokenScopeGroup::builder()
+            .project("simple/project")
+            .target_group_id(42)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,106 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Remove a project from the CI/CD job token allowlist of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct RemoveJobTokenScopeProject<'a> {
+    /// The project to remove the project from the job token allowlist of.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the project to remove.
+    target_project_id: u64,
+}
+
+impl<'a> RemoveJobTokenScopeProject<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RemoveJobTokenScopeProjectBuilder<'a> {
+        RemoveJobTokenScopeProjectBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for RemoveJobTokenScopeProject<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/job_token_scope/allowlist/{}",
+            self.project, self.target_project_id,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::job_token_scope::{
+        RemoveJobTokenScopeProject, RemoveJobTokenScopeProjectBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_target_project_id_are_needed() {
+        let err = RemoveJobTokenScopeProject::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RemoveJobTokenScopeProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = RemoveJobTokenScopeProject::builder()
+            .target_project_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RemoveJobTokenScopeProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn target_project_id_is_needed() {
+        let err = RemoveJobTokenScopeProject::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            RemoveJobTokenScopeProjectBuilderError,
+            "target_project_id"
+        );
+    }
+
+    #[test]
+    fn project_and_target_project_id_are_sufficient() {
+        RemoveJobTokenScopeProject::builder()
+            .project(1)
+            .target_project_id(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/job_token_scope/allowlist/42")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RemoveJobTokenScopeProject::builder()
+            .project("simple/project")
+            .target_project_id(42)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
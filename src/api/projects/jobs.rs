@@ -9,6 +9,7 @@
 //! These endpoints are used for querying CI jobs.
 
 mod cancel;
+mod delete_artifacts;
 mod erase;
 mod job;
 mod jobs;
@@ -20,6 +21,10 @@ pub use self::cancel::CancelJob;
 pub use self::cancel::CancelJobBuilder;
 pub use self::cancel::CancelJobBuilderError;
 
+pub use self::delete_artifacts::DeleteJobArtifacts;
+pub use self::delete_artifacts::DeleteJobArtifactsBuilder;
+pub use self::delete_artifacts::DeleteJobArtifactsBuilderError;
+
 pub use self::erase::EraseJob;
 pub use self::erase::EraseJobBuilder;
 pub use self::erase::EraseJobBuilderError;
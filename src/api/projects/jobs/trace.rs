@@ -9,7 +9,10 @@ use derive_builder::Builder;
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
 
-/// Query for a job within a project.
+/// Query for the trace (log) of a job within a project.
+///
+/// The trace is plain text rather than JSON, so this endpoint should be used with `api::raw`. For
+/// running jobs, the trace contains the output available so far.
 #[derive(Debug, Builder, Clone)]
 pub struct JobTrace<'a> {
     /// The project to query for the job.
@@ -80,4 +83,22 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_raw_text() {
+        let trace = "Running with gitlab-runner\n{\"not\": json\n\u{1b}[0;m";
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/jobs/1/trace")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, trace);
+
+        let endpoint = JobTrace::builder()
+            .project("simple/project")
+            .job(1)
+            .build()
+            .unwrap();
+        let data = api::raw(endpoint).query(&client).unwrap();
+        assert_eq!(String::from_utf8(data).unwrap(), trace);
+    }
 }
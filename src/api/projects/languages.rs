@@ -0,0 +1,86 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the languages used in a project.
+///
+/// The result is an object mapping language names to the percentage of the repository using it.
+#[derive(Debug, Builder, Clone)]
+pub struct ProjectLanguages<'a> {
+    /// The project to query for languages.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ProjectLanguages<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectLanguagesBuilder<'a> {
+        ProjectLanguagesBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectLanguages<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/languages", self.project).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+
+    use crate::api::projects::{ProjectLanguages, ProjectLanguagesBuilderError};
+    use crate::api::Query;
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_necessary() {
+        let err = ProjectLanguages::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectLanguagesBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectLanguages::builder()
+            .project("project")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/project%2Fsubproject/languages")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "Rust": 80.5,
+                "Shell": 19.5,
+            }),
+        );
+
+        let endpoint = ProjectLanguages::builder()
+            .project("project/subproject")
+            .build()
+            .unwrap();
+        let languages: BTreeMap<String, f64> = endpoint.query(&client).unwrap();
+        assert_eq!(languages.len(), 2);
+        assert_eq!(languages["Rust"], 80.5);
+        assert_eq!(languages["Shell"], 19.5);
+    }
+}
@@ -8,6 +8,7 @@
 //!
 //! These endpoints are used for querying projects merge requests.
 
+mod add_spent_time;
 pub mod approval_rules;
 pub mod approval_state;
 pub mod approvals;
@@ -18,16 +19,34 @@ mod commits;
 mod create;
 mod diffs;
 pub mod discussions;
+pub mod draft_notes;
 mod edit;
 mod issues_closed_by;
 mod merge;
+mod merge_ref;
 mod merge_request;
 mod merge_requests;
 pub mod notes;
+mod participants;
 pub mod pipelines;
 mod rebase;
+mod reset_spent_time;
+mod reset_time_estimate;
 mod resource_label_events;
+mod resource_milestone_events;
+mod resource_state_events;
+mod reviewers;
+mod subscribe;
+mod time_estimate;
+mod time_stats;
 mod unapprove;
+mod unsubscribe;
+mod version;
+mod versions;
+
+pub use self::add_spent_time::AddMergeRequestSpentTime;
+pub use self::add_spent_time::AddMergeRequestSpentTimeBuilder;
+pub use self::add_spent_time::AddMergeRequestSpentTimeBuilderError;
 
 pub use self::approve::ApproveMergeRequest;
 pub use self::approve::ApproveMergeRequestBuilder;
@@ -54,6 +73,10 @@ pub use self::merge::MergeMergeRequest;
 pub use self::merge::MergeMergeRequestBuilder;
 pub use self::merge::MergeMergeRequestBuilderError;
 
+pub use self::merge_ref::MergeRequestMergeRef;
+pub use self::merge_ref::MergeRequestMergeRefBuilder;
+pub use self::merge_ref::MergeRequestMergeRefBuilderError;
+
 pub use self::merge_request::MergeRequest;
 pub use self::merge_request::MergeRequestBuilder;
 pub use self::merge_request::MergeRequestBuilderError;
@@ -84,14 +107,62 @@ pub use self::merge_requests::MergeRequests;
 pub use self::merge_requests::MergeRequestsBuilder;
 pub use self::merge_requests::MergeRequestsBuilderError;
 
+pub use self::participants::MergeRequestParticipants;
+pub use self::participants::MergeRequestParticipantsBuilder;
+pub use self::participants::MergeRequestParticipantsBuilderError;
+
 pub use self::rebase::RebaseMergeRequest;
 pub use self::rebase::RebaseMergeRequestBuilder;
 pub use self::rebase::RebaseMergeRequestBuilderError;
 
+pub use self::reset_spent_time::ResetMergeRequestSpentTime;
+pub use self::reset_spent_time::ResetMergeRequestSpentTimeBuilder;
+pub use self::reset_spent_time::ResetMergeRequestSpentTimeBuilderError;
+
+pub use self::reset_time_estimate::ResetMergeRequestTimeEstimate;
+pub use self::reset_time_estimate::ResetMergeRequestTimeEstimateBuilder;
+pub use self::reset_time_estimate::ResetMergeRequestTimeEstimateBuilderError;
+
 pub use self::resource_label_events::MergeRequestResourceLabelEvents;
 pub use self::resource_label_events::MergeRequestResourceLabelEventsBuilder;
 pub use self::resource_label_events::MergeRequestResourceLabelEventsBuilderError;
 
+pub use self::resource_milestone_events::MergeRequestResourceMilestoneEvents;
+pub use self::resource_milestone_events::MergeRequestResourceMilestoneEventsBuilder;
+pub use self::resource_milestone_events::MergeRequestResourceMilestoneEventsBuilderError;
+
+pub use self::resource_state_events::MergeRequestResourceStateEvents;
+pub use self::resource_state_events::MergeRequestResourceStateEventsBuilder;
+pub use self::resource_state_events::MergeRequestResourceStateEventsBuilderError;
+
+pub use self::reviewers::MergeRequestReviewers;
+pub use self::reviewers::MergeRequestReviewersBuilder;
+pub use self::reviewers::MergeRequestReviewersBuilderError;
+
+pub use self::subscribe::SubscribeMergeRequest;
+pub use self::subscribe::SubscribeMergeRequestBuilder;
+pub use self::subscribe::SubscribeMergeRequestBuilderError;
+
+pub use self::time_estimate::SetMergeRequestTimeEstimate;
+pub use self::time_estimate::SetMergeRequestTimeEstimateBuilder;
+pub use self::time_estimate::SetMergeRequestTimeEstimateBuilderError;
+
+pub use self::time_stats::MergeRequestTimeStats;
+pub use self::time_stats::MergeRequestTimeStatsBuilder;
+pub use self::time_stats::MergeRequestTimeStatsBuilderError;
+
 pub use self::unapprove::UnapproveMergeRequest;
 pub use self::unapprove::UnapproveMergeRequestBuilder;
 pub use self::unapprove::UnapproveMergeRequestBuilderError;
+
+pub use self::unsubscribe::UnsubscribeMergeRequest;
+pub use self::unsubscribe::UnsubscribeMergeRequestBuilder;
+pub use self::unsubscribe::UnsubscribeMergeRequestBuilderError;
+
+pub use self::version::MergeRequestVersion;
+pub use self::version::MergeRequestVersionBuilder;
+pub use self::version::MergeRequestVersionBuilderError;
+
+pub use self::versions::MergeRequestVersions;
+pub use self::versions::MergeRequestVersionsBuilder;
+pub use self::versions::MergeRequestVersionsBuilderError;
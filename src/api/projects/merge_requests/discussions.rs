@@ -6,10 +6,11 @@
 
 //! Project merge request discussion API endpoints.
 //!
-//! These endpoints are used for querying project merge request discussions.
+//! These endpoints are used for querying and resolving project merge request discussions.
 
 mod create;
 mod discussions;
+mod resolve;
 
 pub use self::create::CreateMergeRequestDiscussion;
 pub use self::create::CreateMergeRequestDiscussionBuilder;
@@ -34,3 +35,7 @@ pub use self::create::TextPositionBuilderError;
 pub use self::discussions::MergeRequestDiscussions;
 pub use self::discussions::MergeRequestDiscussionsBuilder;
 pub use self::discussions::MergeRequestDiscussionsBuilderError;
+
+pub use self::resolve::ResolveMergeRequestDiscussion;
+pub use self::resolve::ResolveMergeRequestDiscussionBuilder;
+pub use self::resolve::ResolveMergeRequestDiscussionBuilderError;
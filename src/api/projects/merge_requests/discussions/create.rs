@@ -222,7 +222,7 @@ impl<'a> Position<'a> {
         PositionBuilder::default()
     }
 
-    fn add_params<'b>(&'b self, params: &mut FormParams<'b>) {
+    pub(crate) fn add_params<'b>(&'b self, params: &mut FormParams<'b>) {
         params
             .push("position[base_sha]", self.base_sha.as_ref())
             .push("position[start_sha]", self.start_sha.as_ref())
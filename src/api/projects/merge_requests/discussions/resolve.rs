@@ -0,0 +1,192 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Resolve or unresolve a discussion on a merge request within a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ResolveMergeRequestDiscussion<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the merge request.
+    merge_request: u64,
+    /// The ID of the discussion.
+    #[builder(setter(into))]
+    discussion: Cow<'a, str>,
+    /// Whether the discussion is resolved or not.
+    resolved: bool,
+}
+
+impl<'a> ResolveMergeRequestDiscussion<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ResolveMergeRequestDiscussionBuilder<'a> {
+        ResolveMergeRequestDiscussionBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ResolveMergeRequestDiscussion<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/discussions/{}",
+            self.project,
+            self.merge_request,
+            common::path_escaped(&self.discussion),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("resolved", self.resolved);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::merge_requests::discussions::{
+        ResolveMergeRequestDiscussion, ResolveMergeRequestDiscussionBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_necessary() {
+        let err = ResolveMergeRequestDiscussion::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ResolveMergeRequestDiscussionBuilderError,
+            "project",
+        );
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = ResolveMergeRequestDiscussion::builder()
+            .merge_request(1)
+            .discussion("deadbeef")
+            .resolved(true)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ResolveMergeRequestDiscussionBuilderError,
+            "project",
+        );
+    }
+
+    #[test]
+    fn merge_request_is_necessary() {
+        let err = ResolveMergeRequestDiscussion::builder()
+            .project(1)
+            .discussion("deadbeef")
+            .resolved(true)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ResolveMergeRequestDiscussionBuilderError,
+            "merge_request",
+        );
+    }
+
+    #[test]
+    fn discussion_is_necessary() {
+        let err = ResolveMergeRequestDiscussion::builder()
+            .project(1)
+            .merge_request(1)
+            .resolved(true)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ResolveMergeRequestDiscussionBuilderError,
+            "discussion",
+        );
+    }
+
+    #[test]
+    fn resolved_is_necessary() {
+        let err = ResolveMergeRequestDiscussion::builder()
+            .project(1)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ResolveMergeRequestDiscussionBuilderError,
+            "resolved",
+        );
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        ResolveMergeRequestDiscussion::builder()
+            .project(1)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .resolved(true)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/discussions/deadbeef")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("resolved=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ResolveMergeRequestDiscussion::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .discussion("deadbeef")
+            .resolved(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_unresolve() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/discussions/deadbeef")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("resolved=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ResolveMergeRequestDiscussion::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .discussion("deadbeef")
+            .resolved(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,31 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project merge request draft note API endpoints.
+//!
+//! These endpoints are used for collecting draft notes on a merge request and publishing them
+//! together as a review.
+
+mod bulk_publish;
+mod create;
+mod draft_notes;
+mod publish;
+
+pub use self::bulk_publish::BulkPublishMergeRequestDraftNotes;
+pub use self::bulk_publish::BulkPublishMergeRequestDraftNotesBuilder;
+pub use self::bulk_publish::BulkPublishMergeRequestDraftNotesBuilderError;
+
+pub use self::create::CreateMergeRequestDraftNote;
+pub use self::create::CreateMergeRequestDraftNoteBuilder;
+pub use self::create::CreateMergeRequestDraftNoteBuilderError;
+
+pub use self::draft_notes::MergeRequestDraftNotes;
+pub use self::draft_notes::MergeRequestDraftNotesBuilder;
+pub use self::draft_notes::MergeRequestDraftNotesBuilderError;
+
+pub use self::publish::PublishMergeRequestDraftNote;
+pub use self::publish::PublishMergeRequestDraftNoteBuilder;
+pub use self::publish::PublishMergeRequestDraftNoteBuilderError;
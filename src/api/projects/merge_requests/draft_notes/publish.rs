@@ -0,0 +1,134 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Publish a single draft note on a merge request.
+#[derive(Debug, Builder, Clone)]
+pub struct PublishMergeRequestDraftNote<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request.
+    merge_request: u64,
+    /// The ID of the draft note.
+    draft_note: u64,
+}
+
+impl<'a> PublishMergeRequestDraftNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> PublishMergeRequestDraftNoteBuilder<'a> {
+        PublishMergeRequestDraftNoteBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for PublishMergeRequestDraftNote<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/draft_notes/{}/publish",
+            self.project, self.merge_request, self.draft_note,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::merge_requests::draft_notes::{
+        PublishMergeRequestDraftNote, PublishMergeRequestDraftNoteBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_merge_request_and_draft_note_are_needed() {
+        let err = PublishMergeRequestDraftNote::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            PublishMergeRequestDraftNoteBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = PublishMergeRequestDraftNote::builder()
+            .merge_request(1)
+            .draft_note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            PublishMergeRequestDraftNoteBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn merge_request_is_needed() {
+        let err = PublishMergeRequestDraftNote::builder()
+            .project(1)
+            .draft_note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            PublishMergeRequestDraftNoteBuilderError,
+            "merge_request"
+        );
+    }
+
+    #[test]
+    fn draft_note_is_needed() {
+        let err = PublishMergeRequestDraftNote::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            PublishMergeRequestDraftNoteBuilderError,
+            "draft_note"
+        );
+    }
+
+    #[test]
+    fn project_merge_request_and_draft_note_are_sufficient() {
+        PublishMergeRequestDraftNote::builder()
+            .project(1)
+            .merge_request(1)
+            .draft_note(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/draft_notes/5/publish")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PublishMergeRequestDraftNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .draft_note(5)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
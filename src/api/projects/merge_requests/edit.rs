@@ -54,6 +54,33 @@ impl ParamValue<'static> for MergeRequestStateEvent {
     }
 }
 
+/// Title prefixes which GitLab uses to mark a merge request as a draft.
+///
+/// These are matched case-insensitively.
+const DRAFT_PREFIXES: &[&str] = &["draft:", "[draft]", "(draft)"];
+
+/// The prefix added to titles of merge requests which should be marked as a draft.
+const DRAFT_PREFIX: &str = "Draft: ";
+
+/// Compute the title for a merge request with its draft status set as requested.
+///
+/// Any existing draft prefix is replaced so that repeated application is idempotent.
+fn draft_title(title: &str, draft: bool) -> String {
+    let mut title = title.trim_start();
+    while let Some(prefix) = DRAFT_PREFIXES.iter().find(|prefix| {
+        let title = title.as_bytes();
+        title.len() >= prefix.len() && title[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+    }) {
+        title = title[prefix.len()..].trim_start();
+    }
+
+    if draft {
+        format!("{}{}", DRAFT_PREFIX, title)
+    } else {
+        title.into()
+    }
+}
+
 /// Edit a new merge request on project.
 #[derive(Debug, Builder, Clone)]
 #[builder(setter(strip_option))]
@@ -118,8 +145,29 @@ impl<'a> EditMergeRequest<'a> {
 }
 
 impl<'a> EditMergeRequestBuilder<'a> {
+    /// Set the draft status of the merge request.
+    ///
+    /// GitLab derives the draft status from a prefix on the title, so the current title of the
+    /// merge request is required. Existing draft prefixes (`Draft:`, `[Draft]`, or `(Draft)`)
+    /// are removed and, if `draft` is set, replaced with `Draft: `. This overrides any title set
+    /// previously.
+    pub fn set_draft<T>(&mut self, title: T, draft: bool) -> &mut Self
+    where
+        T: AsRef<str>,
+    {
+        self.title = Some(Some(draft_title(title.as_ref(), draft).into()));
+        self
+    }
+
     /// Unset assignee(s) of merge request
     pub fn unassigned(&mut self) -> &mut Self {
+        self.clear_assignees()
+    }
+
+    /// Remove all assignees from the merge request.
+    ///
+    /// Not setting any assignees leaves the current assignees as-is.
+    pub fn clear_assignees(&mut self) -> &mut Self {
         self.assignee = Some(Some(Assignee::Unassigned));
         self
     }
@@ -336,6 +384,29 @@ mod tests {
     use crate::api::{self, Query};
     use crate::test::client::{ExpectedUrl, SingleTestClient};
 
+    #[test]
+    fn draft_title() {
+        let items = &[
+            ("title", true, "Draft: title"),
+            ("title", false, "title"),
+            ("Draft: title", true, "Draft: title"),
+            ("Draft: title", false, "title"),
+            ("draft:title", true, "Draft: title"),
+            ("[Draft] title", true, "Draft: title"),
+            ("(draft) title", false, "title"),
+            ("Draft: [Draft] title", false, "title"),
+            ("  Draft: title", false, "title"),
+            ("Drafting title", true, "Draft: Drafting title"),
+            ("Draft", false, "Draft"),
+        ];
+
+        for (title, draft, expected) in items {
+            let new_title = super::draft_title(title, *draft);
+            assert_eq!(new_title, *expected);
+            assert_eq!(super::draft_title(&new_title, *draft), *expected);
+        }
+    }
+
     #[test]
     fn merge_request_state_event_as_str() {
         let items = &[
@@ -437,6 +508,46 @@ mod tests {
         api::ignore(endpoint).query(&client).unwrap();
     }
 
+    #[test]
+    fn endpoint_set_draft() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/merge_requests/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("title=Draft%3A+title")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditMergeRequest::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .set_draft("title", true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_set_draft_ready() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/merge_requests/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("title=title")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditMergeRequest::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .set_draft("Draft: title", false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
     #[test]
     fn endpoint_unassigned() {
         let endpoint = ExpectedUrl::builder()
@@ -457,6 +568,26 @@ mod tests {
         api::ignore(endpoint).query(&client).unwrap();
     }
 
+    #[test]
+    fn endpoint_clear_assignees() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/merge_requests/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("assignee_ids=0")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditMergeRequest::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .clear_assignees()
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
     #[test]
     fn endpoint_assignee() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,136 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the merge ref of a merge request.
+///
+/// The merge ref (`refs/merge-requests/:iid/merge`) holds the result of merging the source branch
+/// into the target branch without actually merging the merge request. GitLab responds with an
+/// error if the merge request cannot be merged cleanly.
+#[derive(Debug, Builder, Clone)]
+pub struct MergeRequestMergeRef<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request.
+    merge_request: u64,
+}
+
+impl<'a> MergeRequestMergeRef<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MergeRequestMergeRefBuilder<'a> {
+        MergeRequestMergeRefBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for MergeRequestMergeRef<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/merge_ref",
+            self.project, self.merge_request,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use serde_json::json;
+
+    use crate::api::projects::merge_requests::{
+        MergeRequestMergeRef, MergeRequestMergeRefBuilderError,
+    };
+    use crate::api::{self, ApiError, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_merge_request_are_needed() {
+        let err = MergeRequestMergeRef::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestMergeRefBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = MergeRequestMergeRef::builder()
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestMergeRefBuilderError, "project");
+    }
+
+    #[test]
+    fn merge_request_is_needed() {
+        let err = MergeRequestMergeRef::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestMergeRefBuilderError, "merge_request");
+    }
+
+    #[test]
+    fn project_and_merge_request_are_sufficient() {
+        MergeRequestMergeRef::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests/1/merge_ref")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequestMergeRef::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_conflicts() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests/1/merge_ref")
+            .status(StatusCode::BAD_REQUEST)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "Merge request is not mergeable",
+            }),
+        );
+
+        let endpoint = MergeRequestMergeRef::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .build()
+            .unwrap();
+        let err = api::ignore(endpoint).query(&client).unwrap_err();
+        if let ApiError::Gitlab {
+            msg, ..
+        } = err
+        {
+            assert_eq!(msg, "Merge request is not mergeable");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
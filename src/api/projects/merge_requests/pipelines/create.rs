@@ -9,7 +9,7 @@ use derive_builder::Builder;
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
 
-/// List all pipelines attached to a merge request.
+/// Create a pipeline for the latest changes of a merge request.
 #[derive(Debug, Builder, Clone)]
 pub struct CreateMergeRequestPipelines<'a> {
     /// The project with the merge request.
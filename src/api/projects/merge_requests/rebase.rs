@@ -2,7 +2,7 @@
 // http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
 // option. This file may not be copied, modified, or distributed
-// except according to those s.
+// except according to those terms.
 
 //! This module implements the server-side rebasing endpoint, which is only relevant if you've set
 //! your repository's merge model to __fast forward__ merging.
@@ -0,0 +1,106 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Reset the spent time for a merge request.
+#[derive(Debug, Builder, Clone)]
+pub struct ResetMergeRequestSpentTime<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request.
+    merge_request: u64,
+}
+
+impl<'a> ResetMergeRequestSpentTime<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ResetMergeRequestSpentTimeBuilder<'a> {
+        ResetMergeRequestSpentTimeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ResetMergeRequestSpentTime<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/reset_spent_time",
+            self.project, self.merge_request,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::merge_requests::{
+        ResetMergeRequestSpentTime, ResetMergeRequestSpentTimeBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_merge_request_are_needed() {
+        let err = ResetMergeRequestSpentTime::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ResetMergeRequestSpentTimeBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ResetMergeRequestSpentTime::builder()
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ResetMergeRequestSpentTimeBuilderError, "project");
+    }
+
+    #[test]
+    fn merge_request_is_needed() {
+        let err = ResetMergeRequestSpentTime::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ResetMergeRequestSpentTimeBuilderError,
+            "merge_request"
+        );
+    }
+
+    #[test]
+    fn project_and_merge_request_are_sufficient() {
+        ResetMergeRequestSpentTime::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/reset_spent_time")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ResetMergeRequestSpentTime::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
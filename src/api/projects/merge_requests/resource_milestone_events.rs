@@ -0,0 +1,115 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for resource milestone events for a merge request.
+#[derive(Debug, Builder, Clone)]
+pub struct MergeRequestResourceMilestoneEvents<'a> {
+    /// The project to query for the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the merge request.
+    merge_request: u64,
+}
+
+impl<'a> MergeRequestResourceMilestoneEvents<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MergeRequestResourceMilestoneEventsBuilder<'a> {
+        MergeRequestResourceMilestoneEventsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for MergeRequestResourceMilestoneEvents<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/resource_milestone_events",
+            self.project, self.merge_request,
+        )
+        .into()
+    }
+}
+
+impl<'a> Pageable for MergeRequestResourceMilestoneEvents<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::merge_requests::{
+        MergeRequestResourceMilestoneEvents, MergeRequestResourceMilestoneEventsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_merge_request_are_needed() {
+        let err = MergeRequestResourceMilestoneEvents::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            MergeRequestResourceMilestoneEventsBuilderError,
+            "project",
+        );
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = MergeRequestResourceMilestoneEvents::builder()
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            MergeRequestResourceMilestoneEventsBuilderError,
+            "project",
+        );
+    }
+
+    #[test]
+    fn merge_request_is_needed() {
+        let err = MergeRequestResourceMilestoneEvents::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            MergeRequestResourceMilestoneEventsBuilderError,
+            "merge_request",
+        );
+    }
+
+    #[test]
+    fn project_and_merge_request_are_sufficient() {
+        MergeRequestResourceMilestoneEvents::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests/1/resource_milestone_events")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequestResourceMilestoneEvents::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,139 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Set the time estimate for a merge request.
+///
+/// Durations use GitLab's human-readable format (e.g., `3h30m`).
+#[derive(Debug, Builder, Clone)]
+pub struct SetMergeRequestTimeEstimate<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request.
+    merge_request: u64,
+    /// The duration to set as the estimate.
+    #[builder(setter(into))]
+    duration: Cow<'a, str>,
+}
+
+impl<'a> SetMergeRequestTimeEstimate<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SetMergeRequestTimeEstimateBuilder<'a> {
+        SetMergeRequestTimeEstimateBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for SetMergeRequestTimeEstimate<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/time_estimate",
+            self.project, self.merge_request,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("duration", &self.duration);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::merge_requests::{
+        SetMergeRequestTimeEstimate, SetMergeRequestTimeEstimateBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_merge_request_and_duration_are_needed() {
+        let err = SetMergeRequestTimeEstimate::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SetMergeRequestTimeEstimateBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = SetMergeRequestTimeEstimate::builder()
+            .merge_request(1)
+            .duration("3h30m")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetMergeRequestTimeEstimateBuilderError, "project");
+    }
+
+    #[test]
+    fn merge_request_is_needed() {
+        let err = SetMergeRequestTimeEstimate::builder()
+            .project(1)
+            .duration("3h30m")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            SetMergeRequestTimeEstimateBuilderError,
+            "merge_request"
+        );
+    }
+
+    #[test]
+    fn duration_is_needed() {
+        let err = SetMergeRequestTimeEstimate::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            SetMergeRequestTimeEstimateBuilderError,
+            "duration"
+        );
+    }
+
+    #[test]
+    fn project_merge_request_and_duration_are_sufficient() {
+        SetMergeRequestTimeEstimate::builder()
+            .project(1)
+            .merge_request(1)
+            .duration("3h30m")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/time_estimate")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("duration=3h30m")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetMergeRequestTimeEstimate::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .duration("3h30m")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,109 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Unsubscribe from notifications for a merge request.
+///
+/// The response is the updated merge request. If the current user is not subscribed, GitLab
+/// responds with `304 Not Modified` which is reported as an error.
+#[derive(Debug, Builder, Clone)]
+pub struct UnsubscribeMergeRequest<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request.
+    merge_request: u64,
+}
+
+impl<'a> UnsubscribeMergeRequest<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UnsubscribeMergeRequestBuilder<'a> {
+        UnsubscribeMergeRequestBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for UnsubscribeMergeRequest<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/unsubscribe",
+            self.project, self.merge_request,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::merge_requests::{
+        UnsubscribeMergeRequest, UnsubscribeMergeRequestBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_merge_request_are_needed() {
+        let err = UnsubscribeMergeRequest::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnsubscribeMergeRequestBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = UnsubscribeMergeRequest::builder()
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UnsubscribeMergeRequestBuilderError, "project");
+    }
+
+    #[test]
+    fn merge_request_is_needed() {
+        let err = UnsubscribeMergeRequest::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            UnsubscribeMergeRequestBuilderError,
+            "merge_request"
+        );
+    }
+
+    #[test]
+    fn project_and_merge_request_are_sufficient() {
+        UnsubscribeMergeRequest::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/unsubscribe")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UnsubscribeMergeRequest::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
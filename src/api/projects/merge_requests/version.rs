@@ -0,0 +1,149 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for a single diff version of a merge request.
+///
+/// The response includes the commits and diffs of the version.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct MergeRequestVersion<'a> {
+    /// The project with the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the merge request.
+    merge_request: u64,
+    /// The ID of the version.
+    version: u64,
+
+    /// Return diffs as unified diffs.
+    #[builder(default)]
+    unidiff: Option<bool>,
+}
+
+impl<'a> MergeRequestVersion<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MergeRequestVersionBuilder<'a> {
+        MergeRequestVersionBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for MergeRequestVersion<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/versions/{}",
+            self.project, self.merge_request, self.version,
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("unidiff", self.unidiff);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::merge_requests::{
+        MergeRequestVersion, MergeRequestVersionBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_merge_request_and_version_are_needed() {
+        let err = MergeRequestVersion::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestVersionBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = MergeRequestVersion::builder()
+            .merge_request(1)
+            .version(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestVersionBuilderError, "project");
+    }
+
+    #[test]
+    fn merge_request_is_needed() {
+        let err = MergeRequestVersion::builder()
+            .project(1)
+            .version(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestVersionBuilderError, "merge_request");
+    }
+
+    #[test]
+    fn version_is_needed() {
+        let err = MergeRequestVersion::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestVersionBuilderError, "version");
+    }
+
+    #[test]
+    fn project_merge_request_and_version_are_sufficient() {
+        MergeRequestVersion::builder()
+            .project(1)
+            .merge_request(1)
+            .version(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests/1/versions/110")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequestVersion::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .version(110)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_unidiff() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests/1/versions/110")
+            .add_query_params(&[("unidiff", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequestVersion::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .version(110)
+            .unidiff(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -9,7 +9,12 @@
 //! These endpoints are used for querying project milestones.
 
 mod create;
+mod promote;
 
 pub use self::create::CreateProjectMilestone;
 pub use self::create::CreateProjectMilestoneBuilder;
 pub use self::create::CreateProjectMilestoneBuilderError;
+
+pub use self::promote::PromoteProjectMilestone;
+pub use self::promote::PromoteProjectMilestoneBuilder;
+pub use self::promote::PromoteProjectMilestoneBuilderError;
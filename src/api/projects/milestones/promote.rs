@@ -0,0 +1,104 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Promote a project milestone to a milestone of its group.
+///
+/// The response is the new group milestone.
+#[derive(Debug, Builder, Clone)]
+pub struct PromoteProjectMilestone<'a> {
+    /// The project of the milestone.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the milestone.
+    milestone: u64,
+}
+
+impl<'a> PromoteProjectMilestone<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> PromoteProjectMilestoneBuilder<'a> {
+        PromoteProjectMilestoneBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for PromoteProjectMilestone<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/milestones/{}/promote",
+            self.project, self.milestone,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::milestones::{
+        PromoteProjectMilestone, PromoteProjectMilestoneBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_milestone_are_needed() {
+        let err = PromoteProjectMilestone::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, PromoteProjectMilestoneBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = PromoteProjectMilestone::builder()
+            .milestone(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, PromoteProjectMilestoneBuilderError, "project");
+    }
+
+    #[test]
+    fn milestone_is_needed() {
+        let err = PromoteProjectMilestone::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, PromoteProjectMilestoneBuilderError, "milestone");
+    }
+
+    #[test]
+    fn project_and_milestone_are_sufficient() {
+        PromoteProjectMilestone::builder()
+            .project(1)
+            .milestone(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/milestones/1/promote")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PromoteProjectMilestone::builder()
+            .project("simple/project")
+            .milestone(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,21 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project notification settings API endpoints.
+//!
+//! These endpoints are used for querying and modifying the notification settings of the current
+//! user for a project.
+
+mod edit;
+mod settings;
+
+pub use self::edit::EditProjectNotificationSettings;
+pub use self::edit::EditProjectNotificationSettingsBuilder;
+pub use self::edit::EditProjectNotificationSettingsBuilderError;
+
+pub use self::settings::ProjectNotificationSettings;
+pub use self::settings::ProjectNotificationSettingsBuilder;
+pub use self::settings::ProjectNotificationSettingsBuilderError;
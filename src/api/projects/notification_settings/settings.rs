@@ -0,0 +1,73 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the notification settings of the current user for a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ProjectNotificationSettings<'a> {
+    /// The project to query for notification settings.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ProjectNotificationSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectNotificationSettingsBuilder<'a> {
+        ProjectNotificationSettingsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectNotificationSettings<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/notification_settings", self.project).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::notification_settings::{
+        ProjectNotificationSettings, ProjectNotificationSettingsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectNotificationSettings::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectNotificationSettingsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectNotificationSettings::builder()
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/notification_settings")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectNotificationSettings::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -8,6 +8,7 @@
 //!
 //! These endpoints are used for querying CI pipelines.
 
+mod bridges;
 mod cancel;
 mod create;
 mod delete;
@@ -19,6 +20,10 @@ mod test_report;
 mod test_report_summary;
 mod variables;
 
+pub use self::bridges::PipelineBridges;
+pub use self::bridges::PipelineBridgesBuilder;
+pub use self::bridges::PipelineBridgesBuilderError;
+
 pub use self::cancel::CancelPipeline;
 pub use self::cancel::CancelPipelineBuilder;
 pub use self::cancel::CancelPipelineBuilderError;
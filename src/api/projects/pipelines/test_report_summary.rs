@@ -9,7 +9,7 @@ use derive_builder::Builder;
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
 
-/// Query for the test report of a pipeline.
+/// Query for the summary of the test report of a pipeline.
 #[derive(Debug, Builder, Clone)]
 pub struct PipelineTestReportSummary<'a> {
     /// The project of the pipelines.
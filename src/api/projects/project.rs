@@ -88,6 +88,18 @@ mod tests {
         api::ignore(endpoint).query(&client).unwrap();
     }
 
+    #[test]
+    fn endpoint_by_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Project::builder().project(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
     #[test]
     fn endpoint_statistics() {
         let endpoint = ExpectedUrl::builder()
@@ -138,4 +150,27 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_all_flags() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject")
+            .add_query_params(&[
+                ("statistics", "false"),
+                ("license", "true"),
+                ("with_custom_attributes", "true"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Project::builder()
+            .project("simple/project")
+            .statistics(false)
+            .license(true)
+            .with_custom_attributes(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
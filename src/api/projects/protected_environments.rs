@@ -0,0 +1,30 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project protected environments API endpoints.
+//!
+//! These endpoints are used for querying and managing a project's protected environments.
+
+mod protect;
+mod protected_environment;
+mod protected_environments;
+mod unprotect;
+
+pub use self::protect::ProtectEnvironment;
+pub use self::protect::ProtectEnvironmentBuilder;
+pub use self::protect::ProtectEnvironmentBuilderError;
+
+pub use self::protected_environment::ProtectedEnvironment;
+pub use self::protected_environment::ProtectedEnvironmentBuilder;
+pub use self::protected_environment::ProtectedEnvironmentBuilderError;
+
+pub use self::protected_environments::ProtectedEnvironments;
+pub use self::protected_environments::ProtectedEnvironmentsBuilder;
+pub use self::protected_environments::ProtectedEnvironmentsBuilderError;
+
+pub use self::unprotect::UnprotectEnvironment;
+pub use self::unprotect::UnprotectEnvironmentBuilder;
+pub use self::unprotect::UnprotectEnvironmentBuilderError;
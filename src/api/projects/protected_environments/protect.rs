@@ -0,0 +1,245 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+
+use crate::api::common::{NameOrId, ProtectedAccessLevelWithAccess};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::protected_branches::ProtectedAccess;
+
+/// Protect an environment on a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct ProtectEnvironment<'a> {
+    /// The project to protect an environment within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the environment to protect.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    /// The accesses allowed to deploy to the environment.
+    #[builder(setter(name = "_deploy_access_levels"), private)]
+    deploy_access_levels: BTreeSet<ProtectedAccess<ProtectedAccessLevelWithAccess>>,
+
+    /// The number of approvals required to deploy to the environment.
+    #[builder(default)]
+    required_approval_count: Option<u64>,
+}
+
+impl<'a> ProtectEnvironment<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProtectEnvironmentBuilder<'a> {
+        ProtectEnvironmentBuilder::default()
+    }
+}
+
+impl<'a> ProtectEnvironmentBuilder<'a> {
+    /// Add access to deploy to the environment.
+    pub fn deploy_access_level(
+        &mut self,
+        access: ProtectedAccess<ProtectedAccessLevelWithAccess>,
+    ) -> &mut Self {
+        self.deploy_access_levels
+            .get_or_insert_with(BTreeSet::new)
+            .insert(access);
+        self
+    }
+
+    /// Add accesses to deploy to the environment.
+    pub fn deploy_access_levels<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = ProtectedAccess<ProtectedAccessLevelWithAccess>>,
+    {
+        self.deploy_access_levels
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter);
+        self
+    }
+
+    fn validate(&self) -> Result<(), ProtectEnvironmentBuilderError> {
+        match &self.deploy_access_levels {
+            Some(levels) if !levels.is_empty() => Ok(()),
+            _ => {
+                Err(ProtectEnvironmentBuilderError::ValidationError(
+                    "at least one deploy access level is required".into(),
+                ))
+            },
+        }
+    }
+}
+
+impl<'a> Endpoint for ProtectEnvironment<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/protected_environments", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("name", &self.name);
+
+        self.deploy_access_levels
+            .iter()
+            .for_each(|value| value.add_query("deploy_access_levels", &mut params));
+
+        params.push_opt("required_approval_count", self.required_approval_count);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::common::ProtectedAccessLevelWithAccess;
+    use crate::api::projects::protected_branches::ProtectedAccess;
+    use crate::api::projects::protected_environments::{
+        ProtectEnvironment, ProtectEnvironmentBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_name_and_deploy_access_level_are_necessary() {
+        let err = ProtectEnvironment::builder().build().unwrap_err();
+        assert!(matches!(
+            err,
+            ProtectEnvironmentBuilderError::ValidationError(_),
+        ));
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = ProtectEnvironment::builder()
+            .name("production")
+            .deploy_access_level(ProtectedAccessLevelWithAccess::Maintainer.into())
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProtectEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_necessary() {
+        let err = ProtectEnvironment::builder()
+            .project(1)
+            .deploy_access_level(ProtectedAccessLevelWithAccess::Maintainer.into())
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProtectEnvironmentBuilderError, "name");
+    }
+
+    #[test]
+    fn deploy_access_level_is_necessary() {
+        let err = ProtectEnvironment::builder()
+            .project(1)
+            .name("production")
+            .build()
+            .unwrap_err();
+        if let ProtectEnvironmentBuilderError::ValidationError(message) = err {
+            assert_eq!(message, "at least one deploy access level is required");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn project_name_and_deploy_access_level_are_sufficient() {
+        ProtectEnvironment::builder()
+            .project(1)
+            .name("production")
+            .deploy_access_level(ProtectedAccessLevelWithAccess::Maintainer.into())
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/protected_environments")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=production",
+                "&deploy_access_levels%5B%5D%5Baccess_level%5D=40",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProtectEnvironment::builder()
+            .project("simple/project")
+            .name("production")
+            .deploy_access_level(ProtectedAccessLevelWithAccess::Maintainer.into())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_deploy_access_levels() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/protected_environments")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=production",
+                "&deploy_access_levels%5B%5D%5Buser_id%5D=1",
+                "&deploy_access_levels%5B%5D%5Bgroup_id%5D=2",
+                "&deploy_access_levels%5B%5D%5Baccess_level%5D=30",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProtectEnvironment::builder()
+            .project("simple/project")
+            .name("production")
+            .deploy_access_level(ProtectedAccess::User(1))
+            .deploy_access_levels(
+                [
+                    ProtectedAccess::Group(2),
+                    ProtectedAccess::Level(ProtectedAccessLevelWithAccess::Developer),
+                ]
+                .iter()
+                .copied(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_required_approval_count() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/protected_environments")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=production",
+                "&deploy_access_levels%5B%5D%5Baccess_level%5D=40",
+                "&required_approval_count=2",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProtectEnvironment::builder()
+            .project("simple/project")
+            .name("production")
+            .deploy_access_level(ProtectedAccessLevelWithAccess::Maintainer.into())
+            .required_approval_count(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
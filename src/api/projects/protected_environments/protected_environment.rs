@@ -0,0 +1,101 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a protected environment on a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ProtectedEnvironment<'a> {
+    /// The project to query for the protected environment.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the environment.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl<'a> ProtectedEnvironment<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProtectedEnvironmentBuilder<'a> {
+        ProtectedEnvironmentBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProtectedEnvironment<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/protected_environments/{}",
+            self.project,
+            common::path_escaped(&self.name),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::protected_environments::{
+        ProtectedEnvironment, ProtectedEnvironmentBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_name_are_necessary() {
+        let err = ProtectedEnvironment::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProtectedEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = ProtectedEnvironment::builder()
+            .name("production")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProtectedEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_necessary() {
+        let err = ProtectedEnvironment::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProtectedEnvironmentBuilderError, "name");
+    }
+
+    #[test]
+    fn project_and_name_are_sufficient() {
+        ProtectedEnvironment::builder()
+            .project(1)
+            .name("production")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/protected_environments/review%2Fbranch")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProtectedEnvironment::builder()
+            .project("simple/project")
+            .name("review/branch")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,72 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for protected environments on a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ProtectedEnvironments<'a> {
+    /// The project to query for protected environments.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ProtectedEnvironments<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProtectedEnvironmentsBuilder<'a> {
+        ProtectedEnvironmentsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProtectedEnvironments<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/protected_environments", self.project).into()
+    }
+}
+
+impl<'a> Pageable for ProtectedEnvironments<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::protected_environments::{
+        ProtectedEnvironments, ProtectedEnvironmentsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_necessary() {
+        let err = ProtectedEnvironments::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProtectedEnvironmentsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProtectedEnvironments::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/protected_environments")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProtectedEnvironments::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,104 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Unprotect an environment on a project.
+#[derive(Debug, Builder, Clone)]
+pub struct UnprotectEnvironment<'a> {
+    /// The project to unprotect the environment within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the environment.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl<'a> UnprotectEnvironment<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UnprotectEnvironmentBuilder<'a> {
+        UnprotectEnvironmentBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for UnprotectEnvironment<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/protected_environments/{}",
+            self.project,
+            common::path_escaped(&self.name),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::protected_environments::{
+        UnprotectEnvironment, UnprotectEnvironmentBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_name_are_necessary() {
+        let err = UnprotectEnvironment::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnprotectEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = UnprotectEnvironment::builder()
+            .name("production")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UnprotectEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_necessary() {
+        let err = UnprotectEnvironment::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UnprotectEnvironmentBuilderError, "name");
+    }
+
+    #[test]
+    fn project_and_name_are_sufficient() {
+        UnprotectEnvironment::builder()
+            .project(1)
+            .name("production")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/protected_environments/review%2Fbranch")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UnprotectEnvironment::builder()
+            .project("simple/project")
+            .name("review/branch")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
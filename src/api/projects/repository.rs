@@ -11,7 +11,9 @@
 mod archive;
 pub mod branches;
 pub mod commits;
+mod contributors;
 pub mod files;
+mod merge_base;
 pub mod tags;
 mod tree;
 
@@ -20,6 +22,15 @@ pub use archive::ArchiveBuilder;
 pub use archive::ArchiveBuilderError;
 pub use archive::ArchiveFormat;
 
+pub use contributors::Contributors;
+pub use contributors::ContributorsBuilder;
+pub use contributors::ContributorsBuilderError;
+pub use contributors::ContributorsOrderBy;
+
+pub use merge_base::MergeBase;
+pub use merge_base::MergeBaseBuilder;
+pub use merge_base::MergeBaseBuilderError;
+
 pub use tree::Tree;
 pub use tree::TreeBuilder;
 pub use tree::TreeBuilderError;
@@ -8,6 +8,8 @@
 //!
 //! These endpoints are used for querying a project's commits.
 
+mod branches_containing;
+mod combined_status;
 mod comment;
 mod comments;
 mod commit;
@@ -15,11 +17,16 @@ mod commits;
 mod compare;
 mod create;
 mod create_status;
+mod discussions;
 mod merge_requests;
 mod refs;
 mod signature;
 mod statuses;
 
+pub use self::branches_containing::BranchesContaining;
+pub use self::branches_containing::BranchesContainingBuilder;
+pub use self::branches_containing::BranchesContainingBuilderError;
+
 pub use self::comment::CommentOnCommit;
 pub use self::comment::CommentOnCommitBuilder;
 pub use self::comment::CommentOnCommitBuilderError;
@@ -51,6 +58,10 @@ pub use self::create_status::CreateCommitStatus;
 pub use self::create_status::CreateCommitStatusBuilder;
 pub use self::create_status::CreateCommitStatusBuilderError;
 
+pub use self::discussions::CommitDiscussions;
+pub use self::discussions::CommitDiscussionsBuilder;
+pub use self::discussions::CommitDiscussionsBuilderError;
+
 pub use self::refs::CommitReferences;
 pub use self::refs::CommitReferencesBuilder;
 pub use self::refs::CommitReferencesBuilderError;
@@ -64,6 +75,10 @@ pub use self::statuses::CommitStatuses;
 pub use self::statuses::CommitStatusesBuilder;
 pub use self::statuses::CommitStatusesBuilderError;
 
+pub use self::combined_status::CombinedCommitStatus;
+pub use self::combined_status::CombinedCommitStatusBuilder;
+pub use self::combined_status::CombinedCommitStatusBuilderError;
+
 pub use self::merge_requests::MergeRequests;
 pub use self::merge_requests::MergeRequestsBuilder;
 pub use self::merge_requests::MergeRequestsBuilderError;
@@ -0,0 +1,181 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::Deserialize;
+
+use crate::api::common::NameOrId;
+use crate::api::projects::repository::commits::{CommitReferences, CommitRefsType};
+use crate::api::{self, ApiError, AsyncClient, AsyncQuery, Client, Pagination, Query};
+
+/// A reference as returned by the `CommitReferences` endpoint.
+#[derive(Debug, Deserialize)]
+struct CommitRef {
+    #[serde(rename = "type")]
+    kind: String,
+    name: String,
+}
+
+impl CommitRef {
+    fn into_branch(self) -> Option<String> {
+        if self.kind == "branch" {
+            Some(self.name)
+        } else {
+            None
+        }
+    }
+}
+
+/// Query for the names of branches which contain a commit.
+///
+/// All references containing the commit are fetched using `CommitReferences` and then filtered
+/// down to branches.
+#[derive(Debug, Builder, Clone)]
+pub struct BranchesContaining<'a> {
+    /// The project to query.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The commit to look for.
+    #[builder(setter(into))]
+    sha: Cow<'a, str>,
+}
+
+impl<'a> BranchesContaining<'a> {
+    /// Create a builder for the query.
+    pub fn builder() -> BranchesContainingBuilder<'a> {
+        BranchesContainingBuilder::default()
+    }
+
+    fn endpoint(&self) -> CommitReferences<'a> {
+        CommitReferences::builder()
+            .project(self.project.clone())
+            .sha(self.sha.clone())
+            .type_(CommitRefsType::All)
+            .build()
+            .expect("just set the project and sha")
+    }
+}
+
+impl<'a, C> Query<Vec<String>, C> for BranchesContaining<'a>
+where
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Vec<String>, ApiError<C::Error>> {
+        let refs: Vec<CommitRef> = api::paged(self.endpoint(), Pagination::All).query(client)?;
+        Ok(refs
+            .into_iter()
+            .filter_map(CommitRef::into_branch)
+            .collect())
+    }
+}
+
+#[async_trait]
+impl<'a, C> AsyncQuery<Vec<String>, C> for BranchesContaining<'a>
+where
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Vec<String>, ApiError<C::Error>> {
+        let refs: Vec<CommitRef> = api::paged(self.endpoint(), Pagination::All)
+            .query_async(client)
+            .await?;
+        Ok(refs
+            .into_iter()
+            .filter_map(CommitRef::into_branch)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::projects::repository::commits::{
+        BranchesContaining, BranchesContainingBuilderError,
+    };
+    use crate::api::{AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, PagedTestClient};
+
+    const SHA: &str = "0000000000000000000000000000000000000000";
+
+    fn client() -> PagedTestClient<serde_json::Value> {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/commits/0000000000000000000000000000000000000000/refs")
+            .add_query_params(&[("type", "all")])
+            .paginated(true)
+            .build()
+            .unwrap();
+        PagedTestClient::new_raw(
+            endpoint,
+            vec![
+                json!({
+                    "type": "branch",
+                    "name": "main",
+                }),
+                json!({
+                    "type": "tag",
+                    "name": "v1.0.0",
+                }),
+                json!({
+                    "type": "branch",
+                    "name": "release",
+                }),
+            ],
+        )
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = BranchesContaining::builder().sha(SHA).build().unwrap_err();
+        crate::test::assert_missing_field!(err, BranchesContainingBuilderError, "project");
+    }
+
+    #[test]
+    fn sha_is_needed() {
+        let err = BranchesContaining::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, BranchesContainingBuilderError, "sha");
+    }
+
+    #[test]
+    fn project_and_sha_are_sufficient() {
+        BranchesContaining::builder()
+            .project(1)
+            .sha(SHA)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn branches_only() {
+        let client = client();
+
+        let query = BranchesContaining::builder()
+            .project("simple/project")
+            .sha(SHA)
+            .build()
+            .unwrap();
+        let branches = query.query(&client).unwrap();
+        assert_eq!(branches, ["main", "release"]);
+    }
+
+    #[tokio::test]
+    async fn branches_only_async() {
+        let client = client();
+
+        let query = BranchesContaining::builder()
+            .project("simple/project")
+            .sha(SHA)
+            .build()
+            .unwrap();
+        let branches = query.query_async(&client).await.unwrap();
+        assert_eq!(branches, ["main", "release"]);
+    }
+}
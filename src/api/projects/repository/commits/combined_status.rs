@@ -0,0 +1,270 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::Deserialize;
+
+use crate::api::common::NameOrId;
+use crate::api::projects::repository::commits::{CommitStatusState, CommitStatuses};
+use crate::api::{self, ApiError, AsyncClient, AsyncQuery, Client, Pagination, Query};
+
+/// A status as returned by the `CommitStatuses` endpoint.
+#[derive(Debug, Deserialize)]
+struct CommitStatus {
+    status: String,
+    #[serde(default)]
+    allow_failure: bool,
+}
+
+impl CommitStatus {
+    fn combine(statuses: Vec<Self>) -> CommitStatusState {
+        if statuses.is_empty() {
+            return CommitStatusState::Pending;
+        }
+
+        let mut pending = false;
+        for status in statuses {
+            match status.status.as_str() {
+                "success" | "skipped" => (),
+                "failed" if status.allow_failure => (),
+                "failed" | "canceled" => return CommitStatusState::Failed,
+                _ => pending = true,
+            }
+        }
+
+        if pending {
+            CommitStatusState::Pending
+        } else {
+            CommitStatusState::Success
+        }
+    }
+}
+
+/// Query for the combined state of all statuses on a commit.
+///
+/// The latest statuses of the commit are fetched using `CommitStatuses` and rolled up into a
+/// single state:
+///
+///   - `Failed` if any status failed or was canceled (failures which are allowed are ignored);
+///   - otherwise `Pending` if any status has not completed yet (or there are no statuses);
+///   - otherwise `Success`.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CombinedCommitStatus<'a> {
+    /// The project of the commit.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The commit to get the combined status of.
+    #[builder(setter(into))]
+    commit: Cow<'a, str>,
+
+    /// Only consider statuses for a branch or tag.
+    #[builder(setter(into), default)]
+    ref_: Option<Cow<'a, str>>,
+}
+
+impl<'a> CombinedCommitStatus<'a> {
+    /// Create a builder for the query.
+    pub fn builder() -> CombinedCommitStatusBuilder<'a> {
+        CombinedCommitStatusBuilder::default()
+    }
+
+    fn endpoint(&self) -> CommitStatuses<'a> {
+        let mut builder = CommitStatuses::builder();
+        builder
+            .project(self.project.clone())
+            .commit(self.commit.clone());
+        if let Some(ref_) = self.ref_.as_ref() {
+            builder.ref_(ref_.clone());
+        }
+        builder.build().expect("just set the project and commit")
+    }
+}
+
+impl<'a, C> Query<CommitStatusState, C> for CombinedCommitStatus<'a>
+where
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<CommitStatusState, ApiError<C::Error>> {
+        let statuses = api::paged(self.endpoint(), Pagination::All).query(client)?;
+        Ok(CommitStatus::combine(statuses))
+    }
+}
+
+#[async_trait]
+impl<'a, C> AsyncQuery<CommitStatusState, C> for CombinedCommitStatus<'a>
+where
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<CommitStatusState, ApiError<C::Error>> {
+        let statuses = api::paged(self.endpoint(), Pagination::All)
+            .query_async(client)
+            .await?;
+        Ok(CommitStatus::combine(statuses))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::projects::repository::commits::{
+        CombinedCommitStatus, CombinedCommitStatusBuilderError, CommitStatusState,
+    };
+    use crate::api::{AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, PagedTestClient};
+
+    const SHA: &str = "0000000000000000000000000000000000000000";
+
+    fn client(statuses: &[(&str, bool)]) -> PagedTestClient<serde_json::Value> {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/commits/0000000000000000000000000000000000000000/statuses")
+            .paginated(true)
+            .build()
+            .unwrap();
+        PagedTestClient::new_raw(
+            endpoint,
+            statuses.iter().map(|&(status, allow_failure)| {
+                json!({
+                    "status": status,
+                    "allow_failure": allow_failure,
+                })
+            }),
+        )
+    }
+
+    fn combined(statuses: &[(&str, bool)]) -> CommitStatusState {
+        let client = client(statuses);
+
+        let query = CombinedCommitStatus::builder()
+            .project("simple/project")
+            .commit(SHA)
+            .build()
+            .unwrap();
+        query.query(&client).unwrap()
+    }
+
+    #[test]
+    fn project_and_commit_are_needed() {
+        let err = CombinedCommitStatus::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CombinedCommitStatusBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CombinedCommitStatus::builder()
+            .commit(SHA)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CombinedCommitStatusBuilderError, "project");
+    }
+
+    #[test]
+    fn commit_is_needed() {
+        let err = CombinedCommitStatus::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CombinedCommitStatusBuilderError, "commit");
+    }
+
+    #[test]
+    fn project_and_commit_are_sufficient() {
+        CombinedCommitStatus::builder()
+            .project(1)
+            .commit(SHA)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn no_statuses() {
+        assert_eq!(combined(&[]), CommitStatusState::Pending);
+    }
+
+    #[test]
+    fn all_success() {
+        assert_eq!(
+            combined(&[("success", false), ("success", false), ("skipped", false)]),
+            CommitStatusState::Success,
+        );
+    }
+
+    #[test]
+    fn failed_among_successes() {
+        assert_eq!(
+            combined(&[("success", false), ("failed", false), ("success", false)]),
+            CommitStatusState::Failed,
+        );
+    }
+
+    #[test]
+    fn failed_before_pending() {
+        assert_eq!(
+            combined(&[("running", false), ("pending", false), ("failed", false)]),
+            CommitStatusState::Failed,
+        );
+    }
+
+    #[test]
+    fn canceled_is_failed() {
+        assert_eq!(
+            combined(&[("success", false), ("canceled", false)]),
+            CommitStatusState::Failed,
+        );
+    }
+
+    #[test]
+    fn allowed_failure_is_ignored() {
+        assert_eq!(
+            combined(&[("success", false), ("failed", true)]),
+            CommitStatusState::Success,
+        );
+    }
+
+    #[test]
+    fn pending_among_successes() {
+        assert_eq!(
+            combined(&[("success", false), ("running", false)]),
+            CommitStatusState::Pending,
+        );
+    }
+
+    #[test]
+    fn endpoint_ref() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/commits/0000000000000000000000000000000000000000/statuses")
+            .add_query_params(&[("ref", "main")])
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(endpoint, vec![json!({"status": "success"})]);
+
+        let query = CombinedCommitStatus::builder()
+            .project("simple/project")
+            .commit(SHA)
+            .ref_("main")
+            .build()
+            .unwrap();
+        assert_eq!(query.query(&client).unwrap(), CommitStatusState::Success);
+    }
+
+    #[tokio::test]
+    async fn failed_among_successes_async() {
+        let client = client(&[("success", false), ("failed", false)]);
+
+        let query = CombinedCommitStatus::builder()
+            .project("simple/project")
+            .commit(SHA)
+            .build()
+            .unwrap();
+        let state = query.query_async(&client).await.unwrap();
+        assert_eq!(state, CommitStatusState::Failed);
+    }
+}
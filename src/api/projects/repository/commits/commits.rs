@@ -45,6 +45,11 @@ impl ParamValue<'static> for CommitsOrder {
 }
 
 /// Query for commits in a project.
+///
+/// The number of commits reachable from a ref (e.g., for computing build numbers) may be queried
+/// by wrapping this endpoint with `api::count`. GitLab does not always report a total for commit
+/// listings; in that case the count fails with `PaginationError::MissingTotal` rather than
+/// returning zero.
 #[derive(Debug, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct Commits<'a> {
@@ -126,9 +131,10 @@ impl<'a> Pageable for Commits<'a> {}
 #[cfg(test)]
 mod tests {
     use chrono::{TimeZone, Utc};
+    use serde_json::json;
 
     use crate::api::projects::repository::commits::{Commits, CommitsBuilderError, CommitsOrder};
-    use crate::api::{self, Query};
+    use crate::api::{self, ApiError, PaginationError, Query};
     use crate::test::client::{ExpectedUrl, SingleTestClient};
 
     #[test]
@@ -343,4 +349,48 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_count() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/commits")
+            .add_query_params(&[("ref_name", "main"), ("per_page", "1")])
+            .add_response_header("x-total", "1234")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(endpoint, &json!([{}]));
+
+        let endpoint = Commits::builder()
+            .project("simple/project")
+            .ref_name("main")
+            .build()
+            .unwrap();
+        let count = api::count(endpoint).query(&client).unwrap();
+        assert_eq!(count, 1234);
+    }
+
+    #[test]
+    fn endpoint_count_missing_total() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/commits")
+            .add_query_params(&[("ref_name", "main"), ("per_page", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(endpoint, &json!([{}]));
+
+        let endpoint = Commits::builder()
+            .project("simple/project")
+            .ref_name("main")
+            .build()
+            .unwrap();
+        let err = api::count(endpoint).query(&client).unwrap_err();
+        if let ApiError::Pagination {
+            source: PaginationError::MissingTotal,
+        } = err
+        {
+            // expected
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
 }
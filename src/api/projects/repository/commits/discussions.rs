@@ -0,0 +1,102 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Query for discussions on a specific commit in a project.
+#[derive(Debug, Builder, Clone)]
+pub struct CommitDiscussions<'a> {
+    /// The project to get a commit from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The commit to get discussions from.
+    #[builder(setter(into))]
+    commit: Cow<'a, str>,
+}
+
+impl<'a> CommitDiscussions<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CommitDiscussionsBuilder<'a> {
+        CommitDiscussionsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CommitDiscussions<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/repository/commits/{}/discussions",
+            self.project,
+            common::path_escaped(&self.commit),
+        )
+        .into()
+    }
+}
+
+impl<'a> Pageable for CommitDiscussions<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::repository::commits::{
+        CommitDiscussions, CommitDiscussionsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_commit_are_necessary() {
+        let err = CommitDiscussions::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CommitDiscussionsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = CommitDiscussions::builder()
+            .commit("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CommitDiscussionsBuilderError, "project");
+    }
+
+    #[test]
+    fn commit_is_necessary() {
+        let err = CommitDiscussions::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, CommitDiscussionsBuilderError, "commit");
+    }
+
+    #[test]
+    fn project_and_commit_are_sufficient() {
+        CommitDiscussions::builder()
+            .project(1)
+            .commit("master")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint(
+                "projects/simple%2Fproject/repository/commits/refs%2Fheads%2Fmain/discussions",
+            )
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CommitDiscussions::builder()
+            .project("simple/project")
+            .commit("refs/heads/main")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -8,9 +8,11 @@ use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
+use crate::api::projects::merge_requests::MergeRequestState;
 
 /// Get a list of merge requests related to the specified commit.
 #[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
 pub struct MergeRequests<'a> {
     /// The project to get commits from.
     #[builder(setter(into))]
@@ -21,6 +23,10 @@ pub struct MergeRequests<'a> {
     /// The commit SHA.
     #[builder(setter(into))]
     sha: Cow<'a, str>,
+
+    /// Filter merge requests by state.
+    #[builder(default)]
+    state: Option<MergeRequestState>,
 }
 
 impl<'a> MergeRequests<'a> {
@@ -42,13 +48,21 @@ impl<'a> Endpoint for MergeRequests<'a> {
         )
         .into()
     }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("state", self.state);
+
+        params
+    }
 }
 
 impl<'a> Pageable for MergeRequests<'a> {}
 
 #[cfg(test)]
 mod tests {
-
+    use crate::api::projects::merge_requests::MergeRequestState;
     use crate::api::projects::repository::commits::merge_requests::{
         MergeRequests, MergeRequestsBuilderError,
     };
@@ -97,4 +111,22 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_state() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/commits/123/merge_requests")
+            .add_query_params(&[("state", "merged")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequests::builder()
+            .project("simple/project")
+            .sha("123")
+            .state(MergeRequestState::Merged)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -37,7 +37,10 @@ impl ParamValue<'static> for CommitRefsType {
     }
 }
 
-/// Post a comment on a specific commit in a project.
+/// Query for the branches and tags containing a commit.
+///
+/// Use `CommitRefsType::All` with `api::paged` to fetch every reference at once. See
+/// `BranchesContaining` to get just the names of the branches.
 #[derive(Debug, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct CommitReferences<'a> {
@@ -81,6 +84,8 @@ impl<'a> Endpoint for CommitReferences<'a> {
     }
 }
 
+impl<'a> Pageable for CommitReferences<'a> {}
+
 #[cfg(test)]
 mod tests {
     use http::Method;
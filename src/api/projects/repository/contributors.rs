@@ -0,0 +1,238 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{NameOrId, SortOrder};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Orders contributors may be ordered by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ContributorsOrderBy {
+    /// Order by the name of the contributor.
+    Name,
+    /// Order by the email address of the contributor.
+    Email,
+    /// Order by the number of commits from the contributor.
+    Commits,
+}
+
+impl ContributorsOrderBy {
+    fn as_str(self) -> &'static str {
+        match self {
+            ContributorsOrderBy::Name => "name",
+            ContributorsOrderBy::Email => "email",
+            ContributorsOrderBy::Commits => "commits",
+        }
+    }
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for ContributorsOrderBy {
+    fn default() -> Self {
+        // XXX(rust-1.62): use `#[default]`
+        ContributorsOrderBy::Commits
+    }
+}
+
+impl ParamValue<'static> for ContributorsOrderBy {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for the contributors to a project's repository.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct Contributors<'a> {
+    /// The project to get contributors from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// The name of a repository branch or tag or, if not given, the default branch.
+    #[builder(setter(into), default)]
+    ref_: Option<Cow<'a, str>>,
+    /// What field to order returned contributors by.
+    #[builder(default)]
+    order_by: Option<ContributorsOrderBy>,
+    /// Which order to sort the results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl<'a> Contributors<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ContributorsBuilder<'a> {
+        ContributorsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for Contributors<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/repository/contributors", self.project).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("ref", self.ref_.as_ref())
+            .push_opt("order_by", self.order_by)
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl<'a> Pageable for Contributors<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::api::common::SortOrder;
+    use crate::api::projects::repository::{
+        Contributors, ContributorsBuilderError, ContributorsOrderBy,
+    };
+    use crate::api::{self, Pagination, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn contributors_order_by_default() {
+        assert_eq!(
+            ContributorsOrderBy::default(),
+            ContributorsOrderBy::Commits,
+        );
+    }
+
+    #[test]
+    fn contributors_order_by_as_str() {
+        let items = &[
+            (ContributorsOrderBy::Name, "name"),
+            (ContributorsOrderBy::Email, "email"),
+            (ContributorsOrderBy::Commits, "commits"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = Contributors::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ContributorsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        Contributors::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/contributors")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Contributors::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_ref() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/contributors")
+            .add_query_params(&[("ref", "main")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Contributors::builder()
+            .project("simple/project")
+            .ref_("main")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_order_by() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/contributors")
+            .add_query_params(&[("order_by", "email")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Contributors::builder()
+            .project("simple/project")
+            .order_by(ContributorsOrderBy::Email)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/contributors")
+            .add_query_params(&[("sort", "desc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Contributors::builder()
+            .project("simple/project")
+            .sort(SortOrder::Descending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/contributors")
+            .add_query_params(&[("order_by", "commits"), ("page", "1"), ("per_page", "10")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!([
+                {
+                    "name": "Example User",
+                    "email": "user@example.com",
+                    "commits": 117,
+                    "additions": 0,
+                    "deletions": 0,
+                },
+            ]),
+        );
+
+        let endpoint = Contributors::builder()
+            .project("simple/project")
+            .order_by(ContributorsOrderBy::Commits)
+            .build()
+            .unwrap();
+        let contributors: Vec<Value> = api::paged(endpoint, Pagination::Limit(10))
+            .query(&client)
+            .unwrap();
+        assert_eq!(contributors.len(), 1);
+        assert_eq!(contributors[0]["commits"], 117);
+    }
+}
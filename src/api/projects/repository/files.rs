@@ -12,6 +12,7 @@ mod create;
 mod delete;
 mod file;
 mod file_raw;
+mod initialize_readme;
 mod update;
 
 pub use self::file::File;
@@ -31,6 +32,10 @@ pub use self::file_raw::FileRaw;
 pub use self::file_raw::FileRawBuilder;
 pub use self::file_raw::FileRawBuilderError;
 
+pub use self::initialize_readme::InitializeProjectReadme;
+pub use self::initialize_readme::InitializeProjectReadmeBuilder;
+pub use self::initialize_readme::InitializeProjectReadmeBuilderError;
+
 pub use self::update::UpdateFile;
 pub use self::update::UpdateFileBuilder;
 pub use self::update::UpdateFileBuilderError;
@@ -0,0 +1,268 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::api::common::NameOrId;
+use crate::api::projects::repository::files::CreateFile;
+use crate::api::projects::Project;
+use crate::api::{ApiError, AsyncClient, AsyncQuery, Client, Query};
+
+/// The path of the README file which is created.
+const README_PATH: &str = "README.md";
+
+/// The default branch of a project as returned by the `Project` endpoint.
+#[derive(Debug, Deserialize)]
+struct ProjectDefaultBranch {
+    default_branch: String,
+}
+
+/// Commit a `README.md` file to a project.
+///
+/// This is a convenience around `CreateFile`. Unless a branch is given, the project is queried
+/// for its default branch first. The result is the response of the `CreateFile` endpoint.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct InitializeProjectReadme<'a> {
+    /// The project to add the README to.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The content of the README.
+    #[builder(setter(into))]
+    content: Cow<'a, str>,
+
+    /// The branch to commit to.
+    ///
+    /// Defaults to the project's default branch.
+    #[builder(setter(into), default)]
+    branch: Option<Cow<'a, str>>,
+    /// The commit message to use.
+    #[builder(setter(into), default = "\"Add README\".into()")]
+    commit_message: Cow<'a, str>,
+}
+
+impl<'a> InitializeProjectReadme<'a> {
+    /// Create a builder for the query.
+    pub fn builder() -> InitializeProjectReadmeBuilder<'a> {
+        InitializeProjectReadmeBuilder::default()
+    }
+
+    fn project_endpoint(&self) -> Project<'a> {
+        Project::builder()
+            .project(self.project.clone())
+            .build()
+            .expect("just set the project")
+    }
+
+    fn endpoint<'b>(&'b self, branch: &'b str) -> CreateFile<'b> {
+        CreateFile::builder()
+            .project(self.project.clone())
+            .file_path(README_PATH)
+            .branch(branch)
+            .content(self.content.as_bytes())
+            .commit_message(self.commit_message.as_ref())
+            .build()
+            .expect("just set all required fields")
+    }
+}
+
+impl<'a, T, C> Query<T, C> for InitializeProjectReadme<'a>
+where
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let default_branch;
+        let branch = if let Some(branch) = self.branch.as_ref() {
+            branch.as_ref()
+        } else {
+            let project: ProjectDefaultBranch = self.project_endpoint().query(client)?;
+            default_branch = project.default_branch;
+            &default_branch
+        };
+
+        self.endpoint(branch).query(client)
+    }
+}
+
+#[async_trait]
+impl<'a, T, C> AsyncQuery<T, C> for InitializeProjectReadme<'a>
+where
+    T: DeserializeOwned + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let default_branch;
+        let branch = if let Some(branch) = self.branch.as_ref() {
+            branch.as_ref()
+        } else {
+            let project: ProjectDefaultBranch = self.project_endpoint().query_async(client).await?;
+            default_branch = project.default_branch;
+            &default_branch
+        };
+
+        self.endpoint(branch).query_async(client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::api::projects::repository::files::{
+        InitializeProjectReadme, InitializeProjectReadmeBuilderError,
+    };
+    use crate::api::{AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, SequenceTestClient, SingleTestClient};
+
+    #[derive(Debug, Deserialize)]
+    struct CreatedFile {
+        file_path: String,
+        branch: String,
+    }
+
+    fn project_client() -> SingleTestClient {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject")
+            .build()
+            .unwrap();
+        SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "id": 1,
+                "default_branch": "main",
+            }),
+        )
+    }
+
+    fn create_file_client(body: &str, branch: &str) -> SingleTestClient {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/repository/files/README.md")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(body)
+            .build()
+            .unwrap();
+        SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "file_path": "README.md",
+                "branch": branch,
+            }),
+        )
+    }
+
+    #[test]
+    fn project_and_content_are_needed() {
+        let err = InitializeProjectReadme::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, InitializeProjectReadmeBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = InitializeProjectReadme::builder()
+            .content("# Project")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, InitializeProjectReadmeBuilderError, "project");
+    }
+
+    #[test]
+    fn content_is_needed() {
+        let err = InitializeProjectReadme::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, InitializeProjectReadmeBuilderError, "content");
+    }
+
+    #[test]
+    fn project_and_content_are_sufficient() {
+        InitializeProjectReadme::builder()
+            .project(1)
+            .content("# Project")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn default_branch() {
+        let client = SequenceTestClient::new([
+            project_client(),
+            create_file_client(
+                concat!(
+                    "branch=main",
+                    "&commit_message=Add+README",
+                    "&content=%23+Project",
+                ),
+                "main",
+            ),
+        ]);
+
+        let query = InitializeProjectReadme::builder()
+            .project("simple/project")
+            .content("# Project")
+            .build()
+            .unwrap();
+        let file: CreatedFile = query.query(&client).unwrap();
+        assert_eq!(file.file_path, "README.md");
+        assert_eq!(file.branch, "main");
+    }
+
+    #[tokio::test]
+    async fn default_branch_async() {
+        let client = SequenceTestClient::new([
+            project_client(),
+            create_file_client(
+                concat!(
+                    "branch=main",
+                    "&commit_message=Add+README",
+                    "&content=%23+Project",
+                ),
+                "main",
+            ),
+        ]);
+
+        let query = InitializeProjectReadme::builder()
+            .project("simple/project")
+            .content("# Project")
+            .build()
+            .unwrap();
+        let file: CreatedFile = query.query_async(&client).await.unwrap();
+        assert_eq!(file.file_path, "README.md");
+        assert_eq!(file.branch, "main");
+    }
+
+    #[test]
+    fn branch_and_commit_message() {
+        let client = SequenceTestClient::new([create_file_client(
+            concat!(
+                "branch=docs",
+                "&commit_message=Initial+commit",
+                "&content=%23+Project",
+            ),
+            "docs",
+        )]);
+
+        let query = InitializeProjectReadme::builder()
+            .project("simple/project")
+            .content("# Project")
+            .branch("docs")
+            .commit_message("Initial commit")
+            .build()
+            .unwrap();
+        let file: CreatedFile = query.query(&client).unwrap();
+        assert_eq!(file.file_path, "README.md");
+        assert_eq!(file.branch, "docs");
+    }
+}
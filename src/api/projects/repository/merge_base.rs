@@ -0,0 +1,182 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Get the common ancestor of a set of refs in a project's repository.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct MergeBase<'a> {
+    /// The project to query.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// The refs to find the common ancestor of.
+    ///
+    /// At least two refs are required.
+    #[builder(setter(name = "_refs"), private)]
+    refs: BTreeSet<Cow<'a, str>>,
+}
+
+impl<'a> MergeBase<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MergeBaseBuilder<'a> {
+        MergeBaseBuilder::default()
+    }
+}
+
+impl<'a> MergeBaseBuilder<'a> {
+    /// Add a ref to find the common ancestor of.
+    pub fn ref_<R>(&mut self, ref_: R) -> &mut Self
+    where
+        R: Into<Cow<'a, str>>,
+    {
+        self.refs
+            .get_or_insert_with(BTreeSet::new)
+            .insert(ref_.into());
+        self
+    }
+
+    /// Add multiple refs to find the common ancestor of.
+    pub fn refs<I, R>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = R>,
+        R: Into<Cow<'a, str>>,
+    {
+        self.refs
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    fn validate(&self) -> Result<(), MergeBaseBuilderError> {
+        if let Some(refs) = self.refs.as_ref() {
+            if refs.len() < 2 {
+                return Err(MergeBaseBuilderError::ValidationError(
+                    "at least two `refs` are required".into(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for MergeBase<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/repository/merge_base", self.project).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.extend(self.refs.iter().map(|value| ("refs[]", value)));
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::repository::{MergeBase, MergeBaseBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_refs_are_necessary() {
+        let err = MergeBase::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, MergeBaseBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = MergeBase::builder()
+            .ref_("main")
+            .ref_("topic")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MergeBaseBuilderError, "project");
+    }
+
+    #[test]
+    fn refs_are_necessary() {
+        let err = MergeBase::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, MergeBaseBuilderError, "refs");
+    }
+
+    #[test]
+    fn two_refs_are_necessary() {
+        let err = MergeBase::builder()
+            .project(1)
+            .ref_("main")
+            .build()
+            .unwrap_err();
+        if let MergeBaseBuilderError::ValidationError(message) = err {
+            assert_eq!(message, "at least two `refs` are required");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn project_and_refs_are_sufficient() {
+        MergeBase::builder()
+            .project(1)
+            .ref_("main")
+            .ref_("topic")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/merge_base")
+            .add_query_params(&[("refs[]", "main"), ("refs[]", "topic")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeBase::builder()
+            .project("simple/project")
+            .ref_("topic")
+            .ref_("main")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_many_refs() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/merge_base")
+            .add_query_params(&[
+                ("refs[]", "0123456789abcdef"),
+                ("refs[]", "main"),
+                ("refs[]", "topic"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeBase::builder()
+            .project("simple/project")
+            .ref_("main")
+            .refs(["topic", "0123456789abcdef"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
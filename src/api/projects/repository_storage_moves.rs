@@ -0,0 +1,41 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project repository storage move API endpoints.
+//!
+//! These endpoints are used for querying and scheduling moves of project repositories between
+//! storage shards. They are only available to administrators.
+
+mod all_repository_storage_move;
+mod all_repository_storage_moves;
+mod create;
+mod create_all;
+mod repository_storage_move;
+mod repository_storage_moves;
+
+pub use self::all_repository_storage_move::AllProjectRepositoryStorageMove;
+pub use self::all_repository_storage_move::AllProjectRepositoryStorageMoveBuilder;
+pub use self::all_repository_storage_move::AllProjectRepositoryStorageMoveBuilderError;
+
+pub use self::all_repository_storage_moves::AllProjectRepositoryStorageMoves;
+pub use self::all_repository_storage_moves::AllProjectRepositoryStorageMovesBuilder;
+pub use self::all_repository_storage_moves::AllProjectRepositoryStorageMovesBuilderError;
+
+pub use self::create::CreateProjectRepositoryStorageMove;
+pub use self::create::CreateProjectRepositoryStorageMoveBuilder;
+pub use self::create::CreateProjectRepositoryStorageMoveBuilderError;
+
+pub use self::create_all::CreateAllProjectRepositoryStorageMoves;
+pub use self::create_all::CreateAllProjectRepositoryStorageMovesBuilder;
+pub use self::create_all::CreateAllProjectRepositoryStorageMovesBuilderError;
+
+pub use self::repository_storage_move::ProjectRepositoryStorageMove;
+pub use self::repository_storage_move::ProjectRepositoryStorageMoveBuilder;
+pub use self::repository_storage_move::ProjectRepositoryStorageMoveBuilderError;
+
+pub use self::repository_storage_moves::ProjectRepositoryStorageMoves;
+pub use self::repository_storage_moves::ProjectRepositoryStorageMovesBuilder;
+pub use self::repository_storage_moves::ProjectRepositoryStorageMovesBuilderError;
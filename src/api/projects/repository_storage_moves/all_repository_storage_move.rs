@@ -0,0 +1,77 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for a project repository storage move on the instance.
+#[derive(Debug, Builder, Clone)]
+pub struct AllProjectRepositoryStorageMove {
+    /// The ID of the repository storage move.
+    storage_move: u64,
+}
+
+impl AllProjectRepositoryStorageMove {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AllProjectRepositoryStorageMoveBuilder {
+        AllProjectRepositoryStorageMoveBuilder::default()
+    }
+}
+
+impl Endpoint for AllProjectRepositoryStorageMove {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("project_repository_storage_moves/{}", self.storage_move).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::repository_storage_moves::{
+        AllProjectRepositoryStorageMove, AllProjectRepositoryStorageMoveBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn storage_move_is_needed() {
+        let err = AllProjectRepositoryStorageMove::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            AllProjectRepositoryStorageMoveBuilderError,
+            "storage_move",
+        );
+    }
+
+    #[test]
+    fn storage_move_is_sufficient() {
+        AllProjectRepositoryStorageMove::builder()
+            .storage_move(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("project_repository_storage_moves/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AllProjectRepositoryStorageMove::builder()
+            .storage_move(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
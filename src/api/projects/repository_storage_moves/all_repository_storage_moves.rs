@@ -0,0 +1,56 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for all project repository storage moves on the instance.
+#[derive(Debug, Builder, Clone)]
+pub struct AllProjectRepositoryStorageMoves {}
+
+impl AllProjectRepositoryStorageMoves {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AllProjectRepositoryStorageMovesBuilder {
+        AllProjectRepositoryStorageMovesBuilder::default()
+    }
+}
+
+impl Endpoint for AllProjectRepositoryStorageMoves {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "project_repository_storage_moves".into()
+    }
+}
+
+impl Pageable for AllProjectRepositoryStorageMoves {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::repository_storage_moves::AllProjectRepositoryStorageMoves;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        AllProjectRepositoryStorageMoves::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("project_repository_storage_moves")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AllProjectRepositoryStorageMoves::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
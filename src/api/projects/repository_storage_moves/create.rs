@@ -0,0 +1,121 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Schedule a move of a project's repository to another storage.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreateProjectRepositoryStorageMove<'a> {
+    /// The project to move.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// The name of the storage to move the repository to.
+    ///
+    /// If not given, GitLab chooses a storage based on the storage weights of the instance.
+    #[builder(setter(into), default)]
+    destination_storage_name: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreateProjectRepositoryStorageMove<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateProjectRepositoryStorageMoveBuilder<'a> {
+        CreateProjectRepositoryStorageMoveBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateProjectRepositoryStorageMove<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/repository_storage_moves", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push_opt(
+            "destination_storage_name",
+            self.destination_storage_name.as_ref(),
+        );
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::repository_storage_moves::{
+        CreateProjectRepositoryStorageMove, CreateProjectRepositoryStorageMoveBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateProjectRepositoryStorageMove::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateProjectRepositoryStorageMoveBuilderError,
+            "project",
+        );
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        CreateProjectRepositoryStorageMove::builder()
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/repository_storage_moves")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectRepositoryStorageMove::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_destination_storage_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/repository_storage_moves")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("destination_storage_name=storage2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectRepositoryStorageMove::builder()
+            .project("simple/project")
+            .destination_storage_name("storage2")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,122 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Schedule moves of all project repositories on a storage to another storage.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreateAllProjectRepositoryStorageMoves<'a> {
+    /// The name of the storage to move repositories from.
+    #[builder(setter(into))]
+    source_storage_name: Cow<'a, str>,
+
+    /// The name of the storage to move the repositories to.
+    ///
+    /// If not given, GitLab chooses storages based on the storage weights of the instance.
+    #[builder(setter(into), default)]
+    destination_storage_name: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreateAllProjectRepositoryStorageMoves<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateAllProjectRepositoryStorageMovesBuilder<'a> {
+        CreateAllProjectRepositoryStorageMovesBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateAllProjectRepositoryStorageMoves<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "project_repository_storage_moves".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("source_storage_name", &self.source_storage_name)
+            .push_opt(
+                "destination_storage_name",
+                self.destination_storage_name.as_ref(),
+            );
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::repository_storage_moves::{
+        CreateAllProjectRepositoryStorageMoves, CreateAllProjectRepositoryStorageMovesBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn source_storage_name_is_needed() {
+        let err = CreateAllProjectRepositoryStorageMoves::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateAllProjectRepositoryStorageMovesBuilderError,
+            "source_storage_name",
+        );
+    }
+
+    #[test]
+    fn source_storage_name_is_sufficient() {
+        CreateAllProjectRepositoryStorageMoves::builder()
+            .source_storage_name("default")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("project_repository_storage_moves")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("source_storage_name=default")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateAllProjectRepositoryStorageMoves::builder()
+            .source_storage_name("default")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_destination_storage_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("project_repository_storage_moves")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("source_storage_name=default&destination_storage_name=storage2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateAllProjectRepositoryStorageMoves::builder()
+            .source_storage_name("default")
+            .destination_storage_name("storage2")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,111 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for a repository storage move of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ProjectRepositoryStorageMove<'a> {
+    /// The project of the repository storage move.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the repository storage move.
+    storage_move: u64,
+}
+
+impl<'a> ProjectRepositoryStorageMove<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectRepositoryStorageMoveBuilder<'a> {
+        ProjectRepositoryStorageMoveBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectRepositoryStorageMove<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/repository_storage_moves/{}",
+            self.project, self.storage_move,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::repository_storage_moves::{
+        ProjectRepositoryStorageMove, ProjectRepositoryStorageMoveBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_storage_move_are_needed() {
+        let err = ProjectRepositoryStorageMove::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ProjectRepositoryStorageMoveBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectRepositoryStorageMove::builder()
+            .storage_move(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ProjectRepositoryStorageMoveBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn storage_move_is_needed() {
+        let err = ProjectRepositoryStorageMove::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ProjectRepositoryStorageMoveBuilderError,
+            "storage_move",
+        );
+    }
+
+    #[test]
+    fn project_and_storage_move_are_sufficient() {
+        ProjectRepositoryStorageMove::builder()
+            .project(1)
+            .storage_move(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository_storage_moves/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectRepositoryStorageMove::builder()
+            .project("simple/project")
+            .storage_move(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
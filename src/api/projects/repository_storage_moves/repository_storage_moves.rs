@@ -0,0 +1,81 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for repository storage moves of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct ProjectRepositoryStorageMoves<'a> {
+    /// The project to query for repository storage moves.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ProjectRepositoryStorageMoves<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectRepositoryStorageMovesBuilder<'a> {
+        ProjectRepositoryStorageMovesBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ProjectRepositoryStorageMoves<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/repository_storage_moves", self.project).into()
+    }
+}
+
+impl<'a> Pageable for ProjectRepositoryStorageMoves<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::repository_storage_moves::{
+        ProjectRepositoryStorageMoves, ProjectRepositoryStorageMovesBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectRepositoryStorageMoves::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ProjectRepositoryStorageMovesBuilderError,
+            "project",
+        );
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectRepositoryStorageMoves::builder()
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository_storage_moves")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectRepositoryStorageMoves::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
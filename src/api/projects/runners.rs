@@ -13,6 +13,7 @@
 
 mod disable;
 mod enable;
+mod reset_registration_token;
 mod runners;
 
 pub use self::disable::DisableProjectRunner;
@@ -23,6 +24,10 @@ pub use self::enable::EnableProjectRunner;
 pub use self::enable::EnableProjectRunnerBuilder;
 pub use self::enable::EnableProjectRunnerBuilderError;
 
+pub use self::reset_registration_token::ResetProjectRunnerRegistrationToken;
+pub use self::reset_registration_token::ResetProjectRunnerRegistrationTokenBuilder;
+pub use self::reset_registration_token::ResetProjectRunnerRegistrationTokenBuilderError;
+
 pub use self::runners::ProjectRunners;
 pub use self::runners::ProjectRunnersBuilder;
 pub use self::runners::ProjectRunnersBuilderError;
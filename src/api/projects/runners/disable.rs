@@ -6,25 +6,27 @@
 
 use derive_builder::Builder;
 
+use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
 
 /// Disable a runner on a project.
 #[derive(Debug, Builder, Clone)]
-pub struct DisableProjectRunner {
+pub struct DisableProjectRunner<'a> {
     /// The ID or URL-encoded path of the project.
-    project: u64,
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
     /// The ID of the runner.
     runner: u64,
 }
 
-impl DisableProjectRunner {
+impl<'a> DisableProjectRunner<'a> {
     /// Create a builder for the endpoint.
-    pub fn builder() -> DisableProjectRunnerBuilder {
+    pub fn builder() -> DisableProjectRunnerBuilder<'a> {
         DisableProjectRunnerBuilder::default()
     }
 }
 
-impl Endpoint for DisableProjectRunner {
+impl<'a> Endpoint for DisableProjectRunner<'a> {
     fn method(&self) -> Method {
         Method::DELETE
     }
@@ -91,4 +93,21 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_project_path() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/runners/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DisableProjectRunner::builder()
+            .project("simple/project")
+            .runner(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
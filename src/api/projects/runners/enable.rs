@@ -6,25 +6,27 @@
 
 use derive_builder::Builder;
 
+use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
 
 /// Enable a runner on a project.
 #[derive(Debug, Builder, Clone)]
-pub struct EnableProjectRunner {
+pub struct EnableProjectRunner<'a> {
     /// The ID or URL-encoded path of the project.
-    project: u64,
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
     /// The ID of the runner.
     runner: u64,
 }
 
-impl EnableProjectRunner {
+impl<'a> EnableProjectRunner<'a> {
     /// Create a builder for the endpoint.
-    pub fn builder() -> EnableProjectRunnerBuilder {
+    pub fn builder() -> EnableProjectRunnerBuilder<'a> {
         EnableProjectRunnerBuilder::default()
     }
 }
 
-impl Endpoint for EnableProjectRunner {
+impl<'a> Endpoint for EnableProjectRunner<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
@@ -101,4 +103,23 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_project_path() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/runners")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("runner_id=2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EnableProjectRunner::builder()
+            .project("simple/project")
+            .runner(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
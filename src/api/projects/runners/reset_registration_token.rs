@@ -0,0 +1,84 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Reset the runner registration token of a project.
+///
+/// The response contains the new token.
+#[derive(Debug, Builder, Clone)]
+pub struct ResetProjectRunnerRegistrationToken<'a> {
+    /// The project to reset the registration token of.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ResetProjectRunnerRegistrationToken<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ResetProjectRunnerRegistrationTokenBuilder<'a> {
+        ResetProjectRunnerRegistrationTokenBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for ResetProjectRunnerRegistrationToken<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/runners/reset_registration_token", self.project).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::runners::{
+        ResetProjectRunnerRegistrationToken, ResetProjectRunnerRegistrationTokenBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ResetProjectRunnerRegistrationToken::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ResetProjectRunnerRegistrationTokenBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ResetProjectRunnerRegistrationToken::builder()
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/runners/reset_registration_token")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ResetProjectRunnerRegistrationToken::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
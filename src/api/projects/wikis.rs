@@ -0,0 +1,36 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project wiki API endpoints.
+//!
+//! These endpoints are used for querying and modifying the wiki pages of a project.
+
+mod create;
+mod delete;
+mod edit;
+mod page;
+mod pages;
+
+pub use self::create::CreateWikiPage;
+pub use self::create::CreateWikiPageBuilder;
+pub use self::create::CreateWikiPageBuilderError;
+pub use self::create::WikiPageFormat;
+
+pub use self::delete::DeleteWikiPage;
+pub use self::delete::DeleteWikiPageBuilder;
+pub use self::delete::DeleteWikiPageBuilderError;
+
+pub use self::edit::EditWikiPage;
+pub use self::edit::EditWikiPageBuilder;
+pub use self::edit::EditWikiPageBuilderError;
+
+pub use self::page::WikiPage;
+pub use self::page::WikiPageBuilder;
+pub use self::page::WikiPageBuilderError;
+
+pub use self::pages::WikiPages;
+pub use self::pages::WikiPagesBuilder;
+pub use self::pages::WikiPagesBuilderError;
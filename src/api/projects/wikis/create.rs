@@ -0,0 +1,199 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Markup formats for wiki pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WikiPageFormat {
+    /// Markdown.
+    Markdown,
+    /// RDoc.
+    RDoc,
+    /// AsciiDoc.
+    AsciiDoc,
+    /// Org mode.
+    Org,
+}
+
+impl WikiPageFormat {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            WikiPageFormat::Markdown => "markdown",
+            WikiPageFormat::RDoc => "rdoc",
+            WikiPageFormat::AsciiDoc => "asciidoc",
+            WikiPageFormat::Org => "org",
+        }
+    }
+}
+
+impl ParamValue<'static> for WikiPageFormat {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Create a new wiki page for a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreateWikiPage<'a> {
+    /// The project to create the wiki page within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The title of the wiki page.
+    #[builder(setter(into))]
+    title: Cow<'a, str>,
+    /// The content of the wiki page.
+    #[builder(setter(into))]
+    content: Cow<'a, str>,
+
+    /// The format of the wiki page.
+    #[builder(default)]
+    format: Option<WikiPageFormat>,
+}
+
+impl<'a> CreateWikiPage<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateWikiPageBuilder<'a> {
+        CreateWikiPageBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateWikiPage<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/wikis", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("title", &self.title)
+            .push("content", &self.content)
+            .push_opt("format", self.format);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::wikis::{CreateWikiPage, CreateWikiPageBuilderError, WikiPageFormat};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn wiki_page_format_as_str() {
+        let items = &[
+            (WikiPageFormat::Markdown, "markdown"),
+            (WikiPageFormat::RDoc, "rdoc"),
+            (WikiPageFormat::AsciiDoc, "asciidoc"),
+            (WikiPageFormat::Org, "org"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_title_and_content_are_needed() {
+        let err = CreateWikiPage::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateWikiPageBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateWikiPage::builder()
+            .title("title")
+            .content("content")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateWikiPageBuilderError, "project");
+    }
+
+    #[test]
+    fn title_is_needed() {
+        let err = CreateWikiPage::builder()
+            .project(1)
+            .content("content")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateWikiPageBuilderError, "title");
+    }
+
+    #[test]
+    fn content_is_needed() {
+        let err = CreateWikiPage::builder()
+            .project(1)
+            .title("title")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateWikiPageBuilderError, "content");
+    }
+
+    #[test]
+    fn project_title_and_content_are_sufficient() {
+        CreateWikiPage::builder()
+            .project(1)
+            .title("title")
+            .content("content")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/wikis")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("title=title&content=content")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateWikiPage::builder()
+            .project("simple/project")
+            .title("title")
+            .content("content")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_format() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/wikis")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("title=title&content=content&format=asciidoc")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateWikiPage::builder()
+            .project("simple/project")
+            .title("title")
+            .content("content")
+            .format(WikiPageFormat::AsciiDoc)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
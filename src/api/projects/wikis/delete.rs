@@ -0,0 +1,96 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a wiki page of a project.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteWikiPage<'a> {
+    /// The project of the wiki page.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The slug of the wiki page.
+    #[builder(setter(into))]
+    slug: Cow<'a, str>,
+}
+
+impl<'a> DeleteWikiPage<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteWikiPageBuilder<'a> {
+        DeleteWikiPageBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteWikiPage<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/wikis/{}",
+            self.project,
+            common::path_escaped(&self.slug),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::wikis::{DeleteWikiPage, DeleteWikiPageBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_slug_are_needed() {
+        let err = DeleteWikiPage::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteWikiPageBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteWikiPage::builder().slug("home").build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteWikiPageBuilderError, "project");
+    }
+
+    #[test]
+    fn slug_is_needed() {
+        let err = DeleteWikiPage::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteWikiPageBuilderError, "slug");
+    }
+
+    #[test]
+    fn project_and_slug_are_sufficient() {
+        DeleteWikiPage::builder()
+            .project(1)
+            .slug("home")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/wikis/docs%2Fsetup")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteWikiPage::builder()
+            .project("simple/project")
+            .slug("docs/setup")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,181 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::wikis::WikiPageFormat;
+
+/// Edit an existing wiki page of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditWikiPage<'a> {
+    /// The project of the wiki page.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The slug of the wiki page.
+    #[builder(setter(into))]
+    slug: Cow<'a, str>,
+
+    /// The new title of the wiki page.
+    #[builder(setter(into), default)]
+    title: Option<Cow<'a, str>>,
+    /// The new content of the wiki page.
+    #[builder(setter(into), default)]
+    content: Option<Cow<'a, str>>,
+    /// The new format of the wiki page.
+    #[builder(default)]
+    format: Option<WikiPageFormat>,
+}
+
+impl<'a> EditWikiPage<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditWikiPageBuilder<'a> {
+        EditWikiPageBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for EditWikiPage<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/wikis/{}",
+            self.project,
+            common::path_escaped(&self.slug),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("title", self.title.as_ref())
+            .push_opt("content", self.content.as_ref())
+            .push_opt("format", self.format);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::wikis::{EditWikiPage, EditWikiPageBuilderError, WikiPageFormat};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_slug_are_needed() {
+        let err = EditWikiPage::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditWikiPageBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditWikiPage::builder().slug("home").build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditWikiPageBuilderError, "project");
+    }
+
+    #[test]
+    fn slug_is_needed() {
+        let err = EditWikiPage::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditWikiPageBuilderError, "slug");
+    }
+
+    #[test]
+    fn project_and_slug_are_sufficient() {
+        EditWikiPage::builder()
+            .project(1)
+            .slug("home")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/wikis/docs%2Fsetup")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditWikiPage::builder()
+            .project("simple/project")
+            .slug("docs/setup")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_title() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/wikis/home")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("title=title")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditWikiPage::builder()
+            .project("simple/project")
+            .slug("home")
+            .title("title")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_content() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/wikis/home")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("content=content")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditWikiPage::builder()
+            .project("simple/project")
+            .slug("home")
+            .content("content")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_format() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/wikis/home")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("format=rdoc")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditWikiPage::builder()
+            .project("simple/project")
+            .slug("home")
+            .format(WikiPageFormat::RDoc)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,159 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a wiki page of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct WikiPage<'a> {
+    /// The project to query for the wiki page.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The slug of the wiki page.
+    #[builder(setter(into))]
+    slug: Cow<'a, str>,
+
+    /// Return the rendered HTML of the page.
+    #[builder(default)]
+    render_html: Option<bool>,
+    /// The version of the page to return.
+    #[builder(setter(into), default)]
+    version: Option<Cow<'a, str>>,
+}
+
+impl<'a> WikiPage<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> WikiPageBuilder<'a> {
+        WikiPageBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for WikiPage<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/wikis/{}",
+            self.project,
+            common::path_escaped(&self.slug),
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("render_html", self.render_html)
+            .push_opt("version", self.version.as_ref());
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::wikis::{WikiPage, WikiPageBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_slug_are_needed() {
+        let err = WikiPage::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, WikiPageBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = WikiPage::builder().slug("home").build().unwrap_err();
+        crate::test::assert_missing_field!(err, WikiPageBuilderError, "project");
+    }
+
+    #[test]
+    fn slug_is_needed() {
+        let err = WikiPage::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, WikiPageBuilderError, "slug");
+    }
+
+    #[test]
+    fn project_and_slug_are_sufficient() {
+        WikiPage::builder().project(1).slug("home").build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/wikis/home")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = WikiPage::builder()
+            .project("simple/project")
+            .slug("home")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_nested_slug() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/wikis/docs%2Fsetup%20guide")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = WikiPage::builder()
+            .project("simple/project")
+            .slug("docs/setup guide")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_render_html() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/wikis/home")
+            .add_query_params(&[("render_html", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = WikiPage::builder()
+            .project("simple/project")
+            .slug("home")
+            .render_html(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_version() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/wikis/home")
+            .add_query_params(&[("version", "0000000000000000000000000000000000000000")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = WikiPage::builder()
+            .project("simple/project")
+            .slug("home")
+            .version("0000000000000000000000000000000000000000")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -49,9 +49,9 @@ where
             let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
                 v
             } else {
-                return Err(ApiError::server_error(status, rsp.body()));
+                return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
             };
-            return Err(ApiError::from_gitlab(v));
+            return Err(ApiError::from_gitlab(v, rsp.headers()));
         } else if status == http::StatusCode::MOVED_PERMANENTLY {
             return Err(ApiError::moved_permanently(
                 rsp.headers().get(http::header::LOCATION),
@@ -90,9 +90,9 @@ where
             let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
                 v
             } else {
-                return Err(ApiError::server_error(status, rsp.body()));
+                return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
             };
-            return Err(ApiError::from_gitlab(v));
+            return Err(ApiError::from_gitlab(v, rsp.headers()));
         } else if status == http::StatusCode::MOVED_PERMANENTLY {
             return Err(ApiError::moved_permanently(
                 rsp.headers().get(http::header::LOCATION),
@@ -178,7 +178,7 @@ mod tests {
 
         let err = api::raw(Dummy).query(&client).unwrap_err();
         if let ApiError::Gitlab {
-            msg,
+            msg, ..
         } = err
         {
             assert_eq!(msg, "dummy error message");
@@ -203,7 +203,7 @@ mod tests {
 
         let err = api::raw(Dummy).query(&client).unwrap_err();
         if let ApiError::Gitlab {
-            msg,
+            msg, ..
         } = err
         {
             assert_eq!(msg, "dummy error message");
@@ -226,7 +226,7 @@ mod tests {
 
         let err = api::raw(Dummy).query(&client).unwrap_err();
         if let ApiError::GitlabUnrecognized {
-            obj,
+            obj, ..
         } = err
         {
             assert_eq!(obj, err_obj);
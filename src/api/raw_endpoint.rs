@@ -0,0 +1,252 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// An endpoint for an arbitrary API path.
+///
+/// This may be used to call endpoints which are not yet supported by this crate. The path is
+/// used as-is, so any components in it must already be escaped as necessary.
+///
+/// Note that the endpoint is always considered to be pageable; it is up to the caller to only use
+/// `api::paged` with endpoints which actually support pagination.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct RawEndpoint<'a> {
+    /// The HTTP method to use.
+    method: Method,
+    /// The path of the endpoint.
+    #[builder(setter(into))]
+    path: Cow<'a, str>,
+
+    /// The URL base of the endpoint.
+    #[builder(default = "UrlBase::ApiV4")]
+    url_base: UrlBase,
+    /// Query parameters for the endpoint.
+    #[builder(setter(name = "_params"), default, private)]
+    params: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    /// The body of the endpoint and its content type.
+    #[builder(setter(name = "_body"), default, private)]
+    body: Option<(&'static str, Vec<u8>)>,
+    /// Whether the endpoint uses keyset pagination or not.
+    #[builder(default)]
+    keyset_pagination: bool,
+}
+
+impl<'a> RawEndpoint<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RawEndpointBuilder<'a> {
+        RawEndpointBuilder::default()
+    }
+}
+
+impl<'a> RawEndpointBuilder<'a> {
+    /// Add a query parameter.
+    pub fn param<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.params
+            .get_or_insert_with(Vec::new)
+            .push((key.into(), value.into()));
+        self
+    }
+
+    /// Add multiple query parameters.
+    pub fn params<I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = (K, V)>,
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.params
+            .get_or_insert_with(Vec::new)
+            .extend(iter.map(|(key, value)| (key.into(), value.into())));
+        self
+    }
+
+    /// Set the body of the request.
+    pub fn body<D>(&mut self, content_type: &'static str, data: D) -> &mut Self
+    where
+        D: Into<Vec<u8>>,
+    {
+        self.body = Some(Some((content_type, data.into())));
+        self
+    }
+}
+
+impl<'a> Endpoint for RawEndpoint<'a> {
+    fn method(&self) -> Method {
+        self.method.clone()
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.path.to_string().into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        self.url_base
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.extend(
+            self.params
+                .iter()
+                .map(|(key, value)| (key.as_ref(), value.as_ref())),
+        );
+
+        params
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        Ok(self.body.clone())
+    }
+}
+
+impl<'a> Pageable for RawEndpoint<'a> {
+    fn use_keyset_pagination(&self) -> bool {
+        self.keyset_pagination
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::{
+        self, Endpoint, Pageable, Query, RawEndpoint, RawEndpointBuilderError, UrlBase,
+    };
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn method_and_path_are_needed() {
+        let err = RawEndpoint::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RawEndpointBuilderError, "method");
+    }
+
+    #[test]
+    fn method_is_needed() {
+        let err = RawEndpoint::builder().path("version").build().unwrap_err();
+        crate::test::assert_missing_field!(err, RawEndpointBuilderError, "method");
+    }
+
+    #[test]
+    fn path_is_needed() {
+        let err = RawEndpoint::builder()
+            .method(Method::GET)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RawEndpointBuilderError, "path");
+    }
+
+    #[test]
+    fn method_and_path_are_sufficient() {
+        RawEndpoint::builder()
+            .method(Method::GET)
+            .path("version")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn defaults() {
+        let endpoint = RawEndpoint::builder()
+            .method(Method::GET)
+            .path("version")
+            .build()
+            .unwrap();
+        assert_eq!(endpoint.url_base(), UrlBase::ApiV4);
+        assert!(!endpoint.use_keyset_pagination());
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/unsupported")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RawEndpoint::builder()
+            .method(Method::GET)
+            .path("projects/simple%2Fproject/unsupported")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_params() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/unsupported")
+            .add_query_params(&[("scope", "all"), ("ids[]", "1"), ("ids[]", "2")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RawEndpoint::builder()
+            .method(Method::GET)
+            .path("projects/simple%2Fproject/unsupported")
+            .param("scope", "all")
+            .params([("ids[]", "1"), ("ids[]", "2")].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_body() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/unsupported")
+            .content_type("application/json")
+            .body_str(r#"{"key":"value"}"#)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RawEndpoint::builder()
+            .method(Method::POST)
+            .path("projects/simple%2Fproject/unsupported")
+            .body("application/json", r#"{"key":"value"}"#)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_keyset_pagination() {
+        let endpoint = RawEndpoint::builder()
+            .method(Method::GET)
+            .path("projects")
+            .keyset_pagination(true)
+            .build()
+            .unwrap();
+        assert!(endpoint.use_keyset_pagination());
+    }
+
+    #[test]
+    fn endpoint_raw_data() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/unsupported")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "raw data");
+
+        let endpoint = RawEndpoint::builder()
+            .method(Method::GET)
+            .path("projects/simple%2Fproject/unsupported")
+            .build()
+            .unwrap();
+        let data = api::raw(endpoint).query(&client).unwrap();
+        assert_eq!(data, b"raw data");
+    }
+}
@@ -291,6 +291,7 @@ mod test {
                     Err(api::ApiError::GitlabService {
                         status: StatusCode::INTERNAL_SERVER_ERROR,
                         data: Vec::default(),
+                        request_id: None,
                     })
                 }
             })
@@ -332,6 +333,7 @@ mod test {
                 Err(api::ApiError::GitlabService {
                     status: StatusCode::INTERNAL_SERVER_ERROR,
                     data: Vec::default(),
+                    request_id: None,
                 })
             })
             .unwrap_err();
@@ -397,7 +399,7 @@ mod test {
         let res: Result<DummyResult, _> = Dummy.query(&client);
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
-            msg,
+            msg, ..
         } = err
         {
             assert_eq!(msg, "dummy error message");
@@ -20,6 +20,7 @@ mod reset_authentication_token;
 mod reset_authentication_token_by_token;
 mod runner;
 mod runners;
+mod token_validity;
 mod verify;
 
 const MAX_MAINTENANCE_NOTE_LENGTH: usize = 1024;
@@ -69,6 +70,8 @@ pub use self::runners::Runners;
 pub use self::runners::RunnersBuilder;
 pub use self::runners::RunnersBuilderError;
 
+pub use self::token_validity::RunnerTokenValidity;
+
 pub use self::verify::VerifyRunner;
 pub use self::verify::VerifyRunnerBuilder;
 pub use self::verify::VerifyRunnerBuilderError;
@@ -12,7 +12,8 @@ use crate::api::runners::{RunnerStatus, RunnerType};
 
 /// Query for all runners on an instance.
 ///
-/// Note that this endpoint requires administrator privileges.
+/// Note that this endpoint requires administrator privileges. Details such as the version and
+/// platform of a runner may be queried using the `Runner` endpoint.
 #[derive(Debug, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct AllRunners<'a> {
@@ -192,4 +193,21 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_paused_and_tag() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners/all")
+            .add_query_params(&[("paused", "false"), ("tag_list", "docker")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AllRunners::builder()
+            .paused(false)
+            .tag("docker")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
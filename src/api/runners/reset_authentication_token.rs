@@ -9,6 +9,8 @@ use derive_builder::Builder;
 use crate::api::endpoint_prelude::*;
 
 /// Reset a runner's authentication token.
+///
+/// The response contains the new token.
 #[derive(Debug, Builder, Clone)]
 pub struct ResetRunnerAuthenticationToken {
     /// The ID of the runner.
@@ -0,0 +1,181 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{header, Request, Response, StatusCode};
+
+use crate::api::runners::VerifyRunner;
+use crate::api::{query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, RestClient};
+
+/// Query whether a runner token is valid.
+///
+/// The token is checked using the `VerifyRunner` endpoint. GitLab rejects invalid tokens with
+/// `403 Forbidden`; this results in `false` rather than an error.
+#[derive(Debug, Clone)]
+pub struct RunnerTokenValidity<'a> {
+    endpoint: VerifyRunner<'a>,
+}
+
+impl<'a> RunnerTokenValidity<'a> {
+    /// Check the token used by a `VerifyRunner` endpoint.
+    pub fn new(endpoint: VerifyRunner<'a>) -> Self {
+        Self {
+            endpoint,
+        }
+    }
+
+    fn request<C>(
+        &self,
+        client: &C,
+    ) -> Result<(http::request::Builder, Vec<u8>), ApiError<C::Error>>
+    where
+        C: RestClient,
+    {
+        let mut url = self
+            .endpoint
+            .url_base()
+            .endpoint_for(client, &self.endpoint.endpoint())?;
+        self.endpoint.parameters().add_to_url(&mut url);
+
+        let req = Request::builder()
+            .method(self.endpoint.method())
+            .uri(query::url_to_http_uri(url));
+        Ok(if let Some((mime, data)) = self.endpoint.body()? {
+            let req = req.header(header::CONTENT_TYPE, mime);
+            (req, data)
+        } else {
+            (req, Vec::new())
+        })
+    }
+}
+
+fn handle_response<E>(rsp: Response<Bytes>) -> Result<bool, ApiError<E>>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let status = rsp.status();
+    if status == StatusCode::FORBIDDEN {
+        return Ok(false);
+    }
+
+    if !status.is_success() {
+        let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
+            v
+        } else {
+            return Err(ApiError::server_error(status, rsp.headers(), rsp.body()));
+        };
+        return Err(ApiError::from_gitlab(v, rsp.headers()));
+    }
+
+    Ok(true)
+}
+
+impl<'a, C> Query<bool, C> for RunnerTokenValidity<'a>
+where
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<bool, ApiError<C::Error>> {
+        let (req, data) = self.request(client)?;
+        let rsp = client.rest(req, data)?;
+        handle_response(rsp)
+    }
+}
+
+#[async_trait]
+impl<'a, C> AsyncQuery<bool, C> for RunnerTokenValidity<'a>
+where
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<bool, ApiError<C::Error>> {
+        let (req, data) = self.request(client)?;
+        let rsp = client.rest_async(req, data).await?;
+        handle_response(rsp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{Method, StatusCode};
+    use serde_json::json;
+
+    use crate::api::runners::{RunnerTokenValidity, VerifyRunner};
+    use crate::api::{ApiError, AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    fn expected_url(status: StatusCode) -> ExpectedUrl {
+        ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("runners/verify")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("token=tok")
+            .status(status)
+            .build()
+            .unwrap()
+    }
+
+    fn query() -> RunnerTokenValidity<'static> {
+        RunnerTokenValidity::new(VerifyRunner::builder().token("tok").build().unwrap())
+    }
+
+    #[test]
+    fn valid_token() {
+        let client = SingleTestClient::new_json(
+            expected_url(StatusCode::OK),
+            &json!({
+                "id": 1,
+                "token": "tok",
+                "token_expires_at": null,
+            }),
+        );
+
+        assert!(query().query(&client).unwrap());
+    }
+
+    #[test]
+    fn invalid_token() {
+        let client = SingleTestClient::new_json(
+            expected_url(StatusCode::FORBIDDEN),
+            &json!({
+                "message": "403 Forbidden",
+            }),
+        );
+
+        assert!(!query().query(&client).unwrap());
+    }
+
+    #[test]
+    fn other_errors() {
+        let client = SingleTestClient::new_json(
+            expected_url(StatusCode::GONE),
+            &json!({
+                "message": "410 Gone",
+            }),
+        );
+
+        let err = query().query(&client).unwrap_err();
+        if let ApiError::Gitlab {
+            msg, ..
+        } = err
+        {
+            assert_eq!(msg, "410 Gone");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn invalid_token_async() {
+        let client = SingleTestClient::new_json(
+            expected_url(StatusCode::FORBIDDEN),
+            &json!({
+                "message": "403 Forbidden",
+            }),
+        );
+
+        assert!(!query().query_async(&client).await.unwrap());
+    }
+}
@@ -195,7 +195,7 @@ mod tests {
         let res: Result<DummyResult, _> = api::sudo(Dummy, "user").query(&client);
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
-            msg,
+            msg, ..
         } = err
         {
             assert_eq!(msg, "dummy error message");
@@ -222,7 +222,7 @@ mod tests {
         let res: Result<DummyResult, _> = api::sudo(Dummy, "user").query(&client);
         let err = res.unwrap_err();
         if let ApiError::Gitlab {
-            msg,
+            msg, ..
         } = err
         {
             assert_eq!(msg, "dummy error message");
@@ -247,7 +247,7 @@ mod tests {
         let res: Result<DummyResult, _> = api::sudo(Dummy, "user").query(&client);
         let err = res.unwrap_err();
         if let ApiError::GitlabUnrecognized {
-            obj,
+            obj, ..
         } = err
         {
             assert_eq!(obj, err_obj);
@@ -0,0 +1,30 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! To-do item API endpoints.
+//!
+//! These endpoints are used for querying and acting on the to-do items of the current user.
+
+mod mark_all_done;
+mod mark_done;
+mod todos;
+
+pub use self::mark_all_done::MarkAllTodosDone;
+pub use self::mark_all_done::MarkAllTodosDoneBuilder;
+pub use self::mark_all_done::MarkAllTodosDoneBuilderError;
+
+pub use self::mark_done::MarkTodoDone;
+pub use self::mark_done::MarkTodoDoneBuilder;
+pub use self::mark_done::MarkTodoDoneBuilderError;
+
+pub use self::todos::TodoAction;
+pub use self::todos::TodoState;
+pub use self::todos::TodoType;
+pub use self::todos::Todos;
+pub use self::todos::TodosBuilder;
+pub use self::todos::TodosBuilderError;
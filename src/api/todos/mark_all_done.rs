@@ -0,0 +1,57 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Mark all pending to-do items of the current user as done.
+#[derive(Debug, Builder, Clone)]
+pub struct MarkAllTodosDone {}
+
+impl MarkAllTodosDone {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MarkAllTodosDoneBuilder {
+        MarkAllTodosDoneBuilder::default()
+    }
+}
+
+impl Endpoint for MarkAllTodosDone {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "todos/mark_as_done".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::todos::MarkAllTodosDone;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn default_is_sufficient() {
+        MarkAllTodosDone::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("todos/mark_as_done")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MarkAllTodosDone::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,66 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Mark a to-do item of the current user as done.
+#[derive(Debug, Builder, Clone)]
+pub struct MarkTodoDone {
+    /// The ID of the to-do item.
+    todo: u64,
+}
+
+impl MarkTodoDone {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MarkTodoDoneBuilder {
+        MarkTodoDoneBuilder::default()
+    }
+}
+
+impl Endpoint for MarkTodoDone {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("todos/{}/mark_as_done", self.todo).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::todos::{MarkTodoDone, MarkTodoDoneBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn todo_is_needed() {
+        let err = MarkTodoDone::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, MarkTodoDoneBuilderError, "todo");
+    }
+
+    #[test]
+    fn todo_is_sufficient() {
+        MarkTodoDone::builder().todo(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("todos/1/mark_as_done")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MarkTodoDone::builder().todo(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
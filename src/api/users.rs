@@ -12,9 +12,12 @@
 
 mod create;
 mod current_user;
+pub mod custom_attributes;
+mod events;
 pub mod impersonation_tokens;
 pub mod personal_access_tokens;
 mod projects;
+mod starred_projects;
 mod user;
 mod users;
 
@@ -23,11 +26,19 @@ pub use self::create::CreateUserBuilder;
 pub use self::create::CreateUserBuilderError;
 pub use self::create::NewUserPassword;
 
+pub use self::events::UserEvents;
+pub use self::events::UserEventsBuilder;
+pub use self::events::UserEventsBuilderError;
+
 pub use self::projects::UserProjects;
 pub use self::projects::UserProjectsBuilder;
 pub use self::projects::UserProjectsBuilderError;
 pub use self::projects::UserProjectsOrderBy;
 
+pub use self::starred_projects::UserStarredProjects;
+pub use self::starred_projects::UserStarredProjectsBuilder;
+pub use self::starred_projects::UserStarredProjectsBuilderError;
+
 pub use self::user::User;
 pub use self::user::UserBuilder;
 pub use self::user::UserBuilderError;
@@ -0,0 +1,31 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! User custom attribute API endpoints.
+//!
+//! These endpoints are used for querying and modifying custom attributes on users. Custom
+//! attributes are key/value pairs which may only be managed by administrators.
+
+mod custom_attribute;
+mod custom_attributes;
+mod delete;
+mod set;
+
+pub use self::custom_attribute::UserCustomAttribute;
+pub use self::custom_attribute::UserCustomAttributeBuilder;
+pub use self::custom_attribute::UserCustomAttributeBuilderError;
+
+pub use self::custom_attributes::UserCustomAttributes;
+pub use self::custom_attributes::UserCustomAttributesBuilder;
+pub use self::custom_attributes::UserCustomAttributesBuilderError;
+
+pub use self::delete::DeleteUserCustomAttribute;
+pub use self::delete::DeleteUserCustomAttributeBuilder;
+pub use self::delete::DeleteUserCustomAttributeBuilderError;
+
+pub use self::set::SetUserCustomAttribute;
+pub use self::set::SetUserCustomAttributeBuilder;
+pub use self::set::SetUserCustomAttributeBuilderError;
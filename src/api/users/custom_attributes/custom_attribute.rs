@@ -0,0 +1,113 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common;
+use crate::api::endpoint_prelude::*;
+
+/// Query for a custom attribute on a user.
+#[derive(Debug, Builder, Clone)]
+pub struct UserCustomAttribute<'a> {
+    /// The user to query for the custom attribute.
+    user: u64,
+    /// The key of the custom attribute.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+}
+
+impl<'a> UserCustomAttribute<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserCustomAttributeBuilder<'a> {
+        UserCustomAttributeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for UserCustomAttribute<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "users/{}/custom_attributes/{}",
+            self.user,
+            common::path_escaped(&self.key),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::custom_attributes::{
+        UserCustomAttribute, UserCustomAttributeBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_and_key_are_necessary() {
+        let err = UserCustomAttribute::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserCustomAttributeBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_necessary() {
+        let err = UserCustomAttribute::builder()
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UserCustomAttributeBuilderError, "user");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = UserCustomAttribute::builder().user(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserCustomAttributeBuilderError, "key");
+    }
+
+    #[test]
+    fn user_and_key_are_sufficient() {
+        UserCustomAttribute::builder()
+            .user(1)
+            .key("key")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/custom_attributes/key")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserCustomAttribute::builder()
+            .user(1)
+            .key("key")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_key_escaped() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/custom_attributes/external%2Fid")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserCustomAttribute::builder()
+            .user(1)
+            .key("external/id")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
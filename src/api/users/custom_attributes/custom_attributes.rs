@@ -0,0 +1,65 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for custom attributes on a user.
+#[derive(Debug, Builder, Clone)]
+pub struct UserCustomAttributes {
+    /// The user to query for custom attributes.
+    user: u64,
+}
+
+impl UserCustomAttributes {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserCustomAttributesBuilder {
+        UserCustomAttributesBuilder::default()
+    }
+}
+
+impl Endpoint for UserCustomAttributes {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/custom_attributes", self.user).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::custom_attributes::{
+        UserCustomAttributes, UserCustomAttributesBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = UserCustomAttributes::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserCustomAttributesBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        UserCustomAttributes::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/custom_attributes")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserCustomAttributes::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,150 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common;
+use crate::api::endpoint_prelude::*;
+
+/// Set a custom attribute on a user.
+#[derive(Debug, Builder, Clone)]
+pub struct SetUserCustomAttribute<'a> {
+    /// The user to set the custom attribute on.
+    user: u64,
+    /// The key of the custom attribute.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+    /// The value of the custom attribute.
+    #[builder(setter(into))]
+    value: Cow<'a, str>,
+}
+
+impl<'a> SetUserCustomAttribute<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SetUserCustomAttributeBuilder<'a> {
+        SetUserCustomAttributeBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for SetUserCustomAttribute<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "users/{}/custom_attributes/{}",
+            self.user,
+            common::path_escaped(&self.key),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("value", &self.value);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::custom_attributes::{
+        SetUserCustomAttribute, SetUserCustomAttributeBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_key_and_value_are_necessary() {
+        let err = SetUserCustomAttribute::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SetUserCustomAttributeBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_necessary() {
+        let err = SetUserCustomAttribute::builder()
+            .key("key")
+            .value("value")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetUserCustomAttributeBuilderError, "user");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = SetUserCustomAttribute::builder()
+            .user(1)
+            .value("value")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetUserCustomAttributeBuilderError, "key");
+    }
+
+    #[test]
+    fn value_is_necessary() {
+        let err = SetUserCustomAttribute::builder()
+            .user(1)
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetUserCustomAttributeBuilderError, "value");
+    }
+
+    #[test]
+    fn user_key_and_value_are_sufficient() {
+        SetUserCustomAttribute::builder()
+            .user(1)
+            .key("key")
+            .value("value")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1/custom_attributes/key")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("value=value")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetUserCustomAttribute::builder()
+            .user(1)
+            .key("key")
+            .value("value")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_key_escaped() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1/custom_attributes/external%2Fid")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("value=value")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetUserCustomAttribute::builder()
+            .user(1)
+            .key("external/id")
+            .value("value")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
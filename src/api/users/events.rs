@@ -0,0 +1,195 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! User events API endpoint.
+//!
+//! This endpoint is used for querying the activity of a user.
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{NameOrId, SortOrder};
+use crate::api::endpoint_prelude::*;
+use crate::api::events::{EventAction, EventTargetType};
+
+/// Query for events of a user.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct UserEvents<'a> {
+    /// The user to query for events.
+    #[builder(setter(into))]
+    user: NameOrId<'a>,
+
+    /// Filter events by action.
+    #[builder(default)]
+    action: Option<EventAction>,
+    /// Filter events by target type.
+    #[builder(default)]
+    target_type: Option<EventTargetType>,
+    /// Filter events created before a date.
+    #[builder(default)]
+    before: Option<NaiveDate>,
+    /// Filter events created after a date.
+    #[builder(default)]
+    after: Option<NaiveDate>,
+    /// The sort order for returned results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl<'a> UserEvents<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserEventsBuilder<'a> {
+        UserEventsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for UserEvents<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/events", self.user).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("action", self.action)
+            .push_opt("target_type", self.target_type)
+            .push_opt("before", self.before)
+            .push_opt("after", self.after)
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl<'a> Pageable for UserEvents<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::api::common::SortOrder;
+    use crate::api::events::{EventAction, EventTargetType};
+    use crate::api::users::{UserEvents, UserEventsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = UserEvents::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserEventsBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        UserEvents::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/user/events")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserEvents::builder()
+            .user("user")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_action() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/events")
+            .add_query_params(&[("action", "merged")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserEvents::builder()
+            .user(1)
+            .action(EventAction::Merged)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_target_type() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/events")
+            .add_query_params(&[("target_type", "issue")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserEvents::builder()
+            .user(1)
+            .target_type(EventTargetType::Issue)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/events")
+            .add_query_params(&[("before", "2024-01-31")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserEvents::builder()
+            .user(1)
+            .before(NaiveDate::from_ymd_opt(2024, 1, 31).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/events")
+            .add_query_params(&[("after", "2024-01-01")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserEvents::builder()
+            .user(1)
+            .after(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/events")
+            .add_query_params(&[("sort", "asc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserEvents::builder()
+            .user(1)
+            .sort(SortOrder::Ascending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
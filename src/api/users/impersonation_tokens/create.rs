@@ -20,6 +20,20 @@ pub enum ImpersonationTokenScope {
     Api,
     /// Access to read the user information.
     ReadUser,
+    /// Access read-only API endpoints.
+    ReadApi,
+    /// Read access to repositories.
+    ReadRepository,
+    /// Write access to repositories.
+    WriteRepository,
+    /// Read access to Docker registries.
+    ReadRegistry,
+    /// Write access to Docker registries.
+    WriteRegistry,
+    /// Permission to `sudo` as other users.
+    Sudo,
+    /// Permission to access administrator API actions.
+    AdminMode,
 }
 
 impl ImpersonationTokenScope {
@@ -28,6 +42,13 @@ impl ImpersonationTokenScope {
         match self {
             ImpersonationTokenScope::Api => "api",
             ImpersonationTokenScope::ReadUser => "read_user",
+            ImpersonationTokenScope::ReadApi => "read_api",
+            ImpersonationTokenScope::ReadRepository => "read_repository",
+            ImpersonationTokenScope::WriteRepository => "write_repository",
+            ImpersonationTokenScope::ReadRegistry => "read_registry",
+            ImpersonationTokenScope::WriteRegistry => "write_registry",
+            ImpersonationTokenScope::Sudo => "sudo",
+            ImpersonationTokenScope::AdminMode => "admin_mode",
         }
     }
 }
@@ -118,6 +139,13 @@ mod tests {
         let items = &[
             (ImpersonationTokenScope::Api, "api"),
             (ImpersonationTokenScope::ReadUser, "read_user"),
+            (ImpersonationTokenScope::ReadApi, "read_api"),
+            (ImpersonationTokenScope::ReadRepository, "read_repository"),
+            (ImpersonationTokenScope::WriteRepository, "write_repository"),
+            (ImpersonationTokenScope::ReadRegistry, "read_registry"),
+            (ImpersonationTokenScope::WriteRegistry, "write_registry"),
+            (ImpersonationTokenScope::Sudo, "sudo"),
+            (ImpersonationTokenScope::AdminMode, "admin_mode"),
         ];
 
         for (i, s) in items {
@@ -215,4 +243,25 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_read_api() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users/1/impersonation_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=name", "&scopes%5B%5D=read_api"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateImpersonationToken::builder()
+            .user(1)
+            .name("name")
+            .scope(ImpersonationTokenScope::ReadApi)
+            .scope(ImpersonationTokenScope::ReadApi)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
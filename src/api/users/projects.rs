@@ -215,6 +215,18 @@ mod tests {
         api::ignore(endpoint).query(&client).unwrap();
     }
 
+    #[test]
+    fn endpoint_by_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/projects")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
     #[test]
     fn endpoint_archived() {
         let endpoint = ExpectedUrl::builder()
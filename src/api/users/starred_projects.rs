@@ -0,0 +1,355 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{AccessLevel, NameOrId, SortOrder, VisibilityLevel};
+use crate::api::endpoint_prelude::*;
+use crate::api::users::UserProjectsOrderBy;
+
+/// Query projects starred by a user.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct UserStarredProjects<'a> {
+    /// The user to query for starred projects.
+    #[builder(setter(into))]
+    user: NameOrId<'a>,
+
+    /// Limit by archived status.
+    #[builder(default)]
+    archived: Option<bool>,
+    /// Limit by visibility public, internal, or private
+    #[builder(default)]
+    visibility: Option<VisibilityLevel>,
+
+    /// Return projects ordered by keys.
+    #[builder(default)]
+    order_by: Option<UserProjectsOrderBy>,
+    /// Return projects sorted in asc or desc order.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+    /// Search for projects using a query string.
+    ///
+    /// The search query will be escaped automatically.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+
+    /// Return only the ID, URL, name, and path of each project.
+    #[builder(default)]
+    simple: Option<bool>,
+    /// Limit by projects owned by the current user.
+    #[builder(default)]
+    owned: Option<bool>,
+    /// Limit by projects starred by the current user.
+    #[builder(default)]
+    starred: Option<bool>,
+    /// Limit by projects with issues feature enabled.
+    #[builder(default)]
+    with_issues_enabled: Option<bool>,
+    /// Limit by projects with merge requests feature enabled.
+    #[builder(default)]
+    with_merge_requests_enabled: Option<bool>,
+    /// Limit to projects where current user has at least this access level.
+    #[builder(default)]
+    min_access_level: Option<AccessLevel>,
+    /// Include custom attributes in response (admins only).
+    #[builder(default)]
+    with_custom_attributes: Option<bool>,
+}
+
+impl<'a> UserStarredProjects<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserStarredProjectsBuilder<'a> {
+        UserStarredProjectsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for UserStarredProjects<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/starred_projects", self.user).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("archived", self.archived)
+            .push_opt("visibility", self.visibility)
+            .push_opt("order_by", self.order_by)
+            .push_opt("sort", self.sort)
+            .push_opt("search", self.search.as_ref())
+            .push_opt("simple", self.simple)
+            .push_opt("owned", self.owned)
+            .push_opt("starred", self.starred)
+            .push_opt("with_issues_enabled", self.with_issues_enabled)
+            .push_opt(
+                "with_merge_requests_enabled",
+                self.with_merge_requests_enabled,
+            )
+            .push_opt(
+                "min_access_level",
+                self.min_access_level.map(AccessLevel::as_u64),
+            )
+            .push_opt("with_custom_attributes", self.with_custom_attributes);
+
+        params
+    }
+}
+
+impl<'a> Pageable for UserStarredProjects<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::common::{AccessLevel, SortOrder, VisibilityLevel};
+    use crate::api::users::{
+        UserProjectsOrderBy, UserStarredProjects, UserStarredProjectsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = UserStarredProjects::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserStarredProjectsBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        UserStarredProjects::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/user/starred_projects")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStarredProjects::builder().user("user").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_by_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/starred_projects")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStarredProjects::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_archived() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/user/starred_projects")
+            .add_query_params(&[("archived", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStarredProjects::builder()
+            .user("user")
+            .archived(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_visibility() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/user/starred_projects")
+            .add_query_params(&[("visibility", "private")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStarredProjects::builder()
+            .user("user")
+            .visibility(VisibilityLevel::Private)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_order_by() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/user/starred_projects")
+            .add_query_params(&[("order_by", "id")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStarredProjects::builder()
+            .user("user")
+            .order_by(UserProjectsOrderBy::Id)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/user/starred_projects")
+            .add_query_params(&[("sort", "asc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStarredProjects::builder()
+            .user("user")
+            .sort(SortOrder::Ascending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/user/starred_projects")
+            .add_query_params(&[("search", "name")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStarredProjects::builder()
+            .user("user")
+            .search("name")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_simple() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/user/starred_projects")
+            .add_query_params(&[("simple", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStarredProjects::builder()
+            .user("user")
+            .simple(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_owned() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/user/starred_projects")
+            .add_query_params(&[("owned", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStarredProjects::builder()
+            .user("user")
+            .owned(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_starred() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/user/starred_projects")
+            .add_query_params(&[("starred", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStarredProjects::builder()
+            .user("user")
+            .starred(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_with_issues_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/user/starred_projects")
+            .add_query_params(&[("with_issues_enabled", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStarredProjects::builder()
+            .user("user")
+            .with_issues_enabled(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_with_merge_requests_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/user/starred_projects")
+            .add_query_params(&[("with_merge_requests_enabled", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStarredProjects::builder()
+            .user("user")
+            .with_merge_requests_enabled(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_min_access_level() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/user/starred_projects")
+            .add_query_params(&[("min_access_level", "30")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStarredProjects::builder()
+            .user("user")
+            .min_access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_with_custom_attributes() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/user/starred_projects")
+            .add_query_params(&[("with_custom_attributes", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStarredProjects::builder()
+            .user("user")
+            .with_custom_attributes(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
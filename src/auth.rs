@@ -32,6 +32,8 @@ type AuthResult<T> = Result<T, AuthError>;
 pub enum Auth {
     /// A personal access token, obtained through Gitlab user settings
     Token(String),
+    /// A personal access token sent as a bearer token in the Authorization header
+    BearerToken(String),
     /// A job token, obtained through a CI job.
     JobToken(String),
     /// An OAuth2 token, obtained through the OAuth2 flow
@@ -43,8 +45,8 @@ pub enum Auth {
 impl Auth {
     /// Adds the appropriate header to a set of headers.
     ///
-    /// Depending on the token type, this will be either the Private-Token header
-    /// or the Authorization header.
+    /// Depending on the token type, this will be either the Private-Token header,
+    /// the Job-Token header, or the Authorization header.
     ///
     /// Returns an error if the token string cannot be parsed as a header value.
     pub fn set_header<'a>(
@@ -62,7 +64,7 @@ impl Auth {
                 token_header_value.set_sensitive(true);
                 headers.insert("JOB-TOKEN", token_header_value);
             },
-            Auth::OAuth2(token) => {
+            Auth::OAuth2(token) | Auth::BearerToken(token) => {
                 let value = format!("Bearer {}", token);
                 let mut token_header_value = HeaderValue::from_str(&value)?;
                 token_header_value.set_sensitive(true);
@@ -74,6 +76,13 @@ impl Auth {
         Ok(headers)
     }
 
+    /// Whether a set of headers already carries authentication.
+    pub(crate) fn is_set(headers: &HeaderMap<HeaderValue>) -> bool {
+        headers.contains_key("PRIVATE-TOKEN")
+            || headers.contains_key("JOB-TOKEN")
+            || headers.contains_key(http::header::AUTHORIZATION)
+    }
+
     pub fn check_connection<C>(&self, api: &C) -> Result<(), api::ApiError<C::Error>>
     where
         C: api::Client,
@@ -86,7 +95,7 @@ impl Auth {
             Self::JobToken(_) => {
                 api::ignore(Job::builder().build().unwrap()).query(api)?;
             },
-            Self::Token(_) | Self::OAuth2(_) => {
+            Self::Token(_) | Self::BearerToken(_) | Self::OAuth2(_) => {
                 api::ignore(CurrentUser::builder().build().unwrap()).query(api)?;
             },
         }
@@ -108,7 +117,7 @@ impl Auth {
                     .query_async(api)
                     .await?;
             },
-            Self::Token(_) | Self::OAuth2(_) => {
+            Self::Token(_) | Self::BearerToken(_) | Self::OAuth2(_) => {
                 api::ignore(CurrentUser::builder().build().unwrap())
                     .query_async(api)
                     .await?;
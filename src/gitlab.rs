@@ -7,18 +7,21 @@
 use std::any;
 use std::convert::TryInto;
 use std::fmt::{self, Debug};
+use std::net::SocketAddr;
 
 use async_trait::async_trait;
 use bytes::Bytes;
 use graphql_client::{GraphQLQuery, QueryBody, Response};
 use http::{HeaderMap, Response as HttpResponse};
 use itertools::Itertools;
-use log::{debug, error, info};
+use log::{debug, error, info, trace};
 use reqwest::blocking::Client;
 use reqwest::{Certificate, Client as AsyncClient};
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use thiserror::Error;
+#[cfg(feature = "tracing")]
+use tracing::Instrument;
 use url::Url;
 
 #[cfg(any(feature = "client_der", feature = "client_pem"))]
@@ -26,6 +29,7 @@ use reqwest::Identity as TlsIdentity;
 
 use crate::api;
 use crate::auth::{Auth, AuthError};
+use crate::redact;
 
 #[derive(Debug, Clone)]
 pub enum RootCertificate<'a> {
@@ -57,6 +61,13 @@ pub enum GitlabError {
     #[allow(clippy::upper_case_acronyms)]
     #[error("graphql error: [\"{}\"]", message.iter().format("\", \""))]
     GraphQL { message: Vec<graphql_client::Error> },
+    #[allow(clippy::upper_case_acronyms)]
+    #[error(
+        "graphql batch of {} queries received {} responses",
+        expected,
+        actual
+    )]
+    GraphQLBatch { expected: usize, actual: usize },
     #[error("no response from gitlab")]
     NoResponse {},
     #[error("could not parse {} data from JSON: {}", typename, source)]
@@ -81,6 +92,13 @@ impl GitlabError {
         GitlabError::GraphQL { message }
     }
 
+    fn graphql_batch(expected: usize, actual: usize) -> Self {
+        GitlabError::GraphQLBatch {
+            expected,
+            actual,
+        }
+    }
+
     fn no_response() -> Self {
         GitlabError::NoResponse {}
     }
@@ -95,6 +113,139 @@ impl GitlabError {
 
 type GitlabResult<T> = Result<T, GitlabError>;
 
+/// Extract the data from a GraphQL response.
+fn graphql_result<T>(rsp: Response<T>) -> GitlabResult<T> {
+    if let Some(errs) = rsp.errors {
+        return Err(GitlabError::graphql(errs));
+    }
+    rsp.data.ok_or_else(GitlabError::no_response)
+}
+
+/// Split the response to a batch of GraphQL queries into the result of each query.
+fn graphql_batch_results<T>(
+    expected: usize,
+    rsps: Vec<Response<T>>,
+) -> GitlabResult<Vec<GitlabResult<T>>> {
+    if rsps.len() != expected {
+        return Err(GitlabError::graphql_batch(expected, rsps.len()));
+    }
+    Ok(rsps.into_iter().map(graphql_result).collect())
+}
+
+/// Create a span covering a request to GitLab.
+///
+/// The `status` field is recorded once a response has been received.
+#[cfg(feature = "tracing")]
+fn request_span(method: &http::Method, url: &dyn fmt::Display) -> tracing::Span {
+    tracing::info_span!(
+        target: "gitlab",
+        "request",
+        method = %method,
+        endpoint = %url,
+        status = tracing::field::Empty,
+    )
+}
+
+/// Create a span covering a REST request to GitLab.
+#[cfg(feature = "tracing")]
+fn rest_span(request: &http::request::Builder) -> tracing::Span {
+    let method = request.method_ref().cloned().unwrap_or_default();
+    let url = request
+        .uri_ref()
+        .map(ToString::to_string)
+        .unwrap_or_default();
+    request_span(&method, &url)
+}
+
+/// Log a REST request, including its body, with secrets redacted.
+fn log_request(request: &http::request::Builder, body: &[u8]) {
+    let method = request.method_ref().cloned().unwrap_or_default();
+    let url = request
+        .uri_ref()
+        .and_then(|uri| Url::parse(&uri.to_string()).ok())
+        .map(|url| redact::url(&url).to_string())
+        .unwrap_or_default();
+    let empty = HeaderMap::new();
+    let headers = request.headers_ref().unwrap_or(&empty);
+    trace!(
+        target: "gitlab",
+        "request: {} {} headers: {} body: {}",
+        method,
+        url,
+        redact::Headers(headers),
+        redact::body(headers, body),
+    );
+}
+
+/// Log a REST response, including its body, with secrets redacted.
+fn log_response(rsp: &HttpResponse<Bytes>) {
+    trace!(
+        target: "gitlab",
+        "response: {} headers: {} body: {}",
+        rsp.status(),
+        redact::Headers(rsp.headers()),
+        redact::body(rsp.headers(), rsp.body()),
+    );
+}
+
+/// Split a prepared request into a request builder and its body.
+fn raw_request_parts(request: http::Request<Vec<u8>>) -> (http::request::Builder, Vec<u8>) {
+    let (parts, body) = request.into_parts();
+    let mut builder = http::Request::builder()
+        .method(parts.method)
+        .uri(parts.uri)
+        .version(parts.version);
+    if let Some(headers) = builder.headers_mut() {
+        *headers = parts.headers;
+    }
+    (builder, body)
+}
+
+/// Whether a URI points to the same scheme, host, and port as a URL.
+fn same_origin(uri: &http::Uri, url: &Url) -> bool {
+    let port = uri.port_u16().or_else(|| {
+        match uri.scheme_str() {
+            Some("http") => Some(80),
+            Some("https") => Some(443),
+            _ => None,
+        }
+    });
+
+    let same_host = match (uri.host(), url.host_str()) {
+        (Some(lhs), Some(rhs)) => lhs.eq_ignore_ascii_case(rhs),
+        _ => false,
+    };
+
+    uri.scheme_str() == Some(url.scheme()) && same_host && port == url.port_or_known_default()
+}
+
+/// The authentication to use for a prepared request.
+///
+/// Requests which already carry authentication are sent as-is. Requests to any other origin than
+/// the client's API endpoints are sent without authentication so that credentials are not leaked
+/// to other hosts.
+fn raw_request_auth<'a>(
+    request: &http::request::Builder,
+    auth: &'a Auth,
+    urls: &[&Url],
+) -> &'a Auth {
+    static NO_AUTH: Auth = Auth::None;
+
+    let is_api_origin = if let Some(uri) = request.uri_ref() {
+        urls.iter().any(|url| same_origin(uri, url))
+    } else {
+        false
+    };
+    if !is_api_origin {
+        return &NO_AUTH;
+    }
+
+    match request.headers_ref() {
+        Some(headers) if Auth::is_set(headers) => &NO_AUTH,
+        _ => auth,
+    }
+}
+
 // Private enum that enables the parsing of the cert bytes to be
 // delayed until the client is built rather than when they're passed
 // to a builder.
@@ -120,6 +271,8 @@ pub struct Gitlab {
     graphql_url: Url,
     /// The authentication information to use when communicating with Gitlab.
     auth: Auth,
+    /// Whether to log request and response bodies.
+    log_bodies: bool,
 }
 
 impl Debug for Gitlab {
@@ -159,6 +312,8 @@ impl Gitlab {
             Auth::Token(token.into()),
             CertPolicy::Default,
             ClientCert::None,
+            false,
+            &[],
         )
     }
 
@@ -177,6 +332,8 @@ impl Gitlab {
             Auth::Token(token.into()),
             CertPolicy::Insecure,
             ClientCert::None,
+            false,
+            &[],
         )
     }
 
@@ -199,6 +356,8 @@ impl Gitlab {
             Auth::Token(token.into()),
             CertPolicy::SelfSigned(root_certificate),
             ClientCert::None,
+            false,
+            &[],
         )
     }
 
@@ -217,6 +376,8 @@ impl Gitlab {
             Auth::JobToken(token.into()),
             CertPolicy::Default,
             ClientCert::None,
+            false,
+            &[],
         )
     }
 
@@ -235,6 +396,8 @@ impl Gitlab {
             Auth::JobToken(token.into()),
             CertPolicy::Insecure,
             ClientCert::None,
+            false,
+            &[],
         )
     }
 
@@ -253,6 +416,8 @@ impl Gitlab {
             Auth::OAuth2(token.into()),
             CertPolicy::Default,
             ClientCert::None,
+            false,
+            &[],
         )
     }
 
@@ -271,35 +436,38 @@ impl Gitlab {
             Auth::OAuth2(token.into()),
             CertPolicy::Default,
             ClientCert::None,
+            false,
+            &[],
         )
     }
 
     /// Internal method to create a new Gitlab client.
+    #[allow(clippy::too_many_arguments)]
     fn new_impl(
         protocol: &str,
         host: &str,
         auth: Auth,
         cert_validation: CertPolicy,
         identity: ClientCert,
+        log_bodies: bool,
+        resolve: &[(String, SocketAddr)],
     ) -> GitlabResult<Self> {
         let rest_url = Url::parse(&format!("{}://{}/api/v4/", protocol, host))?;
         let graphql_url = Url::parse(&format!("{}://{}/api/graphql", protocol, host))?;
 
-        let client = match cert_validation {
-            CertPolicy::Insecure => Client::builder()
-                .danger_accept_invalid_certs(true)
-                .build()?,
+        let builder = match cert_validation {
+            CertPolicy::Insecure => Client::builder().danger_accept_invalid_certs(true),
             CertPolicy::Default => match identity {
-                ClientCert::None => Client::new(),
+                ClientCert::None => Client::builder(),
                 #[cfg(feature = "client_der")]
                 ClientCert::Der(der, password) => {
                     let id = TlsIdentity::from_pkcs12_der(&der, &password)?;
-                    Client::builder().identity(id).build()?
+                    Client::builder().identity(id)
                 },
                 #[cfg(feature = "client_pem")]
                 ClientCert::Pem(pem) => {
                     let id = TlsIdentity::from_pem(&pem)?;
-                    Client::builder().identity(id).build()?
+                    Client::builder().identity(id)
                 },
             },
             CertPolicy::SelfSigned(cert) => {
@@ -318,15 +486,20 @@ impl Gitlab {
                     },
                 };
 
-                builder.build()?
+                builder
             },
         };
+        let client = resolve
+            .iter()
+            .fold(builder, |builder, (domain, addr)| builder.resolve(domain, *addr))
+            .build()?;
 
         let api = Gitlab {
             client,
             rest_url,
             graphql_url,
             auth,
+            log_bodies,
         };
 
         // Ensure the API is working.
@@ -357,13 +530,63 @@ impl Gitlab {
             query.operation_name,
             query.variables,
         );
+        #[cfg(feature = "tracing")]
+        let span = request_span(&http::Method::POST, &self.graphql_url);
+        #[cfg(feature = "tracing")]
+        let _enter = span.enter();
         let req = self.client.post(self.graphql_url.clone()).json(query);
         let rsp: Response<Q::ResponseData> = self.send(req)?;
 
-        if let Some(errs) = rsp.errors {
-            return Err(GitlabError::graphql(errs));
+        graphql_result(rsp)
+    }
+
+    /// Send a batch of GraphQL queries in a single request.
+    ///
+    /// Results are returned in the same order as the queries. Errors for one query do not affect
+    /// the results of the other queries.
+    pub fn graphql_batch<Q>(
+        &self,
+        queries: &[QueryBody<Q::Variables>],
+    ) -> GitlabResult<Vec<GitlabResult<Q::ResponseData>>>
+    where
+        Q: GraphQLQuery,
+        Q::Variables: Debug,
+        for<'d> Q::ResponseData: Deserialize<'d>,
+    {
+        if queries.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        for query in queries {
+            info!(
+                target: "gitlab",
+                "sending batched GraphQL query '{}' {:?}",
+                query.operation_name,
+                query.variables,
+            );
         }
-        rsp.data.ok_or_else(GitlabError::no_response)
+        #[cfg(feature = "tracing")]
+        let span = request_span(&http::Method::POST, &self.graphql_url);
+        #[cfg(feature = "tracing")]
+        let _enter = span.enter();
+        let req = self.client.post(self.graphql_url.clone()).json(queries);
+        let rsps: Vec<Response<Q::ResponseData>> = self.send(req)?;
+
+        graphql_batch_results(queries.len(), rsps)
+    }
+
+    /// Execute a prepared HTTP request.
+    ///
+    /// The request must use an absolute URI. Authentication is only added to requests for the
+    /// GitLab instance the client talks to and only if the request does not already carry an
+    /// authentication header. No status code handling is performed on the response.
+    pub fn execute_raw(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<HttpResponse<Bytes>, api::ApiError<RestError>> {
+        let (request, body) = raw_request_parts(request);
+        let auth = raw_request_auth(&request, &self.auth, &[&self.rest_url, &self.graphql_url]);
+        self.rest_auth(request, body, auth)
     }
 
     /// Refactored code which talks to Gitlab and transforms error messages properly.
@@ -378,6 +601,8 @@ impl Gitlab {
         };
         let rsp = req.headers(auth_headers).send()?;
         let status = rsp.status();
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("status", status.as_u16());
         if status.is_server_error() {
             return Err(GitlabError::http(status));
         }
@@ -392,11 +617,20 @@ impl Gitlab {
         body: Vec<u8>,
         auth: &Auth,
     ) -> Result<HttpResponse<Bytes>, api::ApiError<<Self as api::RestClient>::Error>> {
+        #[cfg(feature = "tracing")]
+        let span = rest_span(&request);
+        #[cfg(feature = "tracing")]
+        let _enter = span.enter();
         let call = || -> Result<_, RestError> {
             auth.set_header(request.headers_mut().unwrap())?;
+            if self.log_bodies {
+                log_request(&request, &body);
+            }
             let http_request = request.body(body)?;
             let request = http_request.try_into()?;
             let rsp = self.client.execute(request)?;
+            #[cfg(feature = "tracing")]
+            tracing::Span::current().record("status", rsp.status().as_u16());
 
             let mut http_rsp = HttpResponse::builder()
                 .status(rsp.status())
@@ -405,7 +639,11 @@ impl Gitlab {
             for (key, value) in rsp.headers() {
                 headers.insert(key, value.clone());
             }
-            Ok(http_rsp.body(rsp.bytes()?)?)
+            let rsp = http_rsp.body(rsp.bytes()?)?;
+            if self.log_bodies {
+                log_response(&rsp);
+            }
+            Ok(rsp)
         };
         call().map_err(api::ApiError::client)
     }
@@ -454,8 +692,11 @@ pub struct GitlabBuilder<'a> {
     protocol: &'static str,
     host: String,
     token: Auth,
+    token_as_bearer: bool,
     cert_validation: CertPolicy<'a>,
     identity: ClientCert,
+    log_bodies: bool,
+    resolve: Vec<(String, SocketAddr)>,
 }
 
 impl<'a> GitlabBuilder<'a> {
@@ -469,8 +710,11 @@ impl<'a> GitlabBuilder<'a> {
             protocol: "https",
             host: host.into(),
             token: Auth::Token(token.into()),
+            token_as_bearer: false,
             cert_validation: CertPolicy::Default,
             identity: ClientCert::None,
+            log_bodies: false,
+            resolve: Vec::new(),
         }
     }
 
@@ -483,8 +727,11 @@ impl<'a> GitlabBuilder<'a> {
             protocol: "https",
             host: host.into(),
             token: Auth::None,
+            token_as_bearer: false,
             cert_validation: CertPolicy::Default,
             identity: ClientCert::None,
+            log_bodies: false,
+            resolve: Vec::new(),
         }
     }
 
@@ -505,6 +752,9 @@ impl<'a> GitlabBuilder<'a> {
     }
 
     /// Switch to using an OAuth2 token instead of a personal access token
+    ///
+    /// This only has an effect if a personal access token is currently configured. Prefer
+    /// `oauth2` which sets the token unconditionally.
     pub fn oauth2_token(&mut self) -> &mut Self {
         if let Auth::Token(token) = self.token.clone() {
             self.token = Auth::OAuth2(token);
@@ -512,6 +762,58 @@ impl<'a> GitlabBuilder<'a> {
         self
     }
 
+    /// Authenticate using a personal, project, or group access token.
+    ///
+    /// This replaces any previously configured authentication.
+    pub fn token<T>(&mut self, token: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.token = Auth::Token(token.into());
+        self
+    }
+
+    /// Send personal, project, or group access tokens in the `Authorization` header.
+    ///
+    /// By default, such tokens are sent using the `PRIVATE-TOKEN` header. GitLab also accepts
+    /// them as bearer tokens, which may be required when talking to an instance through a proxy
+    /// which only forwards the `Authorization` header. Other kinds of authentication are not
+    /// affected.
+    pub fn token_as_bearer(&mut self, token_as_bearer: bool) -> &mut Self {
+        self.token_as_bearer = token_as_bearer;
+        self
+    }
+
+    /// Authenticate using a CI job token.
+    ///
+    /// This replaces any previously configured authentication.
+    pub fn job_token<T>(&mut self, token: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.token = Auth::JobToken(token.into());
+        self
+    }
+
+    /// Authenticate using an OAuth2 token.
+    ///
+    /// This replaces any previously configured authentication.
+    pub fn oauth2<T>(&mut self, token: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.token = Auth::OAuth2(token.into());
+        self
+    }
+
+    /// Do not authenticate.
+    ///
+    /// This replaces any previously configured authentication.
+    pub fn unauthenticated(&mut self) -> &mut Self {
+        self.token = Auth::None;
+        self
+    }
+
     /// [Authenticate to Gitlab](reqwest::Identity) with the provided
     /// DER-formatted PKCS#12 archive.
     #[cfg(any(doc, feature = "client_der"))]
@@ -528,13 +830,46 @@ impl<'a> GitlabBuilder<'a> {
         self
     }
 
+    /// Log request and response bodies of REST calls at the trace level.
+    ///
+    /// Authentication headers and the values of parameters which look like secrets (e.g.,
+    /// `token`, `private_token`, or `password`) are redacted. Bodies which are neither forms nor
+    /// JSON are only logged by their size.
+    pub fn log_bodies(&mut self, log_bodies: bool) -> &mut Self {
+        self.log_bodies = log_bodies;
+        self
+    }
+
+    /// Override DNS resolution for a domain to use a specific address.
+    ///
+    /// This may be used to connect to an instance at a fixed address without changing the host
+    /// used for URLs or TLS verification. If the port of the address is `0`, the port of the URL
+    /// is used. Multiple overrides may be given.
+    pub fn resolve<D>(&mut self, domain: D, addr: SocketAddr) -> &mut Self
+    where
+        D: Into<String>,
+    {
+        self.resolve.push((domain.into(), addr));
+        self
+    }
+
+    /// The authentication to use for the client.
+    fn auth(&self) -> Auth {
+        match &self.token {
+            Auth::Token(token) if self.token_as_bearer => Auth::BearerToken(token.clone()),
+            auth => auth.clone(),
+        }
+    }
+
     pub fn build(&self) -> GitlabResult<Gitlab> {
         Gitlab::new_impl(
             self.protocol,
             &self.host,
-            self.token.clone(),
+            self.auth(),
             self.cert_validation.clone(),
             self.identity.clone(),
+            self.log_bodies,
+            &self.resolve,
         )
     }
 
@@ -542,9 +877,11 @@ impl<'a> GitlabBuilder<'a> {
         AsyncGitlab::new_impl(
             self.protocol,
             &self.host,
-            self.token.clone(),
+            self.auth(),
             self.cert_validation.clone(),
             self.identity.clone(),
+            self.log_bodies,
+            &self.resolve,
         )
         .await
     }
@@ -565,6 +902,8 @@ pub struct AsyncGitlab {
     graphql_url: Url,
     /// The authentication information to use when communicating with Gitlab.
     auth: Auth,
+    /// Whether to log request and response bodies.
+    log_bodies: bool,
 }
 
 impl Debug for AsyncGitlab {
@@ -605,32 +944,33 @@ impl api::AsyncClient for AsyncGitlab {
 
 impl AsyncGitlab {
     /// Internal method to create a new Gitlab client.
+    #[allow(clippy::too_many_arguments)]
     async fn new_impl<'a>(
         protocol: &str,
         host: &str,
         auth: Auth,
         cert_validation: CertPolicy<'a>,
         identity: ClientCert,
+        log_bodies: bool,
+        resolve: &[(String, SocketAddr)],
     ) -> GitlabResult<Self> {
         let instance_url = Url::parse(&format!("{}://{}/", protocol, host))?;
         let rest_url = Url::parse(&format!("{}://{}/api/v4/", protocol, host))?;
         let graphql_url = Url::parse(&format!("{}://{}/api/graphql", protocol, host))?;
 
-        let client = match cert_validation {
-            CertPolicy::Insecure => AsyncClient::builder()
-                .danger_accept_invalid_certs(true)
-                .build()?,
+        let builder = match cert_validation {
+            CertPolicy::Insecure => AsyncClient::builder().danger_accept_invalid_certs(true),
             CertPolicy::Default => match identity {
-                ClientCert::None => AsyncClient::new(),
+                ClientCert::None => AsyncClient::builder(),
                 #[cfg(feature = "client_der")]
                 ClientCert::Der(der, password) => {
                     let id = TlsIdentity::from_pkcs12_der(&der, &password)?;
-                    AsyncClient::builder().identity(id).build()?
+                    AsyncClient::builder().identity(id)
                 },
                 #[cfg(feature = "client_pem")]
                 ClientCert::Pem(pem) => {
                     let id = TlsIdentity::from_pem(&pem)?;
-                    AsyncClient::builder().identity(id).build()?
+                    AsyncClient::builder().identity(id)
                 },
             },
             CertPolicy::SelfSigned(cert) => {
@@ -649,9 +989,13 @@ impl AsyncGitlab {
                     },
                 };
 
-                builder.build()?
+                builder
             },
         };
+        let client = resolve
+            .iter()
+            .fold(builder, |builder, (domain, addr)| builder.resolve(domain, *addr))
+            .build()?;
 
         let api = AsyncGitlab {
             client,
@@ -659,6 +1003,7 @@ impl AsyncGitlab {
             rest_url,
             graphql_url,
             auth,
+            log_bodies,
         };
 
         // Ensure the API is working.
@@ -681,12 +1026,60 @@ impl AsyncGitlab {
             query.variables,
         );
         let req = self.client.post(self.graphql_url.clone()).json(query);
-        let rsp: Response<Q::ResponseData> = self.send(req).await?;
+        let rsp = self.send(req);
+        #[cfg(feature = "tracing")]
+        let rsp = rsp.instrument(request_span(&http::Method::POST, &self.graphql_url));
+        let rsp: Response<Q::ResponseData> = rsp.await?;
+
+        graphql_result(rsp)
+    }
 
-        if let Some(errs) = rsp.errors {
-            return Err(GitlabError::graphql(errs));
+    /// Send a batch of GraphQL queries in a single request.
+    ///
+    /// Results are returned in the same order as the queries. Errors for one query do not affect
+    /// the results of the other queries.
+    pub async fn graphql_batch<Q>(
+        &self,
+        queries: &[QueryBody<Q::Variables>],
+    ) -> GitlabResult<Vec<GitlabResult<Q::ResponseData>>>
+    where
+        Q: GraphQLQuery,
+        Q::Variables: Debug,
+        for<'d> Q::ResponseData: Deserialize<'d>,
+    {
+        if queries.is_empty() {
+            return Ok(Vec::new());
         }
-        rsp.data.ok_or_else(GitlabError::no_response)
+
+        for query in queries {
+            info!(
+                target: "gitlab",
+                "sending batched GraphQL query '{}' {:?}",
+                query.operation_name,
+                query.variables,
+            );
+        }
+        let req = self.client.post(self.graphql_url.clone()).json(queries);
+        let rsps = self.send(req);
+        #[cfg(feature = "tracing")]
+        let rsps = rsps.instrument(request_span(&http::Method::POST, &self.graphql_url));
+        let rsps: Vec<Response<Q::ResponseData>> = rsps.await?;
+
+        graphql_batch_results(queries.len(), rsps)
+    }
+
+    /// Execute a prepared HTTP request.
+    ///
+    /// The request must use an absolute URI. Authentication is only added to requests for the
+    /// GitLab instance the client talks to and only if the request does not already carry an
+    /// authentication header. No status code handling is performed on the response.
+    pub async fn execute_raw(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<HttpResponse<Bytes>, api::ApiError<RestError>> {
+        let (request, body) = raw_request_parts(request);
+        let auth = raw_request_auth(&request, &self.auth, &[&self.rest_url, &self.graphql_url]);
+        self.rest_async_auth(request, body, auth).await
     }
 
     /// Refactored code which talks to Gitlab and transforms error messages properly.
@@ -701,6 +1094,8 @@ impl AsyncGitlab {
         };
         let rsp = req.headers(auth_headers).send().await?;
         let status = rsp.status();
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("status", status.as_u16());
         if status.is_server_error() {
             return Err(GitlabError::http(status));
         }
@@ -716,11 +1111,18 @@ impl AsyncGitlab {
         auth: &Auth,
     ) -> Result<HttpResponse<Bytes>, api::ApiError<<Self as api::RestClient>::Error>> {
         use futures_util::TryFutureExt;
+        #[cfg(feature = "tracing")]
+        let span = rest_span(&request);
         let call = || async {
             auth.set_header(request.headers_mut().unwrap())?;
+            if self.log_bodies {
+                log_request(&request, &body);
+            }
             let http_request = request.body(body)?;
             let request = http_request.try_into()?;
             let rsp = self.client.execute(request).await?;
+            #[cfg(feature = "tracing")]
+            tracing::Span::current().record("status", rsp.status().as_u16());
 
             let mut http_rsp = HttpResponse::builder()
                 .status(rsp.status())
@@ -729,9 +1131,16 @@ impl AsyncGitlab {
             for (key, value) in rsp.headers() {
                 headers.insert(key, value.clone());
             }
-            Ok(http_rsp.body(rsp.bytes().await?)?)
+            let rsp = http_rsp.body(rsp.bytes().await?)?;
+            if self.log_bodies {
+                log_response(&rsp);
+            }
+            Ok(rsp)
         };
-        call().map_err(api::ApiError::client).await
+        let rsp = call().map_err(api::ApiError::client);
+        #[cfg(feature = "tracing")]
+        let rsp = rsp.instrument(span);
+        rsp.await
     }
 }
 
@@ -27,6 +27,8 @@ pub mod webhooks;
 pub mod api;
 #[cfg(feature = "client_api")]
 mod auth;
+#[cfg(feature = "client_api")]
+mod redact;
 
 #[cfg(feature = "client_api")]
 pub use crate::auth::AuthError;
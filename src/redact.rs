@@ -0,0 +1,237 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Redaction of secrets from requests and responses before they are logged.
+
+use std::fmt;
+
+use http::header::{self, HeaderMap};
+use serde_json::Value;
+use url::Url;
+
+/// The placeholder used for redacted values.
+const REDACTED: &str = "REDACTED";
+
+/// Substrings of parameter names which indicate that the value is a secret.
+///
+/// This covers `token`, `private_token`, `password`, and the like.
+const SECRET_PATTERNS: &[&str] = &["token", "password", "secret"];
+
+/// Whether a parameter or field name refers to a secret value.
+fn is_secret(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    SECRET_PATTERNS
+        .iter()
+        .any(|pattern| name.contains(pattern))
+}
+
+/// Redact secret query parameters from a URL.
+pub(crate) fn url(url: &Url) -> Url {
+    let mut url = url.clone();
+    if url.query().is_some() {
+        let pairs = url
+            .query_pairs()
+            .map(|(key, value)| {
+                let value = if is_secret(&key) {
+                    REDACTED.into()
+                } else {
+                    value.into_owned()
+                };
+                (key.into_owned(), value)
+            })
+            .collect::<Vec<_>>();
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+    url
+}
+
+/// A display wrapper for headers which hides authentication information.
+pub(crate) struct Headers<'a>(pub(crate) &'a HeaderMap);
+
+impl fmt::Display for Headers<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut map = f.debug_map();
+        for (name, value) in self.0 {
+            let secret = value.is_sensitive()
+                || name == header::AUTHORIZATION
+                || name == header::COOKIE
+                || name == header::SET_COOKIE
+                || is_secret(name.as_str());
+            if secret {
+                map.entry(&name.as_str(), &REDACTED);
+            } else {
+                map.entry(&name.as_str(), &value);
+            }
+        }
+        map.finish()
+    }
+}
+
+/// Render a body for logging with secret values redacted.
+///
+/// URL-encoded form and JSON bodies have the values of secret parameters redacted. Other
+/// bodies are only described by their size since their contents cannot be inspected.
+pub(crate) fn body(headers: &HeaderMap, body: &[u8]) -> String {
+    if body.is_empty() {
+        return "<empty>".into();
+    }
+
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(str::trim);
+
+    let redacted = match content_type {
+        Some("application/x-www-form-urlencoded") => form(body),
+        Some("application/json") => json(body),
+        _ => None,
+    };
+
+    redacted.unwrap_or_else(|| format!("<{} bytes>", body.len()))
+}
+
+fn form(body: &[u8]) -> Option<String> {
+    let pairs: Vec<(String, String)> = serde_urlencoded::from_bytes(body).ok()?;
+    let pairs = pairs
+        .into_iter()
+        .map(|(key, value)| {
+            if is_secret(&key) {
+                (key, REDACTED.into())
+            } else {
+                (key, value)
+            }
+        })
+        .collect::<Vec<_>>();
+    serde_urlencoded::to_string(pairs).ok()
+}
+
+fn json(body: &[u8]) -> Option<String> {
+    let mut value = serde_json::from_slice(body).ok()?;
+    redact_json(&mut value);
+    serde_json::to_string(&value).ok()
+}
+
+fn redact_json(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                if is_secret(key) {
+                    *value = Value::String(REDACTED.into());
+                } else {
+                    redact_json(value);
+                }
+            }
+        },
+        Value::Array(array) => array.iter_mut().for_each(redact_json),
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::{self, HeaderMap, HeaderValue};
+    use url::Url;
+
+    use crate::redact;
+
+    #[test]
+    fn is_secret() {
+        let items = &[
+            ("token", true),
+            ("private_token", true),
+            ("PRIVATE-TOKEN", true),
+            ("password", true),
+            ("user[password]", true),
+            ("webhook_secret", true),
+            ("name", false),
+            ("username", false),
+        ];
+
+        for (name, secret) in items {
+            assert_eq!(redact::is_secret(name), *secret, "{}", name);
+        }
+    }
+
+    #[test]
+    fn url() {
+        let url = Url::parse("https://gitlab.host/api/v4/runners?token=secret&name=value").unwrap();
+        assert_eq!(
+            redact::url(&url).as_str(),
+            "https://gitlab.host/api/v4/runners?token=REDACTED&name=value",
+        );
+    }
+
+    #[test]
+    fn url_no_query() {
+        let url = Url::parse("https://gitlab.host/api/v4/projects").unwrap();
+        assert_eq!(redact::url(&url), url);
+    }
+
+    #[test]
+    fn headers() {
+        let mut headers = HeaderMap::new();
+        let mut sensitive = HeaderValue::from_static("sensitive");
+        sensitive.set_sensitive(true);
+        headers.insert("x-sensitive", sensitive);
+        headers.insert("private-token", HeaderValue::from_static("secret"));
+        headers.insert(header::AUTHORIZATION, HeaderValue::from_static("Bearer secret"));
+        headers.insert(header::ACCEPT, HeaderValue::from_static("*/*"));
+
+        assert_eq!(
+            redact::Headers(&headers).to_string(),
+            concat!(
+                r#"{"x-sensitive": "REDACTED", "private-token": "REDACTED", "#,
+                r#""authorization": "REDACTED", "accept": "*/*"}"#,
+            ),
+        );
+    }
+
+    fn content_type(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(value));
+        headers
+    }
+
+    #[test]
+    fn body_empty() {
+        assert_eq!(redact::body(&HeaderMap::new(), b""), "<empty>");
+    }
+
+    #[test]
+    fn body_form() {
+        let headers = content_type("application/x-www-form-urlencoded");
+        assert_eq!(
+            redact::body(&headers, b"name=user&password=secret&user%5Btoken%5D=secret"),
+            "name=user&password=REDACTED&user%5Btoken%5D=REDACTED",
+        );
+    }
+
+    #[test]
+    fn body_json() {
+        let headers = content_type("application/json; charset=utf-8");
+        assert_eq!(
+            redact::body(
+                &headers,
+                br#"{"name":"user","token":"secret","items":[{"password":"secret"}]}"#,
+            ),
+            r#"{"items":[{"password":"REDACTED"}],"name":"user","token":"REDACTED"}"#,
+        );
+    }
+
+    #[test]
+    fn body_opaque() {
+        let headers = content_type("application/octet-stream");
+        assert_eq!(redact::body(&headers, b"token=secret"), "<12 bytes>");
+        assert_eq!(redact::body(&HeaderMap::new(), b"token=secret"), "<12 bytes>");
+    }
+
+    #[test]
+    fn body_invalid_json() {
+        let headers = content_type("application/json");
+        assert_eq!(redact::body(&headers, b"{\"token\":"), "<9 bytes>");
+    }
+}
@@ -4,8 +4,22 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#[cfg(feature = "client_api")]
+mod auth;
 #[cfg(feature = "client_api")]
 pub mod client;
+#[cfg(feature = "client_api")]
+mod graphql;
+#[cfg(feature = "client_api")]
+mod logging;
+#[cfg(feature = "client_api")]
+mod raw;
+#[cfg(feature = "client_api")]
+mod resolve;
+#[cfg(feature = "client_api")]
+mod server;
+#[cfg(feature = "tracing")]
+mod tracing;
 
 #[cfg(not(feature = "_nohooks"))]
 mod webhooks;
@@ -0,0 +1,213 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::api::users::CurrentUser;
+use crate::api::{self, Query};
+use crate::test::server::SingleRequestServer;
+use crate::GitlabBuilder;
+
+const TOKEN: &str = "auth-test-token";
+
+fn request_line(request: &str) -> &str {
+    request.lines().next().unwrap()
+}
+
+fn header<'a>(request: &'a str, name: &str) -> Option<&'a str> {
+    request.lines().skip(1).find_map(|line| {
+        line.split_once(':').and_then(|(key, value)| {
+            if key.eq_ignore_ascii_case(name) {
+                Some(value.trim())
+            } else {
+                None
+            }
+        })
+    })
+}
+
+fn check_token(request: &str) {
+    assert_eq!(request_line(request), "GET /api/v4/user? HTTP/1.1");
+    assert_eq!(header(request, "private-token"), Some(TOKEN));
+    assert_eq!(header(request, "job-token"), None);
+    assert_eq!(header(request, "authorization"), None);
+}
+
+fn check_job_token(request: &str) {
+    assert_eq!(request_line(request), "GET /api/v4/job? HTTP/1.1");
+    assert_eq!(header(request, "private-token"), None);
+    assert_eq!(header(request, "job-token"), Some(TOKEN));
+    assert_eq!(header(request, "authorization"), None);
+}
+
+fn check_oauth2(request: &str) {
+    let bearer = format!("Bearer {}", TOKEN);
+
+    assert_eq!(request_line(request), "GET /api/v4/user? HTTP/1.1");
+    assert_eq!(header(request, "private-token"), None);
+    assert_eq!(header(request, "job-token"), None);
+    assert_eq!(header(request, "authorization"), Some(bearer.as_str()));
+}
+
+#[test]
+fn test_token() {
+    let server = SingleRequestServer::new("{}");
+    GitlabBuilder::new_unauthenticated(server.host())
+        .insecure()
+        .job_token("other")
+        .token(TOKEN)
+        .build()
+        .unwrap();
+
+    check_token(&server.request());
+}
+
+#[test]
+fn test_job_token() {
+    let server = SingleRequestServer::new("{}");
+    GitlabBuilder::new(server.host(), "other")
+        .insecure()
+        .job_token(TOKEN)
+        .build()
+        .unwrap();
+
+    check_job_token(&server.request());
+}
+
+#[test]
+fn test_oauth2() {
+    let server = SingleRequestServer::new("{}");
+    GitlabBuilder::new_unauthenticated(server.host())
+        .insecure()
+        .oauth2(TOKEN)
+        .build()
+        .unwrap();
+
+    check_oauth2(&server.request());
+}
+
+#[test]
+fn test_oauth2_token_only_converts_token() {
+    let server = SingleRequestServer::new("{}");
+    GitlabBuilder::new_unauthenticated(server.host())
+        .insecure()
+        .job_token(TOKEN)
+        .oauth2_token()
+        .build()
+        .unwrap();
+
+    check_job_token(&server.request());
+}
+
+#[test]
+fn test_token_as_bearer() {
+    let server = SingleRequestServer::new("{}");
+    GitlabBuilder::new_unauthenticated(server.host())
+        .insecure()
+        .token_as_bearer(true)
+        .token(TOKEN)
+        .build()
+        .unwrap();
+
+    check_oauth2(&server.request());
+}
+
+#[test]
+fn test_token_as_bearer_disabled() {
+    let server = SingleRequestServer::new("{}");
+    GitlabBuilder::new(server.host(), TOKEN)
+        .insecure()
+        .token_as_bearer(true)
+        .token_as_bearer(false)
+        .build()
+        .unwrap();
+
+    check_token(&server.request());
+}
+
+#[test]
+fn test_token_as_bearer_job_token() {
+    let server = SingleRequestServer::new("{}");
+    GitlabBuilder::new_unauthenticated(server.host())
+        .insecure()
+        .job_token(TOKEN)
+        .token_as_bearer(true)
+        .build()
+        .unwrap();
+
+    check_job_token(&server.request());
+}
+
+#[test]
+fn test_unauthenticated() {
+    let server = SingleRequestServer::new("{}");
+    let client = GitlabBuilder::new(server.host(), TOKEN)
+        .insecure()
+        .unauthenticated()
+        .build()
+        .unwrap();
+
+    // No connection check is made without authentication, so make a request manually.
+    api::ignore(CurrentUser::builder().build().unwrap())
+        .query(&client)
+        .unwrap();
+
+    let request = server.request();
+    assert_eq!(request_line(&request), "GET /api/v4/user? HTTP/1.1");
+    assert_eq!(header(&request, "private-token"), None);
+    assert_eq!(header(&request, "job-token"), None);
+    assert_eq!(header(&request, "authorization"), None);
+}
+
+#[tokio::test]
+async fn test_token_async() {
+    let server = SingleRequestServer::new("{}");
+    GitlabBuilder::new_unauthenticated(server.host())
+        .insecure()
+        .token(TOKEN)
+        .build_async()
+        .await
+        .unwrap();
+
+    check_token(&server.request());
+}
+
+#[tokio::test]
+async fn test_job_token_async() {
+    let server = SingleRequestServer::new("{}");
+    GitlabBuilder::new_unauthenticated(server.host())
+        .insecure()
+        .job_token(TOKEN)
+        .build_async()
+        .await
+        .unwrap();
+
+    check_job_token(&server.request());
+}
+
+#[tokio::test]
+async fn test_oauth2_async() {
+    let server = SingleRequestServer::new("{}");
+    GitlabBuilder::new(server.host(), "other")
+        .insecure()
+        .oauth2(TOKEN)
+        .build_async()
+        .await
+        .unwrap();
+
+    check_oauth2(&server.request());
+}
+
+#[tokio::test]
+async fn test_token_as_bearer_async() {
+    let server = SingleRequestServer::new("{}");
+    GitlabBuilder::new(server.host(), TOKEN)
+        .insecure()
+        .token_as_bearer(true)
+        .build_async()
+        .await
+        .unwrap();
+
+    check_oauth2(&server.request());
+}
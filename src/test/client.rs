@@ -6,14 +6,16 @@
 
 use std::borrow::Cow;
 use std::cmp;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::ops::Range;
+use std::sync::Mutex;
+use std::thread;
 
 use async_trait::async_trait;
 use bytes::Bytes;
 use derive_builder::Builder;
 use http::request::Builder as RequestBuilder;
-use http::{header, Method, Response, StatusCode};
+use http::{header, HeaderMap, Method, Response, StatusCode};
 use serde::ser::Serialize;
 use thiserror::Error;
 use url::Url;
@@ -33,6 +35,12 @@ pub struct ExpectedUrl {
     pub body: Vec<u8>,
     #[builder(default = "StatusCode::OK")]
     pub status: StatusCode,
+    #[builder(default)]
+    pub headers: Vec<(&'static str, &'static str)>,
+    #[builder(default)]
+    pub absent_headers: Vec<&'static str>,
+    #[builder(default)]
+    pub response_headers: Vec<(&'static str, &'static str)>,
 
     #[builder(default = "false")]
     pub paginated: bool,
@@ -50,6 +58,25 @@ impl ExpectedUrlBuilder {
         self.body = Some(body.bytes().collect());
         self
     }
+
+    pub fn add_header(&mut self, name: &'static str, value: &'static str) -> &mut Self {
+        self.headers
+            .get_or_insert_with(Vec::new)
+            .push((name, value));
+        self
+    }
+
+    pub fn add_absent_header(&mut self, name: &'static str) -> &mut Self {
+        self.absent_headers.get_or_insert_with(Vec::new).push(name);
+        self
+    }
+
+    pub fn add_response_header(&mut self, name: &'static str, value: &'static str) -> &mut Self {
+        self.response_headers
+            .get_or_insert_with(Vec::new)
+            .push((name, value));
+        self
+    }
 }
 
 impl ExpectedUrl {
@@ -87,6 +114,22 @@ impl ExpectedUrl {
         assert_eq!(url.fragment(), None);
     }
 
+    fn check_headers(&self, headers: &HeaderMap) {
+        for (name, value) in &self.headers {
+            let actual = headers
+                .get(*name)
+                .unwrap_or_else(|| panic!("missing expected header `{}`", name));
+            assert_eq!(actual, value, "unexpected value for header `{}`", name);
+        }
+        for name in &self.absent_headers {
+            assert!(
+                !headers.contains_key(*name),
+                "unexpected header `{}`",
+                name,
+            );
+        }
+    }
+
     fn is_pagination_key(key: &str) -> bool {
         key == "pagination" || key == "__test_keyset" || key == "page" || key == "per_page"
     }
@@ -95,15 +138,17 @@ impl ExpectedUrl {
 #[derive(Debug, Clone)]
 struct MockResponse {
     status: StatusCode,
+    headers: Vec<(&'static str, &'static str)>,
     data: Vec<u8>,
 }
 
 impl MockResponse {
     fn response(&self) -> Response<Vec<u8>> {
-        Response::builder()
-            .status(self.status)
-            .body(self.data.clone())
-            .unwrap()
+        let mut response = Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            response = response.header(*name, *value);
+        }
+        response.body(self.data.clone()).unwrap()
     }
 }
 
@@ -134,6 +179,7 @@ impl SingleTestClient {
         );
         let response = MockResponse {
             status: expected.status,
+            headers: expected.response_headers.clone(),
             data: data.into(),
         };
 
@@ -187,6 +233,7 @@ impl Client for SingleTestClient {
             String::from_utf8_lossy(&self.expected.body),
         );
         let headers = request.headers_ref().unwrap();
+        self.expected.check_headers(headers);
         let content_type = headers
             .get_all(header::CONTENT_TYPE)
             .iter()
@@ -220,6 +267,70 @@ impl AsyncClient for SingleTestClient {
     }
 }
 
+/// A client which expects a sequence of requests, each handled by its own `SingleTestClient`.
+pub struct SequenceTestClient {
+    clients: Mutex<VecDeque<SingleTestClient>>,
+}
+
+impl SequenceTestClient {
+    pub fn new<I>(clients: I) -> Self
+    where
+        I: IntoIterator<Item = SingleTestClient>,
+    {
+        Self {
+            clients: Mutex::new(clients.into_iter().collect()),
+        }
+    }
+}
+
+impl Drop for SequenceTestClient {
+    fn drop(&mut self) {
+        if !thread::panicking() {
+            let remaining = self.clients.get_mut().unwrap().len();
+            assert_eq!(remaining, 0, "not all expected requests were made");
+        }
+    }
+}
+
+impl RestClient for SequenceTestClient {
+    type Error = TestClientError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(Url::parse(&format!("{}/{}", CLIENT_STUB_APIV4, endpoint))?)
+    }
+
+    fn instance_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(Url::parse(&format!("{}/{}", CLIENT_STUB, endpoint))?)
+    }
+}
+
+impl Client for SequenceTestClient {
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let client = self
+            .clients
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("unexpected extra request");
+        client.rest(request, body)
+    }
+}
+
+#[async_trait]
+impl AsyncClient for SequenceTestClient {
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<<Self as RestClient>::Error>> {
+        <Self as Client>::rest(self, request, body)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Page {
     ByNumber { number: usize, size: usize },
@@ -298,6 +409,7 @@ where
             String::from_utf8_lossy(&self.expected.body),
         );
         let headers = request.headers_ref().unwrap();
+        self.expected.check_headers(headers);
         let content_type = headers
             .get_all(header::CONTENT_TYPE)
             .iter()
@@ -354,7 +466,10 @@ where
         let request = request.body(body).unwrap();
         assert_eq!(*request.method(), Method::GET);
 
-        let response = Response::builder().status(self.expected.status);
+        let response = self.expected.response_headers.iter().fold(
+            Response::builder().status(self.expected.status),
+            |response, (name, value)| response.header(*name, *value),
+        );
         let response = if pagination {
             if range.end + 1 < self.data.len() {
                 // Generate the URL for the next page.
@@ -0,0 +1,172 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use graphql_client::{GraphQLQuery, QueryBody};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::test::server::SingleRequestServer;
+use crate::{GitlabBuilder, GitlabError};
+
+const QUERY: &str = "query ProjectName($fullPath: ID!) { project(fullPath: $fullPath) { name } }";
+
+const BATCH_RESPONSE: &str = concat!(
+    "[",
+    r#"{"data":{"project":{"name":"first"}}},"#,
+    r#"{"data":{"project":null},"errors":[{"message":"not found"}]}"#,
+    "]",
+);
+
+struct ProjectName;
+
+#[derive(Debug, Serialize)]
+struct Variables {
+    #[serde(rename = "fullPath")]
+    full_path: &'static str,
+}
+
+#[derive(Debug, Deserialize)]
+struct Project {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseData {
+    project: Option<Project>,
+}
+
+impl GraphQLQuery for ProjectName {
+    type Variables = Variables;
+    type ResponseData = ResponseData;
+
+    fn build_query(variables: Self::Variables) -> QueryBody<Self::Variables> {
+        QueryBody {
+            variables,
+            query: QUERY,
+            operation_name: "ProjectName",
+        }
+    }
+}
+
+fn queries() -> Vec<QueryBody<Variables>> {
+    ["group/first", "group/missing"]
+        .iter()
+        .map(|full_path| {
+            ProjectName::build_query(Variables {
+                full_path,
+            })
+        })
+        .collect()
+}
+
+fn check_request(request: &str) {
+    assert_eq!(
+        request.lines().next().unwrap(),
+        "POST /api/graphql HTTP/1.1",
+    );
+
+    let (_, body) = request.split_once("\r\n\r\n").unwrap();
+    let body: Value = serde_json::from_str(body).unwrap();
+    assert_eq!(
+        body,
+        json!([
+            {
+                "variables": {"fullPath": "group/first"},
+                "query": QUERY,
+                "operationName": "ProjectName",
+            },
+            {
+                "variables": {"fullPath": "group/missing"},
+                "query": QUERY,
+                "operationName": "ProjectName",
+            },
+        ]),
+    );
+}
+
+fn check_results(results: Vec<Result<ResponseData, GitlabError>>) {
+    assert_eq!(results.len(), 2);
+
+    let mut results = results.into_iter();
+    let first = results.next().unwrap().unwrap();
+    assert_eq!(first.project.unwrap().name, "first");
+
+    let second = results.next().unwrap().unwrap_err();
+    if let GitlabError::GraphQL {
+        message,
+    } = second
+    {
+        assert_eq!(message.len(), 1);
+        assert_eq!(message[0].message, "not found");
+    } else {
+        panic!("unexpected error: {:?}", second);
+    }
+}
+
+#[test]
+fn test_graphql_batch() {
+    let server = SingleRequestServer::new(BATCH_RESPONSE);
+    let client = GitlabBuilder::new_unauthenticated(server.host())
+        .insecure()
+        .build()
+        .unwrap();
+
+    let results = client.graphql_batch::<ProjectName>(&queries()).unwrap();
+
+    check_request(&server.request());
+    check_results(results);
+}
+
+#[tokio::test]
+async fn test_graphql_batch_async() {
+    let server = SingleRequestServer::new(BATCH_RESPONSE);
+    let client = GitlabBuilder::new_unauthenticated(server.host())
+        .insecure()
+        .build_async()
+        .await
+        .unwrap();
+
+    let results = client
+        .graphql_batch::<ProjectName>(&queries())
+        .await
+        .unwrap();
+
+    check_request(&server.request());
+    check_results(results);
+}
+
+#[test]
+fn test_graphql_batch_empty() {
+    let client = GitlabBuilder::new_unauthenticated("gitlab.invalid")
+        .build()
+        .unwrap();
+
+    let results = client.graphql_batch::<ProjectName>(&[]).unwrap();
+    assert!(results.is_empty());
+}
+
+#[test]
+fn test_graphql_batch_response_count_mismatch() {
+    let server = SingleRequestServer::new(r#"[{"data":{"project":{"name":"first"}}}]"#);
+    let client = GitlabBuilder::new_unauthenticated(server.host())
+        .insecure()
+        .build()
+        .unwrap();
+
+    let err = client.graphql_batch::<ProjectName>(&queries()).unwrap_err();
+    server.request();
+
+    if let GitlabError::GraphQLBatch {
+        expected,
+        actual,
+    } = err
+    {
+        assert_eq!(expected, 2);
+        assert_eq!(actual, 1);
+    } else {
+        panic!("unexpected error: {:?}", err);
+    }
+}
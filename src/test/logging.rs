@@ -0,0 +1,147 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::sync::Once;
+
+use http::Method;
+use log::{LevelFilter, Log, Metadata, Record};
+
+use crate::api::{self, AsyncQuery, Endpoint, FormParams, Query, QueryParams};
+use crate::test::server::SingleRequestServer;
+use crate::{GitlabBuilder, ImpersonationClient};
+
+const TOKEN: &str = "auth-token-value";
+const PASSWORD: &str = "form-password-value";
+const QUERY_TOKEN: &str = "query-token-value";
+const RESPONSE_TOKEN: &str = "response-token-value";
+const RESPONSE: &str = r#"{"name":"response-name","token":"response-token-value"}"#;
+
+thread_local! {
+    static RECORDS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+struct CaptureLogger;
+
+impl Log for CaptureLogger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        if record.target() == "gitlab" {
+            RECORDS.with(|records| records.borrow_mut().push(record.args().to_string()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CaptureLogger = CaptureLogger;
+
+/// Collect the messages logged by the current thread.
+fn captured_logs() -> String {
+    RECORDS.with(|records| records.borrow_mut().drain(..).collect::<Vec<_>>().join("\n"))
+}
+
+fn init_logger() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(LevelFilter::Trace);
+    });
+    captured_logs();
+}
+
+struct Dummy;
+
+impl Endpoint for Dummy {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "dummy".into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+        params.push("token", QUERY_TOKEN);
+        params
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, api::BodyError> {
+        let mut params = FormParams::default();
+        params.push("name", "user").push("password", PASSWORD);
+        params.into_body()
+    }
+}
+
+fn check_logs(logs: &str) {
+    for secret in [TOKEN, PASSWORD, QUERY_TOKEN, RESPONSE_TOKEN] {
+        assert!(!logs.contains(secret), "{} found in: {}", secret, logs);
+    }
+    assert!(logs.contains("name=user&password=REDACTED"), "{}", logs);
+    assert!(logs.contains(r#""private-token": "REDACTED""#), "{}", logs);
+    assert!(logs.contains("token=REDACTED"), "{}", logs);
+    assert!(logs.contains("response-name"), "{}", logs);
+}
+
+#[test]
+fn test_log_bodies_redacted() {
+    init_logger();
+    let server = SingleRequestServer::new(RESPONSE);
+    let client = GitlabBuilder::new_unauthenticated(server.host())
+        .insecure()
+        .log_bodies(true)
+        .build()
+        .unwrap();
+    let client = ImpersonationClient::new(&client, TOKEN);
+
+    api::ignore(Dummy).query(&client).unwrap();
+
+    let request = server.request();
+    assert!(request.contains(TOKEN));
+    assert!(request.contains(PASSWORD));
+    check_logs(&captured_logs());
+}
+
+#[tokio::test]
+async fn test_log_bodies_redacted_async() {
+    init_logger();
+    let server = SingleRequestServer::new(RESPONSE);
+    let client = GitlabBuilder::new_unauthenticated(server.host())
+        .insecure()
+        .log_bodies(true)
+        .build_async()
+        .await
+        .unwrap();
+    let client = ImpersonationClient::new(&client, TOKEN);
+
+    api::ignore(Dummy).query_async(&client).await.unwrap();
+
+    server.request();
+    check_logs(&captured_logs());
+}
+
+#[test]
+fn test_log_bodies_disabled() {
+    init_logger();
+    let server = SingleRequestServer::new(RESPONSE);
+    let client = GitlabBuilder::new_unauthenticated(server.host())
+        .insecure()
+        .build()
+        .unwrap();
+    let client = ImpersonationClient::new(&client, TOKEN);
+
+    api::ignore(Dummy).query(&client).unwrap();
+
+    server.request();
+    let logs = captured_logs();
+    assert!(!logs.contains("request:"), "{}", logs);
+    assert!(!logs.contains("response:"), "{}", logs);
+}
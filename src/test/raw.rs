@@ -0,0 +1,132 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use http::header::AUTHORIZATION;
+use http::Request;
+
+use crate::test::server::SingleRequestServer;
+use crate::GitlabBuilder;
+
+const TOKEN: &str = "raw-test-token";
+const BODY: &str = r#"{"id":1}"#;
+
+fn header<'a>(request: &'a str, name: &str) -> Option<&'a str> {
+    request.lines().skip(1).find_map(|line| {
+        line.split_once(':').and_then(|(key, value)| {
+            if key.eq_ignore_ascii_case(name) {
+                Some(value.trim())
+            } else {
+                None
+            }
+        })
+    })
+}
+
+fn get_request(host: &str) -> Request<Vec<u8>> {
+    Request::get(format!("http://{}/api/v4/user", host))
+        .body(Vec::new())
+        .unwrap()
+}
+
+#[test]
+fn test_execute_raw() {
+    // Building an authenticated client checks the token against the server first.
+    let server = SingleRequestServer::with_count(BODY, 2);
+    let client = GitlabBuilder::new(server.host(), TOKEN)
+        .insecure()
+        .build()
+        .unwrap();
+
+    let rsp = client.execute_raw(get_request(server.host())).unwrap();
+    assert_eq!(rsp.status(), http::StatusCode::OK);
+    assert_eq!(rsp.body().as_ref(), BODY.as_bytes());
+
+    let request = server.requests().pop().unwrap();
+    assert_eq!(request.lines().next().unwrap(), "GET /api/v4/user HTTP/1.1");
+    assert_eq!(header(&request, "private-token"), Some(TOKEN));
+}
+
+#[tokio::test]
+async fn test_execute_raw_async() {
+    let server = SingleRequestServer::with_count(BODY, 2);
+    let client = GitlabBuilder::new(server.host(), TOKEN)
+        .insecure()
+        .build_async()
+        .await
+        .unwrap();
+
+    let rsp = client
+        .execute_raw(get_request(server.host()))
+        .await
+        .unwrap();
+    assert_eq!(rsp.status(), http::StatusCode::OK);
+    assert_eq!(rsp.body().as_ref(), BODY.as_bytes());
+
+    let request = server.requests().pop().unwrap();
+    assert_eq!(request.lines().next().unwrap(), "GET /api/v4/user HTTP/1.1");
+    assert_eq!(header(&request, "private-token"), Some(TOKEN));
+}
+
+#[test]
+fn test_execute_raw_existing_auth() {
+    let server = SingleRequestServer::with_count(BODY, 2);
+    let client = GitlabBuilder::new(server.host(), TOKEN)
+        .insecure()
+        .build()
+        .unwrap();
+
+    let mut request = get_request(server.host());
+    request
+        .headers_mut()
+        .insert(AUTHORIZATION, "Bearer other".parse().unwrap());
+    let rsp = client.execute_raw(request).unwrap();
+    assert_eq!(rsp.body().as_ref(), BODY.as_bytes());
+
+    let request = server.requests().pop().unwrap();
+    assert_eq!(header(&request, "private-token"), None);
+    assert_eq!(header(&request, "authorization"), Some("Bearer other"));
+}
+
+#[test]
+fn test_execute_raw_foreign_host() {
+    let setup = SingleRequestServer::new("{}");
+    let client = GitlabBuilder::new(setup.host(), TOKEN)
+        .insecure()
+        .build()
+        .unwrap();
+    setup.request();
+
+    // A different port is a different origin.
+    let server = SingleRequestServer::new(BODY);
+    let rsp = client.execute_raw(get_request(server.host())).unwrap();
+    assert_eq!(rsp.body().as_ref(), BODY.as_bytes());
+
+    let request = server.request();
+    assert_eq!(header(&request, "private-token"), None);
+    assert_eq!(header(&request, "authorization"), None);
+}
+
+#[tokio::test]
+async fn test_execute_raw_foreign_host_async() {
+    let setup = SingleRequestServer::new("{}");
+    let client = GitlabBuilder::new(setup.host(), TOKEN)
+        .insecure()
+        .build_async()
+        .await
+        .unwrap();
+    setup.request();
+
+    let server = SingleRequestServer::new(BODY);
+    let rsp = client
+        .execute_raw(get_request(server.host()))
+        .await
+        .unwrap();
+    assert_eq!(rsp.body().as_ref(), BODY.as_bytes());
+
+    let request = server.request();
+    assert_eq!(header(&request, "private-token"), None);
+    assert_eq!(header(&request, "authorization"), None);
+}
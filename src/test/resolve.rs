@@ -0,0 +1,70 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::net::SocketAddr;
+
+use crate::api::job::Job;
+use crate::api::{self, AsyncQuery, Query};
+use crate::test::server::SingleRequestServer;
+use crate::GitlabBuilder;
+
+const DOMAIN: &str = "gitlab.invalid";
+
+fn host_header(request: &str) -> Option<&str> {
+    request.lines().skip(1).find_map(|line| {
+        line.split_once(':').and_then(|(key, value)| {
+            if key.eq_ignore_ascii_case("host") {
+                Some(value.trim())
+            } else {
+                None
+            }
+        })
+    })
+}
+
+fn resolved(server: &SingleRequestServer) -> (SocketAddr, String) {
+    let addr: SocketAddr = server.host().parse().unwrap();
+    let host = format!("{}:{}", DOMAIN, addr.port());
+    (addr, host)
+}
+
+#[test]
+fn test_resolve() {
+    let server = SingleRequestServer::new("{}");
+    let (addr, host) = resolved(&server);
+    let client = GitlabBuilder::new_unauthenticated(&host)
+        .insecure()
+        .resolve(DOMAIN, addr)
+        .build()
+        .unwrap();
+
+    api::ignore(Job::builder().build().unwrap())
+        .query(&client)
+        .unwrap();
+
+    let request = server.request();
+    assert_eq!(host_header(&request), Some(host.as_str()));
+}
+
+#[tokio::test]
+async fn test_resolve_async() {
+    let server = SingleRequestServer::new("{}");
+    let (addr, host) = resolved(&server);
+    let client = GitlabBuilder::new_unauthenticated(&host)
+        .insecure()
+        .resolve(DOMAIN, addr)
+        .build_async()
+        .await
+        .unwrap();
+
+    api::ignore(Job::builder().build().unwrap())
+        .query_async(&client)
+        .await
+        .unwrap();
+
+    let request = server.request();
+    assert_eq!(host_header(&request), Some(host.as_str()));
+}
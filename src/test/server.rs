@@ -0,0 +1,98 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A minimal HTTP server for testing the real clients.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+/// A server which answers requests (a single one by default) with a canned JSON response.
+pub struct SingleRequestServer {
+    host: String,
+    handle: JoinHandle<Vec<String>>,
+}
+
+impl SingleRequestServer {
+    /// Start a server answering with the given JSON body.
+    pub fn new(body: &'static str) -> Self {
+        Self::with_count(body, 1)
+    }
+
+    /// Start a server answering a number of requests with the given JSON body.
+    pub fn with_count(body: &'static str, count: usize) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let host = listener.local_addr().unwrap().to_string();
+
+        let handle = thread::spawn(move || {
+            (0..count)
+                .map(|_| Self::answer(&listener, body))
+                .collect()
+        });
+
+        Self {
+            host,
+            handle,
+        }
+    }
+
+    fn answer(listener: &TcpListener, body: &str) -> String {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+
+        let mut request = String::new();
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+            request.push_str(&line);
+            if line == "\r\n" || line.is_empty() {
+                break;
+            }
+        }
+        let mut request_body = vec![0; content_length];
+        reader.read_exact(&mut request_body).unwrap();
+        request.push_str(&String::from_utf8_lossy(&request_body));
+
+        let response = format!(
+            concat!(
+                "HTTP/1.1 200 OK\r\n",
+                "content-type: application/json\r\n",
+                "content-length: {}\r\n",
+                "connection: close\r\n",
+                "\r\n",
+                "{}",
+            ),
+            body.len(),
+            body,
+        );
+        reader.get_mut().write_all(response.as_bytes()).unwrap();
+
+        request
+    }
+
+    /// The host (and port) the server is listening on.
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Wait for the request and return it as it was received.
+    pub fn request(self) -> String {
+        let mut requests = self.requests();
+        assert_eq!(requests.len(), 1);
+        requests.remove(0)
+    }
+
+    /// Wait for all requests and return them as they were received.
+    pub fn requests(self) -> Vec<String> {
+        self.handle.join().unwrap()
+    }
+}
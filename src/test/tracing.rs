@@ -0,0 +1,172 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing_core::span::Current;
+use tracing::{Event, Metadata, Subscriber};
+
+use crate::api::{self, AsyncQuery, Query};
+use crate::test::server::SingleRequestServer;
+use crate::GitlabBuilder;
+
+#[derive(Debug)]
+struct SpanData {
+    metadata: &'static Metadata<'static>,
+    fields: BTreeMap<&'static str, String>,
+    entered: bool,
+}
+
+impl Visit for SpanData {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.fields.insert(field.name(), format!("{:?}", value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.fields.insert(field.name(), value.to_string());
+    }
+}
+
+#[derive(Clone, Default)]
+struct RecordingSubscriber {
+    spans: Arc<Mutex<Vec<SpanData>>>,
+    stack: Arc<Mutex<Vec<Id>>>,
+}
+
+impl RecordingSubscriber {
+    fn gitlab_spans(&self) -> Vec<SpanData> {
+        let mut spans = self.spans.lock().unwrap();
+        spans
+            .drain(..)
+            .filter(|span| span.metadata.target() == "gitlab")
+            .collect()
+    }
+
+    fn with_span<F>(&self, id: &Id, f: F)
+    where
+        F: FnOnce(&mut SpanData),
+    {
+        let mut spans = self.spans.lock().unwrap();
+        f(&mut spans[(id.into_u64() - 1) as usize]);
+    }
+}
+
+impl Subscriber for RecordingSubscriber {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn new_span(&self, attrs: &Attributes) -> Id {
+        let mut span = SpanData {
+            metadata: attrs.metadata(),
+            fields: BTreeMap::new(),
+            entered: false,
+        };
+        attrs.record(&mut span);
+
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(span);
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, id: &Id, values: &Record) {
+        self.with_span(id, |span| values.record(span));
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event) {}
+
+    fn enter(&self, id: &Id) {
+        self.with_span(id, |span| span.entered = true);
+        self.stack.lock().unwrap().push(id.clone());
+    }
+
+    fn exit(&self, _: &Id) {
+        self.stack.lock().unwrap().pop();
+    }
+
+    fn current_span(&self) -> Current {
+        let stack = self.stack.lock().unwrap();
+        if let Some(id) = stack.last() {
+            let spans = self.spans.lock().unwrap();
+            Current::new(id.clone(), spans[(id.into_u64() - 1) as usize].metadata)
+        } else {
+            Current::none()
+        }
+    }
+}
+
+fn check_span(spans: &[SpanData], host: &str) {
+    assert_eq!(spans.len(), 1);
+    let span = &spans[0];
+    assert_eq!(span.metadata.name(), "request");
+    assert!(span.entered);
+    assert_eq!(span.fields["method"], "GET");
+    assert_eq!(
+        span.fields["endpoint"],
+        format!("http://{}/api/v4/dummy?key=value", host),
+    );
+    assert_eq!(span.fields["status"], "200");
+}
+
+struct Dummy;
+
+impl api::Endpoint for Dummy {
+    fn method(&self) -> http::Method {
+        http::Method::GET
+    }
+
+    fn endpoint(&self) -> std::borrow::Cow<'static, str> {
+        "dummy".into()
+    }
+
+    fn parameters(&self) -> api::QueryParams {
+        let mut params = api::QueryParams::default();
+        params.push("key", "value");
+        params
+    }
+}
+
+#[test]
+fn test_rest_span() {
+    let server = SingleRequestServer::new("{}");
+    let client = GitlabBuilder::new_unauthenticated(server.host())
+        .insecure()
+        .build()
+        .unwrap();
+
+    let subscriber = RecordingSubscriber::default();
+    tracing::subscriber::with_default(subscriber.clone(), || {
+        api::ignore(Dummy).query(&client).unwrap();
+    });
+
+    check_span(&subscriber.gitlab_spans(), server.host());
+    server.request();
+}
+
+#[tokio::test]
+async fn test_rest_span_async() {
+    let server = SingleRequestServer::new("{}");
+    let client = GitlabBuilder::new_unauthenticated(server.host())
+        .insecure()
+        .build_async()
+        .await
+        .unwrap();
+
+    let subscriber = RecordingSubscriber::default();
+    {
+        let _guard = tracing::subscriber::set_default(subscriber.clone());
+        api::ignore(Dummy).query_async(&client).await.unwrap();
+    }
+
+    check_span(&subscriber.gitlab_spans(), server.host());
+    server.request();
+}